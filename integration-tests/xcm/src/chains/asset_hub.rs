@@ -0,0 +1,200 @@
+//! Asset Hub: its own assets (USDT) in `Assets`, plus $TCC held as a foreign asset.
+//!
+//! The real Asset Hub keeps foreign assets in a separate pallet keyed by location; here $TCC is
+//! asset `TCC_ON_ASSET_HUB` of the same `Assets` instance, matched by its sibling location.
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Everything, Nothing, PalletInfoAccess},
+};
+use pallet_tcc::cross_chain::{tcc_sibling_location, TrustedReserves};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup, MaybeEquivalence},
+    AccountId32, BuildStorage,
+};
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+
+use super::{techconnect, ASSET_HUB_PARA_ID, TECHCONNECT_PARA_ID};
+use crate::{
+    executor::{self, FungiblesTransactor, Outcome, SovereignSignedViaLocation, XcmExecutor},
+    network::{ParaRouter, Parachain, SiblingOrAccountId32},
+};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+/// Asset id of USDT
+pub const USDT: u32 = 1984;
+/// Asset id of $TCC (foreign)
+pub const TCC_ON_ASSET_HUB: u32 = 200;
+/// Index of `Assets`, as on the real Asset Hub
+pub const ASSETS_PALLET_INDEX: u8 = 50;
+
+parameter_types! {
+    pub const ParaId: u32 = ASSET_HUB_PARA_ID;
+    pub UniversalLocation: InteriorLocation = Parachain(ASSET_HUB_PARA_ID).into();
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets = 50,
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = u64;
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type BlockHashCount = ConstU64<250>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type ReserveIdentifier = ();
+    type FreezeIdentifier = ();
+    type MaxReserves = ();
+    type MaxFreezes = ();
+    type DoneSlashHandler = ();
+}
+
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<5>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Holder = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+}
+
+/// USDT as seen from TechConnect
+pub fn usdt_location() -> Location {
+    Location::new(1, [Parachain(ASSET_HUB_PARA_ID), PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(USDT.into())])
+}
+
+/// $TCC as seen from Asset Hub
+pub fn tcc_location() -> Location {
+    tcc_sibling_location(
+        TECHCONNECT_PARA_ID,
+        <techconnect::Assets as PalletInfoAccess>::index() as u8,
+        techconnect::TCC.into(),
+    )
+}
+
+/// Local assets by `(PalletInstance(50), GeneralIndex(id))`, $TCC by its sibling location
+pub struct AssetHubAssets;
+impl MaybeEquivalence<Location, u32> for AssetHubAssets {
+    fn convert(location: &Location) -> Option<u32> {
+        if *location == tcc_location() {
+            return Some(TCC_ON_ASSET_HUB);
+        }
+        match location.unpack() {
+            (0, [PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(id)]) => {
+                u32::try_from(*id).ok().filter(|id| *id != TCC_ON_ASSET_HUB)
+            },
+            _ => None,
+        }
+    }
+
+    fn convert_back(asset_id: &u32) -> Option<Location> {
+        Some(match *asset_id {
+            TCC_ON_ASSET_HUB => tcc_location(),
+            id => Location::new(0, [PalletInstance(ASSETS_PALLET_INDEX), GeneralIndex(id.into())]),
+        })
+    }
+}
+
+pub struct XcmConfig;
+impl executor::Config for XcmConfig {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type OriginConverter = SovereignSignedViaLocation<SiblingOrAccountId32>;
+    type AssetTransactor = FungiblesTransactor<Assets, AssetHubAssets, SiblingOrAccountId32, AccountId>;
+    // every chain is the reserve of its own assets
+    type IsReserve = TrustedReserves<Everything>;
+    type UniversalLocation = UniversalLocation;
+    type XcmSender = ParaRouter<ParaId>;
+    type UnpaidExecutionFrom = Nothing;
+    type ReserveTransferFilter = Everything;
+}
+
+fn new_ext() -> sp_io::TestExternalities {
+    let admin = AccountId32::new([0u8; 32]);
+    let assets = pallet_assets::GenesisConfig {
+        assets: [USDT, TCC_ON_ASSET_HUB].iter().map(|id| (*id, admin.clone(), true, 1)).collect(),
+        ..Default::default()
+    };
+    let storage = RuntimeGenesisConfig { assets, ..Default::default() }.build_storage().expect("genesis storage");
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+thread_local! {
+    static EXT: RefCell<sp_io::TestExternalities> = RefCell::new(new_ext());
+}
+
+pub struct AssetHub;
+impl Parachain for AssetHub {
+    const PARA_ID: u32 = ASSET_HUB_PARA_ID;
+
+    fn with_state<R>(f: impl FnOnce() -> R) -> R {
+        EXT.with(|ext| ext.borrow_mut().execute_with(f))
+    }
+
+    fn execute(from: u32, message: Xcm<()>) -> Outcome {
+        XcmExecutor::<XcmConfig>::execute(Location::new(1, [Parachain(from)]), message)
+    }
+}
@@ -0,0 +1,169 @@
+//! A partner parachain keeping read-only copies of TechConnect badges.
+//!
+//! `pallet_badge_mirrors` is the sibling pallet `TransactAttestation` is written for: it accepts
+//! `MirrorUpdate`s only from TechConnect's sovereign account and keeps the attested badges.
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, ConstU64, Contains, Everything, Nothing},
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    AccountId32, BuildStorage,
+};
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+
+use super::{MIRROR_SIBLING_PARA_ID, TECHCONNECT_PARA_ID};
+use crate::{
+    executor::{self, Outcome, SovereignSignedViaLocation, XcmExecutor},
+    network::{sibling_account, ParaRouter, Parachain, SiblingOrAccountId32},
+};
+
+pub type AccountId = AccountId32;
+
+#[frame_support::pallet]
+pub mod pallet_badge_mirrors {
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use pallet_badges::{BadgeAttestation, ClassId, InstanceId, MirrorUpdate};
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Sovereign account of the chain badges are mirrored from
+        type Source: Get<Self::AccountId>;
+    }
+
+    /// Badges currently attested by the source chain
+    #[pallet::storage]
+    pub type Mirrored<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        InstanceId,
+        BadgeAttestation<T::AccountId>,
+        OptionQuery,
+    >;
+
+    #[pallet::error]
+    pub enum Error<T> {
+        NotSource,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        pub fn receive(origin: OriginFor<T>, update: MirrorUpdate<T::AccountId>) -> DispatchResult {
+            ensure!(ensure_signed(origin)? == T::Source::get(), Error::<T>::NotSource);
+            match update {
+                MirrorUpdate::Attest(badge) => Mirrored::<T>::insert(badge.class, badge.instance, badge),
+                MirrorUpdate::Retract { class, instance } => Mirrored::<T>::remove(class, instance),
+            }
+            Ok(())
+        }
+    }
+}
+
+parameter_types! {
+    pub const ParaId: u32 = MIRROR_SIBLING_PARA_ID;
+    pub UniversalLocation: InteriorLocation = Parachain(MIRROR_SIBLING_PARA_ID).into();
+    pub TechConnectSovereign: AccountId = sibling_account(TECHCONNECT_PARA_ID);
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        BadgeMirrors: pallet_badge_mirrors = 60,
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = u64;
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type BlockHashCount = ConstU64<250>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_badge_mirrors::Config for Runtime {
+    type Source = TechConnectSovereign;
+}
+
+/// TechConnect may send unpaid messages (the `TransactAttestation` contract)
+pub struct FromTechConnect;
+impl Contains<Location> for FromTechConnect {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, [Parachain(TECHCONNECT_PARA_ID)]))
+    }
+}
+
+pub struct XcmConfig;
+impl executor::Config for XcmConfig {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type OriginConverter = SovereignSignedViaLocation<SiblingOrAccountId32>;
+    type AssetTransactor = ();
+    type IsReserve = Nothing;
+    type UniversalLocation = UniversalLocation;
+    type XcmSender = ParaRouter<ParaId>;
+    type UnpaidExecutionFrom = FromTechConnect;
+    type ReserveTransferFilter = Nothing;
+}
+
+fn new_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().expect("frame_system storage");
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+thread_local! {
+    static EXT: RefCell<sp_io::TestExternalities> = RefCell::new(new_ext());
+}
+
+pub struct MirrorSibling;
+impl Parachain for MirrorSibling {
+    const PARA_ID: u32 = MIRROR_SIBLING_PARA_ID;
+
+    fn with_state<R>(f: impl FnOnce() -> R) -> R {
+        EXT.with(|ext| ext.borrow_mut().execute_with(f))
+    }
+
+    fn execute(from: u32, message: Xcm<()>) -> Outcome {
+        XcmExecutor::<XcmConfig>::execute(Location::new(1, [Parachain(from)]), message)
+    }
+}
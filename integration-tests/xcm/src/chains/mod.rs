@@ -0,0 +1,33 @@
+//! The emulated network: TechConnect, Asset Hub and the mirror sibling.
+//!
+//! Every test runs on its own thread, so every test starts from fresh chain states.
+
+pub mod asset_hub;
+pub mod mirror_sibling;
+pub mod techconnect;
+
+pub use asset_hub::AssetHub;
+pub use mirror_sibling::MirrorSibling;
+pub use techconnect::TechConnect;
+
+use crate::{
+    executor::Outcome,
+    network::{Network, Parachain},
+};
+use xcm::latest::Xcm;
+
+pub use pallet_tcc::cross_chain::ASSET_HUB_PARA_ID;
+pub const TECHCONNECT_PARA_ID: u32 = 2000;
+pub const MIRROR_SIBLING_PARA_ID: u32 = 3000;
+
+pub struct Net;
+impl Network for Net {
+    fn deliver(from: u32, to: u32, message: Xcm<()>) -> Option<Outcome> {
+        match to {
+            ASSET_HUB_PARA_ID => Some(AssetHub::with_state(|| AssetHub::execute(from, message))),
+            TECHCONNECT_PARA_ID => Some(TechConnect::with_state(|| TechConnect::execute(from, message))),
+            MIRROR_SIBLING_PARA_ID => Some(MirrorSibling::with_state(|| MirrorSibling::execute(from, message))),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! TechConnect: badges, mirrored to the siblings in `MirrorDestinations`, and $TCC with the
+//! runtime's cross-chain policy (`pallet_tcc::cross_chain`): reserve transfers of $TCC only, to
+//! Asset Hub and whitelisted siblings; reserves trusted for their own assets; foreign assets
+//! deposited once governance registered them.
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Contains, Equals, Everything, Nothing, PalletInfoAccess, VariantCountOf},
+    weights::Weight,
+    PalletId,
+};
+use pallet_tcc::cross_chain::{AssetHubAndSiblings, ForeignAssetIds, TccReserveTransfers, TrustedReserves};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup, MaybeEquivalence},
+    AccountId32, BuildStorage,
+};
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+
+use super::{MIRROR_SIBLING_PARA_ID, TECHCONNECT_PARA_ID};
+use crate::{
+    executor::{self, FungiblesTransactor, Outcome, SovereignSignedViaLocation, XcmExecutor},
+    network::{ParaRouter, Parachain, SiblingOrAccountId32},
+};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+
+/// Asset id of $TCC in `Assets`
+pub const TCC: u32 = 1;
+
+/// Siblings $TCC may be reserve-transferred to besides Asset Hub, like the runtime's
+/// `WHITELISTED_SIBLINGS`
+pub const WHITELISTED_SIBLINGS: &[u32] = &[MIRROR_SIBLING_PARA_ID];
+
+parameter_types! {
+    pub const ParaId: u32 = TECHCONNECT_PARA_ID;
+    pub UniversalLocation: InteriorLocation = Parachain(TECHCONNECT_PARA_ID).into();
+    pub const RelayLocation: Location = Location::parent();
+    pub TccLocation: Location = pallet_tcc::cross_chain::tcc_local_location(
+        <Assets as PalletInfoAccess>::index() as u8,
+        TCC.into(),
+    );
+    pub const TccAssetId: u32 = TCC;
+    pub const TccPalletId: PalletId = PalletId(*b"tcc/pots");
+    /// Call index of `BadgeMirrors::receive` on the mirror sibling
+    pub const MirrorCallIndex: [u8; 2] = [60, 0];
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Badges: pallet_badges,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        Tcc: pallet_tcc,
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = u64;
+    type Block = frame_system::mocking::MockBlock<Runtime>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type BlockHashCount = ConstU64<250>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+/// The mirror sibling
+pub struct MirrorSiblings;
+impl Contains<Location> for MirrorSiblings {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, [Parachain(MIRROR_SIBLING_PARA_ID)]))
+    }
+}
+
+impl pallet_badges::Config for Runtime {
+    type ClassCreationOrigin = frame_system::EnsureSigned<AccountId>;
+    type TimeProvider = Timestamp;
+    type MaxClasses = ConstU32<100>;
+    type MaxInstancesPerClass = ConstU32<100>;
+    type MaxMetadataLen = ConstU32<64>;
+    type MaxClassesPerAccount = ConstU32<10>;
+    type MaxPrerequisites = ConstU32<4>;
+    type MaxAutoAwardDependents = ConstU32<4>;
+    type MaxBatchIssuance = ConstU32<8>;
+    type MaxClaimProofLength = ConstU32<16>;
+    type MaxMetadataRevisions = ConstU32<2>;
+    type Endorsers = Nothing;
+    type MaxEndorsementsPerInstance = ConstU32<2>;
+    type ReassignOrigin = frame_system::EnsureRoot<AccountId>;
+    type ReassignmentDelay = ConstU64<10>;
+    type OfferExpiry = ConstU64<5>;
+    type IssuanceEpochLength = ConstU64<10>;
+    type BadgeHooks = ();
+    type XcmSender = ParaRouter<ParaId>;
+    type MirrorDestinations = MirrorSiblings;
+    type MirrorMessage = pallet_badges::TransactAttestation<MirrorCallIndex>;
+    type MirrorReleaseOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxMirrorsPerBadge = ConstU32<2>;
+    type NftBackend = ();
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type ReserveIdentifier = ();
+    type FreezeIdentifier = ();
+    type MaxReserves = ();
+    type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+    type DoneSlashHandler = ();
+}
+
+// Deposits are zero so accounts need no native balance to hold assets.
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<5>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = Tcc;
+    type Holder = Tcc;
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+}
+
+pub struct ZeroWeight;
+impl pallet_tcc::WeightInfo for ZeroWeight {
+    fn instantiate_asset() -> Weight { Weight::zero() }
+    fn mint() -> Weight { Weight::zero() }
+    fn burn() -> Weight { Weight::zero() }
+    fn transfer() -> Weight { Weight::zero() }
+    fn set_max_supply() -> Weight { Weight::zero() }
+    fn set_mint_budget() -> Weight { Weight::zero() }
+    fn execute_mint() -> Weight { Weight::zero() }
+    fn cancel_mint() -> Weight { Weight::zero() }
+    fn pause() -> Weight { Weight::zero() }
+    fn unpause() -> Weight { Weight::zero() }
+    fn freeze_account() -> Weight { Weight::zero() }
+    fn thaw_account() -> Weight { Weight::zero() }
+    fn vest() -> Weight { Weight::zero() }
+    fn vest_other() -> Weight { Weight::zero() }
+    fn create_vesting() -> Weight { Weight::zero() }
+    fn terminate_vesting() -> Weight { Weight::zero() }
+    fn register_foreign_asset() -> Weight { Weight::zero() }
+    fn deregister_foreign_asset() -> Weight { Weight::zero() }
+}
+
+impl pallet_tcc::Config for Runtime {
+    type AssetId = u32;
+    type Balance = Balance;
+    type TccAssetId = TccAssetId;
+    type Assets = Assets;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type PalletId = TccPalletId;
+    type SupplyCapOrigin = frame_system::EnsureRoot<AccountId>;
+    type VestingOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxVestingSchedules = ConstU32<8>;
+    type InstantiateOrigin = frame_system::EnsureRoot<AccountId>;
+    type MintRole = ();
+    type MintOrigin = frame_system::EnsureRoot<AccountId>;
+    type MintEpochLength = ConstU64<100>;
+    type LargeMintThreshold = ConstU128<{ u128::MAX }>;
+    type MintDelay = ConstU64<10>;
+    type GuardianOrigin = frame_system::EnsureRoot<AccountId>;
+    type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+    type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+    type BurnOrigin = frame_system::EnsureRoot<AccountId>;
+    type ForeignAssetOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = ZeroWeight;
+}
+
+pub struct WhitelistedSiblings;
+impl Contains<u32> for WhitelistedSiblings {
+    fn contains(para_id: &u32) -> bool {
+        WHITELISTED_SIBLINGS.contains(para_id)
+    }
+}
+
+/// Destinations of $TCC reserve transfers, like the runtime's `TccDestinations`
+pub type TccDestinations = AssetHubAndSiblings<WhitelistedSiblings>;
+
+/// $TCC, by its canonical location
+pub struct IsTcc;
+impl MaybeEquivalence<Location, u32> for IsTcc {
+    fn convert(location: &Location) -> Option<u32> {
+        (*location == TccLocation::get()).then_some(TCC)
+    }
+
+    fn convert_back(asset_id: &u32) -> Option<Location> {
+        (*asset_id == TCC).then(TccLocation::get)
+    }
+}
+
+/// $TCC and registered foreign assets, like the runtime's `TccAssetTransactor` and
+/// `ForeignAssetTransactor`
+pub type AssetTransactors = (
+    FungiblesTransactor<Assets, IsTcc, SiblingOrAccountId32, AccountId>,
+    FungiblesTransactor<Assets, ForeignAssetIds<Runtime>, SiblingOrAccountId32, AccountId>,
+);
+
+pub struct XcmConfig;
+impl executor::Config for XcmConfig {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type OriginConverter = SovereignSignedViaLocation<SiblingOrAccountId32>;
+    type AssetTransactor = AssetTransactors;
+    type IsReserve = TrustedReserves<(Equals<RelayLocation>, TccDestinations)>;
+    type UniversalLocation = UniversalLocation;
+    type XcmSender = ParaRouter<ParaId>;
+    type UnpaidExecutionFrom = Nothing;
+    type ReserveTransferFilter = TccReserveTransfers<TccLocation, TccDestinations>;
+}
+
+fn new_ext() -> sp_io::TestExternalities {
+    let tcc = pallet_tcc::GenesisConfig { create_asset: true, min_balance: 1, ..Default::default() };
+    let storage = RuntimeGenesisConfig { tcc, ..Default::default() }.build_storage().expect("genesis storage");
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(1);
+    });
+    ext
+}
+
+thread_local! {
+    static EXT: RefCell<sp_io::TestExternalities> = RefCell::new(new_ext());
+}
+
+pub struct TechConnect;
+impl Parachain for TechConnect {
+    const PARA_ID: u32 = TECHCONNECT_PARA_ID;
+
+    fn with_state<R>(f: impl FnOnce() -> R) -> R {
+        EXT.with(|ext| ext.borrow_mut().execute_with(f))
+    }
+
+    fn execute(from: u32, message: Xcm<()>) -> Outcome {
+        XcmExecutor::<XcmConfig>::execute(Location::new(1, [Parachain(from)]), message)
+    }
+}
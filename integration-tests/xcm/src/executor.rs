@@ -0,0 +1,360 @@
+//! The part of the XCM executor the TechConnect messages need.
+//!
+//! Mirrors `xcm_executor::XcmExecutor`: a barrier decides whether a message from another chain may
+//! run at all, then its instructions run in order, each in a storage transaction rolled back if it
+//! fails. Execution stops at the first error; assets still in holding then (or left over at the
+//! end) are trapped. Instructions outside the supported subset fail with `Unimplemented`.
+//!
+//! Supported: `UnpaidExecution` (first instruction, from `UnpaidExecutionFrom` origins),
+//! `WithdrawAsset`, `ReserveAssetDeposited` (from trusted reserves only), `BuyExecution` (free),
+//! `DepositAsset`, `DepositReserveAsset`, `InitiateReserveWithdraw`, `Transact`, `ClearOrigin` and
+//! `SetTopic` (ignored). [`XcmExecutor::reserve_transfer`] builds the local message of pallet-xcm's
+//! `limited_reserve_transfer_assets`.
+
+use codec::Decode;
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::PostDispatchInfo,
+    traits::{
+        fungibles,
+        tokens::{Fortitude, Precision, Preservation},
+        Contains, ContainsPair, Get, OriginTrait,
+    },
+};
+use sp_runtime::{
+    traits::{Dispatchable, MaybeEquivalence},
+    DispatchResult,
+};
+use xcm::latest::prelude::*;
+
+/// Per-chain configuration, like `xcm_executor::Config`
+pub trait Config {
+    type RuntimeOrigin: OriginTrait;
+    type RuntimeCall: Decode + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>;
+
+    /// Origins `Transact` may dispatch with
+    type OriginConverter: ConvertOrigin<Self::RuntimeOrigin>;
+
+    /// How assets are withdrawn and deposited
+    type AssetTransactor: TransactAsset;
+
+    /// Origins trusted as reserve of an asset (`ReserveAssetDeposited`)
+    type IsReserve: ContainsPair<Asset, Location>;
+
+    /// This chain, to reanchor assets sent elsewhere
+    type UniversalLocation: Get<InteriorLocation>;
+
+    /// Router of the messages this chain sends
+    type XcmSender: SendXcm;
+
+    /// Origins whose messages may start with `UnpaidExecution`
+    type UnpaidExecutionFrom: Contains<Location>;
+
+    /// Reserve transfers allowed by `reserve_transfer`, like pallet-xcm's `XcmReserveTransferFilter`
+    type ReserveTransferFilter: Contains<(Location, Vec<Asset>)>;
+}
+
+/// Local origin of a `Transact` from `origin`, like `xcm_executor::traits::ConvertOrigin`
+pub trait ConvertOrigin<RuntimeOrigin> {
+    fn convert_origin(origin: &Location, kind: OriginKind) -> Option<RuntimeOrigin>;
+}
+
+/// Local account of a location, like `xcm_executor::traits::ConvertLocation`
+pub trait LocationToAccount<AccountId> {
+    fn account(location: &Location) -> Option<AccountId>;
+}
+
+/// Moves assets in and out of the accounts of locations, like `xcm_executor::traits::TransactAsset`.
+/// A transactor that doesn't handle an asset fails with `AssetNotFound`, so a pair tries the next.
+pub trait TransactAsset {
+    fn deposit_asset(what: &Asset, who: &Location) -> Result<(), XcmError>;
+    fn withdraw_asset(what: &Asset, who: &Location) -> Result<(), XcmError>;
+}
+
+impl TransactAsset for () {
+    fn deposit_asset(_: &Asset, _: &Location) -> Result<(), XcmError> {
+        Err(XcmError::AssetNotFound)
+    }
+
+    fn withdraw_asset(_: &Asset, _: &Location) -> Result<(), XcmError> {
+        Err(XcmError::AssetNotFound)
+    }
+}
+
+impl<A: TransactAsset, B: TransactAsset> TransactAsset for (A, B) {
+    fn deposit_asset(what: &Asset, who: &Location) -> Result<(), XcmError> {
+        match A::deposit_asset(what, who) {
+            Err(XcmError::AssetNotFound) => B::deposit_asset(what, who),
+            result => result,
+        }
+    }
+
+    fn withdraw_asset(what: &Asset, who: &Location) -> Result<(), XcmError> {
+        match A::withdraw_asset(what, who) {
+            Err(XcmError::AssetNotFound) => B::withdraw_asset(what, who),
+            result => result,
+        }
+    }
+}
+
+/// Fungible assets of `Fungibles` whose location `Matcher` converts to an asset id, minted on
+/// deposit and burned on withdrawal; like `xcm_builder::FungiblesAdapter` with `NoChecking`.
+pub struct FungiblesTransactor<Fungibles, Matcher, Accounts, AccountId>(
+    PhantomData<(Fungibles, Matcher, Accounts, AccountId)>,
+);
+
+impl<Fungibles, Matcher, Accounts, AccountId> FungiblesTransactor<Fungibles, Matcher, Accounts, AccountId>
+where
+    Fungibles: fungibles::Mutate<AccountId>,
+    Fungibles::Balance: TryFrom<u128>,
+    Matcher: MaybeEquivalence<Location, Fungibles::AssetId>,
+    Accounts: LocationToAccount<AccountId>,
+    AccountId: Eq,
+{
+    fn matched(what: &Asset, who: &Location) -> Result<(Fungibles::AssetId, Fungibles::Balance, AccountId), XcmError> {
+        let (Fungible(amount), Some(asset_id)) = (&what.fun, Matcher::convert(&what.id.0)) else {
+            return Err(XcmError::AssetNotFound);
+        };
+        let amount = (*amount).try_into().map_err(|_| XcmError::Overflow)?;
+        let account = Accounts::account(who).ok_or(XcmError::FailedToTransactAsset("unknown account"))?;
+        Ok((asset_id, amount, account))
+    }
+}
+
+impl<Fungibles, Matcher, Accounts, AccountId> TransactAsset for FungiblesTransactor<Fungibles, Matcher, Accounts, AccountId>
+where
+    Fungibles: fungibles::Mutate<AccountId>,
+    Fungibles::Balance: TryFrom<u128>,
+    Matcher: MaybeEquivalence<Location, Fungibles::AssetId>,
+    Accounts: LocationToAccount<AccountId>,
+    AccountId: Eq,
+{
+    fn deposit_asset(what: &Asset, who: &Location) -> Result<(), XcmError> {
+        let (asset_id, amount, account) = Self::matched(what, who)?;
+        Fungibles::mint_into(asset_id, &account, amount)
+            .map(|_| ())
+            .map_err(|_| XcmError::FailedToTransactAsset("mint failed"))
+    }
+
+    fn withdraw_asset(what: &Asset, who: &Location) -> Result<(), XcmError> {
+        let (asset_id, amount, account) = Self::matched(what, who)?;
+        Fungibles::burn_from(asset_id, &account, amount, Preservation::Expendable, Precision::Exact, Fortitude::Polite)
+            .map(|_| ())
+            .map_err(|_| XcmError::FailedToTransactAsset("burn failed"))
+    }
+}
+
+/// `OriginKind::SovereignAccount` dispatches as the signed account of the origin
+pub struct SovereignSignedViaLocation<Accounts>(PhantomData<Accounts>);
+
+impl<RuntimeOrigin, Accounts> ConvertOrigin<RuntimeOrigin> for SovereignSignedViaLocation<Accounts>
+where
+    RuntimeOrigin: OriginTrait,
+    Accounts: LocationToAccount<RuntimeOrigin::AccountId>,
+{
+    fn convert_origin(origin: &Location, kind: OriginKind) -> Option<RuntimeOrigin> {
+        match kind {
+            OriginKind::SovereignAccount => Accounts::account(origin).map(RuntimeOrigin::signed),
+            _ => None,
+        }
+    }
+}
+
+/// What executing a message did
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    /// Error of the instruction that failed, if any (only that instruction was rolled back)
+    pub result: Result<(), XcmError>,
+    /// Result of every call dispatched by `Transact`; a failed call does not fail the message
+    pub dispatched: Vec<DispatchResult>,
+    /// Assets left in holding, which the real executor hands to the asset trap
+    pub trapped: Vec<Asset>,
+}
+
+impl Outcome {
+    /// The message ran to the end and every call it dispatched succeeded
+    pub fn is_complete(&self) -> bool {
+        self.result.is_ok() && self.dispatched.iter().all(Result::is_ok)
+    }
+
+    fn error(error: XcmError) -> Self {
+        Outcome { result: Err(error), dispatched: Vec::new(), trapped: Vec::new() }
+    }
+}
+
+/// Executes messages with the configuration `C`
+pub struct XcmExecutor<C>(PhantomData<C>);
+
+impl<C: Config> XcmExecutor<C> {
+    /// Execute a message another chain sent from `origin` (relative to this chain)
+    pub fn execute(origin: Location, message: Xcm<()>) -> Outcome {
+        match Self::barrier(&origin, &message) {
+            Ok(()) => Self::execute_local(origin, message),
+            Err(error) => Outcome::error(error),
+        }
+    }
+
+    /// Execute a message built on this chain on behalf of `origin`, as pallet-xcm does: no barrier
+    pub fn execute_local(origin: Location, message: Xcm<()>) -> Outcome {
+        let mut state = State { origin: Some(origin), holding: Vec::new(), dispatched: Vec::new() };
+        let mut result = Ok(());
+        for (index, instruction) in message.0.into_iter().enumerate() {
+            sp_io::storage::start_transaction();
+            result = Self::process(&mut state, index, instruction);
+            match result {
+                Ok(()) => sp_io::storage::commit_transaction(),
+                Err(_) => {
+                    sp_io::storage::rollback_transaction();
+                    break;
+                },
+            }
+        }
+        Outcome { result, dispatched: state.dispatched, trapped: state.holding }
+    }
+
+    /// Reserve-transfer `assets` of the local `origin` to `beneficiary` (relative to `dest`), like
+    /// pallet-xcm's `limited_reserve_transfer_assets`: local assets are deposited in the sovereign
+    /// account of `dest`, assets of `dest` are withdrawn from ours there. Transfers outside
+    /// `ReserveTransferFilter` fail with `NoPermission`, transfers through a third reserve with
+    /// `Unimplemented`.
+    pub fn reserve_transfer(origin: Location, dest: Location, beneficiary: Location, assets: Vec<Asset>) -> Outcome {
+        if !C::ReserveTransferFilter::contains(&(dest.clone(), assets.clone())) {
+            return Outcome::error(XcmError::NoPermission);
+        }
+        let Some(fees) = assets.first().and_then(|fees| fees.clone().reanchored(&dest, &C::UniversalLocation::get()).ok())
+        else {
+            return Outcome::error(XcmError::NotHoldingFees);
+        };
+        let all = Wild(AllCounted(assets.len() as u32));
+        let remote = Xcm(vec![
+            BuyExecution { fees, weight_limit: Unlimited },
+            DepositAsset { assets: all.clone(), beneficiary },
+        ]);
+
+        let transfer = if assets.iter().all(|asset| asset.id.0.parent_count() == 0) {
+            DepositReserveAsset { assets: all, dest, xcm: remote }
+        } else if assets.iter().all(|asset| asset.id.0.chain_location() == dest) {
+            InitiateReserveWithdraw { assets: all, reserve: dest, xcm: remote }
+        } else {
+            return Outcome::error(XcmError::Unimplemented);
+        };
+        Self::execute_local(origin, Xcm(vec![WithdrawAsset(assets.into()), transfer]))
+    }
+
+    fn barrier(origin: &Location, message: &Xcm<()>) -> Result<(), XcmError> {
+        match message.0.as_slice() {
+            [UnpaidExecution { .. }, ..] if C::UnpaidExecutionFrom::contains(origin) => Ok(()),
+            // like `AllowTopLevelPaidExecutionFrom<Everything>`
+            [WithdrawAsset(_) | ReserveAssetDeposited(_), ClearOrigin, BuyExecution { .. }, ..]
+            | [WithdrawAsset(_) | ReserveAssetDeposited(_), BuyExecution { .. }, ..] => Ok(()),
+            _ => Err(XcmError::Barrier),
+        }
+    }
+
+    fn process(state: &mut State, index: usize, instruction: Instruction<()>) -> Result<(), XcmError> {
+        match instruction {
+            UnpaidExecution { .. } if index == 0 => {},
+            WithdrawAsset(assets) => {
+                let origin = state.origin.as_ref().ok_or(XcmError::BadOrigin)?;
+                for asset in assets.inner() {
+                    C::AssetTransactor::withdraw_asset(asset, origin)?;
+                }
+                assets.into_inner().into_iter().for_each(|asset| state.hold(asset));
+            },
+            ReserveAssetDeposited(assets) => {
+                let origin = state.origin.as_ref().ok_or(XcmError::BadOrigin)?;
+                if !assets.inner().iter().all(|asset| C::IsReserve::contains(asset, origin)) {
+                    return Err(XcmError::UntrustedReserveLocation);
+                }
+                assets.into_inner().into_iter().for_each(|asset| state.hold(asset));
+            },
+            BuyExecution { .. } => {},
+            DepositAsset { assets, beneficiary } => state.with_taken(assets, |assets| {
+                assets.iter().try_for_each(|asset| C::AssetTransactor::deposit_asset(asset, &beneficiary))
+            })?,
+            DepositReserveAsset { assets, dest, xcm } => state.with_taken(assets, |assets| {
+                let reanchored = Self::reanchored(assets, &dest)?;
+                assets.iter().try_for_each(|asset| C::AssetTransactor::deposit_asset(asset, &dest))?;
+                Self::send(dest, ReserveAssetDeposited(reanchored), xcm)
+            })?,
+            InitiateReserveWithdraw { assets, reserve, xcm } => state.with_taken(assets, |assets| {
+                let reanchored = Self::reanchored(assets, &reserve)?;
+                Self::send(reserve, WithdrawAsset(reanchored), xcm)
+            })?,
+            Transact { origin_kind, call, .. } => {
+                let origin = state.origin.as_ref().ok_or(XcmError::BadOrigin)?;
+                let dispatch_origin =
+                    C::OriginConverter::convert_origin(origin, origin_kind).ok_or(XcmError::BadOrigin)?;
+                let call =
+                    C::RuntimeCall::decode(&mut &call.into_encoded()[..]).map_err(|_| XcmError::FailedToDecode)?;
+                state.dispatched.push(call.dispatch(dispatch_origin).map(|_| ()).map_err(|e| e.error));
+            },
+            ClearOrigin => state.origin = None,
+            SetTopic(_) => {},
+            _ => return Err(XcmError::Unimplemented),
+        }
+        Ok(())
+    }
+
+    /// `assets` as seen from `dest`
+    fn reanchored(assets: &[Asset], dest: &Location) -> Result<Assets, XcmError> {
+        let context = C::UniversalLocation::get();
+        let assets = assets
+            .iter()
+            .map(|asset| asset.clone().reanchored(dest, &context).map_err(|()| XcmError::ReanchorFailed))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(assets.into())
+    }
+
+    /// Send `first`, `ClearOrigin`, then `xcm` to `dest`, like the executor's reserve instructions
+    fn send(dest: Location, first: Instruction<()>, xcm: Xcm<()>) -> Result<(), XcmError> {
+        let message = Xcm([first, ClearOrigin].into_iter().chain(xcm.0).collect());
+        send_xcm::<C::XcmSender>(dest, message).map(|_| ()).map_err(XcmError::from)
+    }
+}
+
+/// Executor registers while a message runs
+struct State {
+    origin: Option<Location>,
+    holding: Vec<Asset>,
+    dispatched: Vec<DispatchResult>,
+}
+
+impl State {
+    /// Add `asset` to holding, merged with what is held of it already
+    fn hold(&mut self, asset: Asset) {
+        match (self.holding.iter_mut().find(|held| held.id == asset.id), asset.fun) {
+            (Some(Asset { fun: Fungible(held), .. }), Fungible(amount)) => *held = held.saturating_add(amount),
+            (_, fun) => self.holding.push(Asset { id: asset.id, fun }),
+        }
+    }
+
+    /// Take `filter` out of holding (all of it for a wildcard, at most the listed amounts
+    /// otherwise) and pass it to `f`; what `f` fails on goes back to holding.
+    fn with_taken(
+        &mut self,
+        filter: AssetFilter,
+        f: impl FnOnce(&[Asset]) -> Result<(), XcmError>,
+    ) -> Result<(), XcmError> {
+        let taken = match filter {
+            Wild(_) => core::mem::take(&mut self.holding),
+            Definite(wanted) => wanted
+                .into_inner()
+                .into_iter()
+                .filter_map(|wanted| {
+                    let Fungible(wanted_amount) = wanted.fun else { return None };
+                    let Some(Asset { fun: Fungible(held), .. }) =
+                        self.holding.iter_mut().find(|held| held.id == wanted.id)
+                    else {
+                        return None;
+                    };
+                    let amount = wanted_amount.min(*held);
+                    *held -= amount;
+                    Some(Asset { id: wanted.id, fun: Fungible(amount) })
+                })
+                .collect(),
+        };
+        self.holding.retain(|held| held.fun != Fungible(0));
+        f(&taken).inspect_err(|_| taken.into_iter().for_each(|asset| self.hold(asset)))
+    }
+}
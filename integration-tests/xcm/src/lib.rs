@@ -0,0 +1,22 @@
+//! Cross-chain tests of the TechConnect pallets on an in-repo XCM network.
+//!
+//! `xcm-emulator` (like `xcm-builder`, `xcm-executor` and `pallet-xcm`) is only published against
+//! an older `frame-support` than this workspace, so it can't be linked here. This crate stands in
+//! for it:
+//! - [`network`]: parachains as mock runtimes, each with its own state, and a message bus their
+//!   routers push to. Messages are executed on the destination after every `execute_with`.
+//! - [`executor`]: the subset of the XCM executor the TechConnect messages use (asset transfers,
+//!   reserve transfers and `Transact`), configured per chain like `xcm_executor::Config`.
+//!
+//! The chains (`chains`) run the real pallets with the policy types the runtime's `xcm_config`
+//! uses; the tests (`tests`) drive them end to end. Execution is free: `BuyExecution` is accepted
+//! without charging, and weights are not metered.
+
+pub mod executor;
+pub mod network;
+
+#[cfg(test)]
+mod chains;
+
+#[cfg(test)]
+mod tests;
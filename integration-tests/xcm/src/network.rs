@@ -0,0 +1,119 @@
+//! Parachains and the message bus between them.
+//!
+//! Each chain implements [`Parachain`] over its own `TestExternalities`; its XCM router is a
+//! [`ParaRouter`], which queues messages on the bus instead of delivering them. A [`Network`] maps
+//! para ids to chains; [`Network::execute_with`] runs a closure on one chain and then executes
+//! every queued message on its destination (and whatever those send in turn) until the bus is
+//! empty.
+
+use codec::Encode;
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use sp_runtime::AccountId32;
+use std::{cell::RefCell, collections::VecDeque};
+use xcm::latest::prelude::*;
+
+use crate::executor::{LocationToAccount, Outcome};
+
+/// A message in flight: (sender para id, destination para id, message)
+pub type Envelope = (u32, u32, Xcm<()>);
+
+thread_local! {
+    static BUS: RefCell<VecDeque<Envelope>> = const { RefCell::new(VecDeque::new()) };
+    static PROCESSED: RefCell<Vec<(u32, Xcm<()>, Outcome)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A parachain of the network
+pub trait Parachain {
+    const PARA_ID: u32;
+
+    /// Run `f` on this chain's state, without delivering the messages it sends
+    fn with_state<R>(f: impl FnOnce() -> R) -> R;
+
+    /// Execute a message from sibling `from`; called within `with_state`
+    fn execute(from: u32, message: Xcm<()>) -> Outcome;
+
+    /// This chain as seen from its siblings
+    fn sibling_location() -> Location {
+        Location::new(1, [Parachain(Self::PARA_ID)])
+    }
+}
+
+/// The chains of a network, by para id
+pub trait Network {
+    /// Execute `message` on the chain `to`. None if the network has no such chain.
+    fn deliver(from: u32, to: u32, message: Xcm<()>) -> Option<Outcome>;
+
+    /// Run `f` on `C`, then deliver messages until the bus is empty.
+    fn execute_with<C: Parachain, R>(f: impl FnOnce() -> R) -> R {
+        let result = C::with_state(f);
+        Self::dispatch();
+        result
+    }
+
+    /// Deliver queued messages until the bus is empty. Messages to unknown chains are dropped.
+    fn dispatch() {
+        while let Some((from, to, message)) = BUS.with(|bus| bus.borrow_mut().pop_front()) {
+            if let Some(outcome) = Self::deliver(from, to, message.clone()) {
+                PROCESSED.with(|p| p.borrow_mut().push((to, message, outcome)));
+            }
+        }
+    }
+}
+
+/// Messages executed so far: (destination para id, message, outcome)
+pub fn processed() -> Vec<(u32, Xcm<()>, Outcome)> {
+    PROCESSED.with(|p| p.borrow().clone())
+}
+
+/// Forget the messages executed so far and drop any still queued
+pub fn reset() {
+    BUS.with(|bus| bus.borrow_mut().clear());
+    PROCESSED.with(|p| p.borrow_mut().clear());
+}
+
+/// Router of the chain `ParaId`: queues messages to siblings (`(1, [Parachain(id)])`) on the bus.
+pub struct ParaRouter<ParaId>(PhantomData<ParaId>);
+
+impl<ParaId: Get<u32>> SendXcm for ParaRouter<ParaId> {
+    type Ticket = (u32, Xcm<()>);
+
+    fn validate(dest: &mut Option<Location>, message: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+        let to = match dest.as_ref().map(|d| d.unpack()) {
+            Some((1, [Parachain(id)])) => *id,
+            _ => return Err(SendError::NotApplicable),
+        };
+        let message = message.take().ok_or(SendError::MissingArgument)?;
+        *dest = None;
+        Ok(((to, message), Assets::new()))
+    }
+
+    fn deliver((to, message): Self::Ticket) -> Result<XcmHash, SendError> {
+        let hash = message.using_encoded(sp_io::hashing::blake2_256);
+        BUS.with(|bus| bus.borrow_mut().push_back((ParaId::get(), to, message)));
+        Ok(hash)
+    }
+}
+
+/// Accounts of locations the way the runtime's `LocationToAccountId` derives them: local
+/// `AccountId32` junctions alias the account, siblings get their sovereign account
+/// (`b"sibl" ++ para id`, zero padded).
+pub struct SiblingOrAccountId32;
+
+impl LocationToAccount<AccountId32> for SiblingOrAccountId32 {
+    fn account(location: &Location) -> Option<AccountId32> {
+        match location.unpack() {
+            (0, [Junction::AccountId32 { id, .. }]) => Some((*id).into()),
+            (1, [Parachain(id)]) => Some(sibling_account(*id)),
+            _ => None,
+        }
+    }
+}
+
+/// Sovereign account of sibling `para_id`
+pub fn sibling_account(para_id: u32) -> AccountId32 {
+    let mut account = [0u8; 32];
+    let encoded = (b"sibl", para_id).encode();
+    account[..encoded.len()].copy_from_slice(&encoded);
+    account.into()
+}
@@ -0,0 +1,93 @@
+//! Badge mirrors: `TransactAttestation` messages received and executed on the mirror sibling.
+
+use codec::Encode;
+use frame_support::{assert_ok, traits::PalletInfoAccess};
+use frame_system::RawOrigin;
+use pallet_badges::{ClassId, MirrorUpdate};
+use sp_runtime::AccountId32;
+use xcm::{latest::prelude::*, VersionedLocation};
+
+use super::{ALICE, BOB, ISSUER};
+use crate::{
+    chains::{
+        mirror_sibling::{pallet_badge_mirrors::Mirrored, BadgeMirrors, Runtime as Sibling},
+        techconnect::Badges,
+        MirrorSibling, Net, TechConnect, MIRROR_SIBLING_PARA_ID, TECHCONNECT_PARA_ID,
+    },
+    network::{processed, Network, Parachain},
+};
+
+fn sibling() -> Box<VersionedLocation> {
+    Box::new(MirrorSibling::sibling_location().into())
+}
+
+/// Create a class and issue its first badge to ALICE
+fn issue_to_alice(soulbound: bool) -> ClassId {
+    let class = Badges::next_class_id();
+    assert_ok!(Badges::create_class(RawOrigin::Signed(ISSUER).into(), [1u8; 32], None, !soulbound, soulbound));
+    assert_ok!(Badges::issue_badge(RawOrigin::Signed(ISSUER).into(), class, ALICE, [2u8; 32], None, None));
+    class
+}
+
+fn mirrored_owner(class: ClassId) -> Option<AccountId32> {
+    MirrorSibling::with_state(|| Mirrored::<Sibling>::get(class, 0).map(|b| b.owner))
+}
+
+#[test]
+fn mirrored_badges_are_attested_on_the_sibling() {
+    let class = Net::execute_with::<TechConnect, _>(|| {
+        let class = issue_to_alice(false);
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, sibling()));
+        class
+    });
+
+    let executed = processed();
+    assert_eq!(executed.len(), 1);
+    assert_eq!(executed[0].0, MIRROR_SIBLING_PARA_ID);
+    assert!(executed[0].2.is_complete(), "{:?}", executed[0].2);
+    let attestation = MirrorSibling::with_state(|| Mirrored::<Sibling>::get(class, 0))
+        .expect("the sibling keeps the attestation");
+    assert_eq!((attestation.owner, attestation.issuer, attestation.uri_hash), (ALICE, ISSUER, [2u8; 32]));
+}
+
+#[test]
+fn reassignment_and_revocation_reach_the_mirror() {
+    let class = Net::execute_with::<TechConnect, _>(|| {
+        let class = issue_to_alice(true);
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, sibling()));
+        class
+    });
+    assert_eq!(mirrored_owner(class), Some(ALICE));
+
+    Net::execute_with::<TechConnect, _>(|| {
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Root.into(), class, 0, BOB, [9u8; 32]));
+    });
+    assert_eq!(mirrored_owner(class), Some(BOB));
+
+    Net::execute_with::<TechConnect, _>(|| {
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(ISSUER).into(), class, 0));
+    });
+    assert_eq!(mirrored_owner(class), None);
+    assert!(processed().iter().all(|(_, _, outcome)| outcome.is_complete()));
+}
+
+#[test]
+fn mirror_updates_are_only_accepted_from_techconnect() {
+    let update = MirrorUpdate::<AccountId32>::Retract { class: 0, instance: 0 };
+    let call = (BadgeMirrors::index() as u8, 0u8, update).encode();
+    let message = |origin_kind| {
+        Xcm(vec![
+            UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+            Transact { origin_kind, fallback_max_weight: None, call: call.clone().into() },
+        ])
+    };
+
+    // other chains can't execute for free, nor dispatch as TechConnect
+    let outcome = Net::deliver(4000, MIRROR_SIBLING_PARA_ID, message(OriginKind::SovereignAccount)).unwrap();
+    assert_eq!(outcome.result, Err(XcmError::Barrier));
+    let outcome = Net::deliver(TECHCONNECT_PARA_ID, MIRROR_SIBLING_PARA_ID, message(OriginKind::Native)).unwrap();
+    assert_eq!(outcome.result, Err(XcmError::BadOrigin));
+
+    let outcome = Net::deliver(TECHCONNECT_PARA_ID, MIRROR_SIBLING_PARA_ID, message(OriginKind::SovereignAccount)).unwrap();
+    assert!(outcome.is_complete());
+}
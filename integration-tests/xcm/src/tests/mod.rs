@@ -0,0 +1,10 @@
+//! End-to-end tests on the emulated network (see `chains`).
+
+mod mirrors;
+mod reserve_transfers;
+
+use sp_runtime::AccountId32;
+
+pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
+pub const ISSUER: AccountId32 = AccountId32::new([3u8; 32]);
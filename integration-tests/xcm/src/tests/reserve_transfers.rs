@@ -0,0 +1,164 @@
+//! Reserve transfers between TechConnect and Asset Hub under the runtime's $TCC policy.
+
+use frame_support::{assert_ok, traits::fungibles::Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::MaybeEquivalence, AccountId32};
+use xcm::{latest::prelude::*, VersionedLocation};
+
+use super::ALICE;
+use crate::{
+    chains::{
+        asset_hub::{self, usdt_location, AssetHubAssets, TCC_ON_ASSET_HUB, USDT},
+        techconnect::{self, Tcc, TccLocation, TCC},
+        AssetHub, Net, TechConnect, ASSET_HUB_PARA_ID, MIRROR_SIBLING_PARA_ID, TECHCONNECT_PARA_ID,
+    },
+    executor::{Outcome, XcmExecutor},
+    network::{processed, sibling_account, Network, Parachain},
+};
+
+/// A local account, as origin or beneficiary
+fn account(who: &AccountId32) -> Location {
+    Location::new(0, [Junction::AccountId32 { network: None, id: who.clone().into() }])
+}
+
+fn tcc(amount: u128) -> Asset {
+    (TccLocation::get(), amount).into()
+}
+
+/// Reserve-transfer `assets` of ALICE on TechConnect to ALICE on `dest`
+fn from_techconnect(dest: Location, assets: Vec<Asset>) -> Outcome {
+    Net::execute_with::<TechConnect, _>(|| {
+        XcmExecutor::<techconnect::XcmConfig>::reserve_transfer(account(&ALICE), dest, account(&ALICE), assets)
+    })
+}
+
+/// Reserve-transfer `assets` of ALICE on Asset Hub to ALICE on TechConnect
+fn from_asset_hub(assets: Vec<Asset>) -> Outcome {
+    Net::execute_with::<AssetHub, _>(|| {
+        XcmExecutor::<asset_hub::XcmConfig>::reserve_transfer(
+            account(&ALICE),
+            TechConnect::sibling_location(),
+            account(&ALICE),
+            assets,
+        )
+    })
+}
+
+fn tcc_balance(who: &AccountId32) -> u128 {
+    TechConnect::with_state(|| techconnect::Assets::balance(TCC, who))
+}
+
+fn register_usdt_on_techconnect() {
+    TechConnect::with_state(|| {
+        let location = Box::new(VersionedLocation::from(usdt_location()));
+        assert_ok!(Tcc::register_foreign_asset(RawOrigin::Root.into(), location, USDT, 1));
+    });
+}
+
+/// The message last executed on `para`
+fn last_outcome_on(para: u32) -> Outcome {
+    processed().into_iter().rev().find(|(to, _, _)| *to == para).expect("a message reached the chain").2
+}
+
+#[test]
+fn tcc_round_trips_through_asset_hub() {
+    TechConnect::with_state(|| {
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 1_000));
+    });
+
+    let outcome = from_techconnect(AssetHub::sibling_location(), vec![tcc(400)]);
+    assert!(outcome.is_complete(), "{outcome:?}");
+    assert!(outcome.trapped.is_empty());
+    // TechConnect is the reserve: the $TCC backing Asset Hub's sits in Asset Hub's sovereign account
+    assert_eq!(tcc_balance(&ALICE), 600);
+    assert_eq!(tcc_balance(&sibling_account(ASSET_HUB_PARA_ID)), 400);
+    assert_eq!(TechConnect::with_state(Tcc::total_supply), 1_000);
+    assert!(last_outcome_on(ASSET_HUB_PARA_ID).is_complete());
+    assert_eq!(AssetHub::with_state(|| asset_hub::Assets::balance(TCC_ON_ASSET_HUB, ALICE)), 400);
+
+    let outcome = from_asset_hub(vec![(asset_hub::tcc_location(), 150).into()]);
+    assert!(outcome.is_complete(), "{outcome:?}");
+    assert!(last_outcome_on(TECHCONNECT_PARA_ID).is_complete());
+    assert_eq!(AssetHub::with_state(|| asset_hub::Assets::balance(TCC_ON_ASSET_HUB, ALICE)), 250);
+    assert_eq!(tcc_balance(&ALICE), 750);
+    assert_eq!(tcc_balance(&sibling_account(ASSET_HUB_PARA_ID)), 250);
+}
+
+#[test]
+fn only_tcc_leaves_and_only_for_asset_hub_and_whitelisted_siblings() {
+    TechConnect::with_state(|| {
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 1_000));
+    });
+    register_usdt_on_techconnect();
+    TechConnect::with_state(|| {
+        assert_ok!(techconnect::Assets::mint_into(USDT, &ALICE, 100));
+    });
+
+    let unknown_sibling = Location::new(1, [Parachain(4_000)]);
+    assert_eq!(from_techconnect(unknown_sibling, vec![tcc(10)]).result, Err(XcmError::NoPermission));
+    assert_eq!(from_techconnect(Location::parent(), vec![tcc(10)]).result, Err(XcmError::NoPermission));
+    // foreign assets stay: only their reserve can move them on
+    let usdt: Asset = (usdt_location(), 10).into();
+    let asset_hub = AssetHub::sibling_location();
+    assert_eq!(from_techconnect(asset_hub.clone(), vec![usdt.clone()]).result, Err(XcmError::NoPermission));
+    assert_eq!(from_techconnect(asset_hub, vec![tcc(10), usdt]).result, Err(XcmError::NoPermission));
+    assert!(processed().is_empty());
+    assert_eq!(tcc_balance(&ALICE), 1_000);
+
+    let whitelisted = Location::new(1, [Parachain(MIRROR_SIBLING_PARA_ID)]);
+    assert!(from_techconnect(whitelisted, vec![tcc(10)]).is_complete());
+    assert_eq!(tcc_balance(&sibling_account(MIRROR_SIBLING_PARA_ID)), 10);
+}
+
+#[test]
+fn foreign_assets_are_trapped_until_governance_registers_them() {
+    let usdt_on_asset_hub = AssetHubAssets::convert_back(&USDT).expect("a local asset");
+    AssetHub::with_state(|| {
+        assert_ok!(asset_hub::Assets::mint_into(USDT, &ALICE, 100));
+    });
+
+    assert!(from_asset_hub(vec![(usdt_on_asset_hub.clone(), 40).into()]).is_complete());
+    let received = last_outcome_on(TECHCONNECT_PARA_ID);
+    assert_eq!(received.result, Err(XcmError::AssetNotFound));
+    assert_eq!(received.trapped, vec![(usdt_location(), 40).into()]);
+
+    register_usdt_on_techconnect();
+    assert!(from_asset_hub(vec![(usdt_on_asset_hub, 60).into()]).is_complete());
+    let received = last_outcome_on(TECHCONNECT_PARA_ID);
+    assert!(received.is_complete() && received.trapped.is_empty(), "{received:?}");
+    assert_eq!(TechConnect::with_state(|| techconnect::Assets::balance(USDT, ALICE)), 60);
+    // Asset Hub is the reserve: TechConnect's sovereign account holds everything sent
+    assert_eq!(
+        AssetHub::with_state(|| asset_hub::Assets::balance(USDT, sibling_account(TECHCONNECT_PARA_ID))),
+        100
+    );
+}
+
+#[test]
+fn reserves_are_only_trusted_for_their_own_assets() {
+    register_usdt_on_techconnect();
+    let deposit = |asset: Asset| {
+        Xcm(vec![
+            ReserveAssetDeposited(asset.clone().into()),
+            ClearOrigin,
+            BuyExecution { fees: asset, weight_limit: Unlimited },
+            DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account(&ALICE) },
+        ])
+    };
+
+    // unknown chains, even for their own assets
+    let own_asset: Asset = (Location::new(1, [Parachain(4_000), GeneralIndex(1)]), 10).into();
+    let outcome = Net::deliver(4_000, TECHCONNECT_PARA_ID, deposit(own_asset)).unwrap();
+    assert_eq!(outcome.result, Err(XcmError::UntrustedReserveLocation));
+    // a whitelisted sibling can't deposit USDT, nor Asset Hub $TCC
+    let usdt: Asset = (usdt_location(), 10).into();
+    let outcome = Net::deliver(MIRROR_SIBLING_PARA_ID, TECHCONNECT_PARA_ID, deposit(usdt.clone())).unwrap();
+    assert_eq!(outcome.result, Err(XcmError::UntrustedReserveLocation));
+    let outcome = Net::deliver(ASSET_HUB_PARA_ID, TECHCONNECT_PARA_ID, deposit(tcc(10))).unwrap();
+    assert_eq!(outcome.result, Err(XcmError::UntrustedReserveLocation));
+
+    // Asset Hub for USDT
+    assert!(Net::deliver(ASSET_HUB_PARA_ID, TECHCONNECT_PARA_ID, deposit(usdt)).unwrap().is_complete());
+    assert_eq!(TechConnect::with_state(|| techconnect::Assets::balance(USDT, ALICE)), 10);
+    assert_eq!(tcc_balance(&ALICE), 0);
+}
@@ -0,0 +1,83 @@
+//! `airdrop-merkle` subcommand: build the Merkle root and claim proofs of a $TCC airdrop round.
+//!
+//! Parsing and tree building live in `pallet_tcc_airdrop::list` (see there for the CSV format);
+//! this command reads the CSV and writes the root, leaf count and total to pass to
+//! `TccAirdrop::create_round`, plus each recipient's index, amount and proof for `claim`, as JSON.
+
+use polkadot_sdk::*;
+
+use pallet_tcc_airdrop::list;
+use sp_core::{
+	crypto::{AccountId32, Ss58Codec},
+	hexdisplay::HexDisplay,
+};
+use std::path::PathBuf;
+use techconnectchain_runtime::Balance;
+
+/// Build the Merkle root and proofs of an airdrop round from a CSV.
+#[derive(Debug, clap::Args)]
+pub struct AirdropMerkleCmd {
+	/// CSV of `account,amount` rows.
+	#[arg(long)]
+	pub csv: PathBuf,
+
+	/// Write the JSON here instead of stdout.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+}
+
+/// One recipient's claim
+#[derive(Debug, serde::Serialize)]
+pub struct AirdropClaim {
+	pub index: u32,
+	pub account: String,
+	pub amount: String,
+	pub proof: Vec<String>,
+}
+
+/// Everything needed to register a round and claim from it
+#[derive(Debug, serde::Serialize)]
+pub struct AirdropTree {
+	pub root: String,
+	pub leaf_count: u32,
+	/// amounts are strings so JSON consumers keep full u128 precision
+	pub total: String,
+	pub claims: Vec<AirdropClaim>,
+}
+
+impl From<list::AirdropTree<AccountId32, Balance>> for AirdropTree {
+	fn from(tree: list::AirdropTree<AccountId32, Balance>) -> Self {
+		let hex = |hash: &[u8; 32]| format!("0x{}", HexDisplay::from(hash));
+		Self {
+			root: hex(&tree.root),
+			leaf_count: tree.leaf_count,
+			total: tree.total.to_string(),
+			claims: tree
+				.claims
+				.into_iter()
+				.map(|claim| AirdropClaim {
+					index: claim.index,
+					account: claim.account.to_ss58check(),
+					amount: claim.amount.to_string(),
+					proof: claim.proof.iter().map(hex).collect(),
+				})
+				.collect(),
+		}
+	}
+}
+
+impl AirdropMerkleCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let csv = std::fs::read_to_string(&self.csv)
+			.map_err(|e| sc_cli::Error::Input(format!("cannot read {}: {e}", self.csv.display())))?;
+		let entries = list::parse_csv::<AccountId32, Balance>(&csv).map_err(sc_cli::Error::Input)?;
+		let tree = AirdropTree::from(list::build_tree(&entries).map_err(sc_cli::Error::Input)?);
+		let json = serde_json::to_string_pretty(&tree)
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+		match &self.output {
+			Some(path) => std::fs::write(path, json)?,
+			None => println!("{json}"),
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,97 @@
+//! `badge-attributes` subcommand: build the attribute root of a badge and disclosure proofs.
+//!
+//! Salting and tree building live in `pallet_badges::attributes` (salts are derived from the
+//! holder's secret seed); this command writes the root to pass to `Badges::commit_attributes` and,
+//! for each `--disclose` key, the key, value, salt, leaf and proof a verifier checks with
+//! `verify_badge_attribute`, as JSON.
+
+use polkadot_sdk::*;
+
+use pallet_badges::attributes;
+use sp_core::{bytes::from_hex, hexdisplay::HexDisplay};
+use std::path::PathBuf;
+
+/// Build the attribute root of a badge and disclosure proofs of some of its attributes.
+#[derive(Debug, clap::Args)]
+pub struct BadgeAttributesCmd {
+	/// Secret 32-byte seed the salts are derived from, hex encoded.
+	#[arg(long)]
+	pub seed: String,
+
+	/// An attribute as `key=value`; repeat for every attribute, in commitment order.
+	#[arg(long = "attribute", value_name = "KEY=VALUE", required = true)]
+	pub attributes: Vec<String>,
+
+	/// Key of an attribute to disclose; may be repeated.
+	#[arg(long = "disclose", value_name = "KEY")]
+	pub disclose: Vec<String>,
+
+	/// Write the JSON here instead of stdout.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+}
+
+/// One disclosed attribute
+#[derive(Debug, serde::Serialize)]
+pub struct Disclosure {
+	pub key: String,
+	pub value: String,
+	pub salt: String,
+	pub leaf: String,
+	pub proof: Vec<String>,
+}
+
+/// The commitment and the requested disclosures
+#[derive(Debug, serde::Serialize)]
+pub struct BadgeAttributes {
+	pub root: String,
+	pub disclosures: Vec<Disclosure>,
+}
+
+fn hex(bytes: &[u8]) -> String {
+	format!("0x{}", HexDisplay::from(&bytes))
+}
+
+impl BadgeAttributesCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let seed: [u8; 32] = from_hex(&self.seed)
+			.ok()
+			.and_then(|seed| seed.try_into().ok())
+			.ok_or_else(|| sc_cli::Error::Input("--seed must be 32 hex-encoded bytes".into()))?;
+		let pairs = self
+			.attributes
+			.iter()
+			.map(|attribute| {
+				attribute
+					.split_once('=')
+					.ok_or_else(|| sc_cli::Error::Input(format!("expected `key=value`: {attribute}")))
+			})
+			.collect::<Result<Vec<(&str, &str)>, _>>()?;
+
+		let root = attributes::seeded_root(&seed, &pairs)
+			.ok_or_else(|| sc_cli::Error::Input("no attributes".into()))?;
+		let disclosures = self
+			.disclose
+			.iter()
+			.map(|key| {
+				let disclosure = attributes::disclose_seeded(&seed, &pairs, key)
+					.ok_or_else(|| sc_cli::Error::Input(format!("unknown attribute: {key}")))?;
+				Ok(Disclosure {
+					key: String::from_utf8_lossy(&disclosure.key).into_owned(),
+					value: String::from_utf8_lossy(&disclosure.value).into_owned(),
+					salt: hex(&disclosure.salt),
+					leaf: hex(&disclosure.leaf),
+					proof: disclosure.proof.iter().map(|hash| hex(hash)).collect(),
+				})
+			})
+			.collect::<sc_cli::Result<Vec<_>>>()?;
+
+		let json = serde_json::to_string_pretty(&BadgeAttributes { root: hex(&root), disclosures })
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+		match &self.output {
+			Some(path) => std::fs::write(path, json)?,
+			None => println!("{json}"),
+		}
+		Ok(())
+	}
+}
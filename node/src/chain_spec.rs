@@ -35,6 +35,9 @@ where
     signer.into_account()
 }
 
+/// $TCC supply at genesis: 100,000,000 TCC with 12 decimals
+pub const TCC_GENESIS_SUPPLY: runtime::Balance = 100_000_000 * 1_000_000_000_000;
+
 // --- Core genesis builder (shared) --- //
 fn build_genesis(endowed: Vec<runtime::AccountId>, sudo_key: runtime::AccountId) -> runtime::GenesisConfig {
     // Balances vector must be Vec<(AccountId, Balance)>
@@ -60,6 +63,15 @@ fn build_genesis(endowed: Vec<runtime::AccountId>, sudo_key: runtime::AccountId)
         },
         // Your member registry has no genesis config; default is fine:
         member_registry: runtime::MemberRegistryConfig {},
+        assets: Default::default(),
+        // Creates $TCC (admin: the keyless pallet account, not sudo) and credits the 50/25/15/10 allocation pots
+        tcc: runtime::TccConfig {
+            create_asset: true,
+            min_balance: 1,
+            max_supply: Some(TCC_GENESIS_SUPPLY),
+            allocations: pallet_tcc::AllocationPot::split(TCC_GENESIS_SUPPLY),
+            ..Default::default()
+        },
     }
 }
 
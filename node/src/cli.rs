@@ -39,6 +39,12 @@ pub enum Subcommand {
 	/// The pallet benchmarking moved to the `pallet` sub-command.
 	#[command(subcommand)]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Build the Merkle root and claim proofs of a $TCC airdrop round from a CSV.
+	AirdropMerkle(crate::airdrop::AirdropMerkleCmd),
+
+	/// Build the attribute root of a badge and disclosure proofs of some of its attributes.
+	BadgeAttributes(crate::badge_attributes::BadgeAttributesCmd),
 }
 
 const AFTER_HELP_EXAMPLE: &str = color_print::cstr!(
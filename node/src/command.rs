@@ -177,6 +177,8 @@ pub fn run() -> Result<()> {
 				cmd.run(&*spec)
 			})
 		},
+		Some(Subcommand::AirdropMerkle(cmd)) => cmd.run(),
+		Some(Subcommand::BadgeAttributes(cmd)) => cmd.run(),
 		Some(Subcommand::Benchmark(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			// Switch on the concrete benchmark sub-command-
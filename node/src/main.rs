@@ -4,6 +4,8 @@
 
 use polkadot_sdk::*;
 
+mod airdrop;
+mod badge_attributes;
 mod chain_spec;
 mod cli;
 mod command;
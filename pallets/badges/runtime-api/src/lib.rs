@@ -0,0 +1,33 @@
+//! Runtime API for reading pallet-badges state (profile pages, explorers).
+//!
+//! A runtime implements it by delegating to the pallet helpers, e.g.
+//! `fn endorsements(class, instance) { Badges::instance_endorsements(class, instance) }`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_badges::{BadgeOffer, ClassId, Endorsement, InstanceId};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait BadgesApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Endorsements of a badge instance, in endorsement order
+        fn endorsements(class: ClassId, instance: InstanceId) -> Vec<Endorsement<AccountId>>;
+
+        /// Page of live instance ids of `class` following `start_after` (see `Pallet::class_instances`)
+        fn class_instances(class: ClassId, start_after: Option<InstanceId>, limit: u32) -> Vec<InstanceId>;
+
+        /// False if the badge doesn't exist or its owner hid it from their public profile
+        fn is_badge_visible(class: ClassId, instance: InstanceId) -> bool;
+
+        /// True if `leaf` is a committed attribute of the instance (`proof`: sibling hashes to the root)
+        fn verify_badge_attribute(class: ClassId, instance: InstanceId, leaf: [u8; 32], proof: Vec<[u8; 32]>) -> bool;
+
+        /// Unexpired badge offers awaiting `who`'s acceptance
+        fn pending_offers(who: AccountId) -> Vec<(ClassId, BadgeOffer<AccountId, BlockNumber>)>;
+    }
+}
@@ -0,0 +1,96 @@
+//! Selective-disclosure badge attributes.
+//!
+//! An issuer may commit a badge instance to the Merkle root (see `merkle`) of its attributes
+//! (grade, date, skills, ...). Each leaf is `blake2_256((ATTRIBUTE_LEAF_DOMAIN, key, value,
+//! salt).encode())`; the domain prefix keeps attribute leaves apart from claim leaves and inner
+//! nodes, and the random per-attribute salt stops verifiers from brute-forcing undisclosed
+//! low-entropy values (grades) from the sibling hashes in a proof. To reveal one attribute the
+//! holder hands over its key, value, salt and proof, which anyone can check with
+//! `Pallet::verify_badge_attribute`.
+//!
+//! Holder tooling (std only) derives every salt from one secret seed
+//! (`blake2_256(seed ++ key)`), so the holder only keeps the seed and the attribute list.
+
+use codec::Encode;
+use sp_core::hashing::blake2_256;
+#[cfg(feature = "std")]
+use sp_std::vec::Vec;
+
+/// Domain separator prefixed to every attribute leaf
+pub const ATTRIBUTE_LEAF_DOMAIN: [u8; 16] = *b"tcc/badge-attr/0";
+
+/// Leaf committing to one attribute
+pub fn attribute_leaf(key: &[u8], value: &[u8], salt: &[u8; 32]) -> [u8; 32] {
+    blake2_256(&(ATTRIBUTE_LEAF_DOMAIN, key, value, salt).encode())
+}
+
+/// A salted attribute as kept by the holder (off-chain tooling / tests)
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BadgeAttribute {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub salt: [u8; 32],
+}
+
+#[cfg(feature = "std")]
+impl BadgeAttribute {
+    pub fn leaf(&self) -> [u8; 32] {
+        attribute_leaf(&self.key, &self.value, &self.salt)
+    }
+}
+
+/// Root to commit on-chain for `attributes`. Returns `None` for no attributes.
+#[cfg(feature = "std")]
+pub fn attributes_root(attributes: &[BadgeAttribute]) -> Option<[u8; 32]> {
+    let leaves: Vec<[u8; 32]> = attributes.iter().map(BadgeAttribute::leaf).collect();
+    crate::merkle::merkle_root(&leaves)
+}
+
+/// Leaf and proof disclosing `attributes[index]` only
+#[cfg(feature = "std")]
+pub fn disclose(attributes: &[BadgeAttribute], index: usize) -> Option<([u8; 32], Vec<[u8; 32]>)> {
+    let leaves: Vec<[u8; 32]> = attributes.iter().map(BadgeAttribute::leaf).collect();
+    let proof = crate::merkle::merkle_proof(&leaves, index)?;
+    Some((leaves[index], proof))
+}
+
+/// Everything a verifier needs to check one attribute against the on-chain commitment
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AttributeDisclosure {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub salt: [u8; 32],
+    pub leaf: [u8; 32],
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Salt each `(key, value)` pair with a salt derived from `seed`
+#[cfg(feature = "std")]
+pub fn salted_attributes(seed: &[u8; 32], pairs: &[(&str, &str)]) -> Vec<BadgeAttribute> {
+    pairs
+        .iter()
+        .map(|(key, value)| BadgeAttribute {
+            key: key.as_bytes().to_vec(),
+            value: value.as_bytes().to_vec(),
+            salt: blake2_256(&[seed.as_slice(), key.as_bytes()].concat()),
+        })
+        .collect()
+}
+
+/// Root to pass to `commit_attributes` for `pairs` salted from `seed`
+#[cfg(feature = "std")]
+pub fn seeded_root(seed: &[u8; 32], pairs: &[(&str, &str)]) -> Option<[u8; 32]> {
+    attributes_root(&salted_attributes(seed, pairs))
+}
+
+/// Disclose the attribute named `key` only, of `pairs` salted from `seed`
+#[cfg(feature = "std")]
+pub fn disclose_seeded(seed: &[u8; 32], pairs: &[(&str, &str)], key: &str) -> Option<AttributeDisclosure> {
+    let index = pairs.iter().position(|(k, _)| *k == key)?;
+    let salted = salted_attributes(seed, pairs);
+    let (leaf, proof) = disclose(&salted, index)?;
+    let BadgeAttribute { key, value, salt } = salted.into_iter().nth(index)?;
+    Some(AttributeDisclosure { key, value, salt, leaf, proof })
+}
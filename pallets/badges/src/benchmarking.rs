@@ -0,0 +1,93 @@
+//! Benchmarking setup for pallet-badges
+
+use super::*;
+use crate::Pallet as Badges;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+/// Insert a plain (non club-scoped, no prerequisites) class owned by `creator`.
+fn bench_class<T: Config>(creator: &T::AccountId) -> ClassId {
+    let class = NextClassId::<T>::get();
+    T::NftBackend::class_created(class, creator, &[0u8; 32])
+        .expect("bench class must be creatable in the NFT backend");
+    Classes::<T>::insert(
+        class,
+        ClassInfo {
+            creator: creator.clone(),
+            club: None,
+            metadata_hash: [0u8; 32],
+            default_transferable: true,
+            default_soulbound: false,
+            instances_count: 0,
+        },
+    );
+    NextClassId::<T>::put(class.saturating_add(1));
+    class
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn issue_badge() {
+        let caller: T::AccountId = whitelisted_caller();
+        let class = bench_class::<T>(&caller);
+        let to: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), class, to.clone(), [1u8; 32], None, None);
+
+        assert_eq!(Badges::<T>::owner_of(class, 0), Some(to));
+    }
+
+    #[benchmark]
+    fn issue_badges_batch(n: Linear<1, { T::MaxBatchIssuance::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let class = bench_class::<T>(&caller);
+        let recipients: Vec<(T::AccountId, [u8; 32])> =
+            (0..n).map(|i| (account("recipient", i, 0), [1u8; 32])).collect();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), class, recipients);
+
+        assert_eq!(NextInstanceId::<T>::get(class), n as InstanceId);
+    }
+
+    impl_benchmark_test_suite!(Badges, crate::mock::new_test_ext(), crate::mock::Test);
+}
+
+/// Measure the weights in `weights.rs` (see there for the command).
+#[cfg(test)]
+mod measure {
+    use crate::mock::{new_test_ext, AllPalletsWithSystem, Test};
+    use frame_support::traits::WhitelistedStorageKeys;
+    use techconnectchain_benchmarking::{measure, BenchmarkWeights};
+
+    fn measured(steps: u32, repeat: u32) -> Vec<BenchmarkWeights> {
+        let whitelist = AllPalletsWithSystem::whitelisted_storage_keys();
+        measure::<crate::Pallet<Test>, _>(new_test_ext(), &whitelist, steps, repeat).expect("benchmarks run")
+    }
+
+    #[test]
+    #[ignore = "measures weights; run in release mode"]
+    fn measure_weights() {
+        for weights in measured(50, 20) {
+            println!("{weights}");
+        }
+    }
+
+    /// Database accesses are deterministic, so the measured ones must match `weights.rs`.
+    #[test]
+    fn weights_match_measured_database_accesses() {
+        let measured = measured(3, 1);
+        let accesses = |name: &str| {
+            let weights = measured.iter().find(|w| w.name == name).expect("benchmarked");
+            (weights.reads.clone(), weights.writes.clone())
+        };
+        let fit = |base, slopes: &[u128]| techconnectchain_benchmarking::Fit { base, slopes: slopes.to_vec() };
+
+        assert_eq!(accesses("issue_badge"), (fit(15, &[]), fit(12, &[])));
+        assert_eq!(accesses("issue_badges_batch"), (fit(10, &[5]), fit(4, &[8])));
+    }
+}
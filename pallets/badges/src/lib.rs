@@ -8,25 +8,81 @@
 //! - Permission checks: class creator OR club officer/admin may issue/revoke when class is club-scoped.
 //! - Timestamps for issuance using T::Time (UnixTime).
 //! - Bounded storage (bounded vecs & limits) to avoid unbounded on-chain allocations.
+//! - Batch issuance of a class to many recipients (e.g. event attendance) with per-recipient results.
+//! - Merkle-claimable (POAP-style) badges: an issuer publishes a Merkle root of eligible accounts or
+//!   claim-code keys with a claim window, and attendees claim (and pay for) their own badge. Code
+//!   claims are signed for the claimant with the key derived from the code, so the code never goes
+//!   on chain and a claim seen in the transaction pool can't be front-run.
+//! - Optional NFT backend (`nft::NftsBackend`) mirroring classes/instances into pallet-nfts
+//!   collections/items, with soulbound enforced through item transfer locks.
+//! - Versioned metadata: class creators may update class metadata and issuers may update instance
+//!   `uri_hash`es; superseded hashes are kept in a bounded revision history until frozen for good.
+//! - Peer endorsements: holders of a class (or accounts with the endorser role) vouch for a specific
+//!   badge instance; capped per instance, revocable, and readable via `pallet-badges-runtime-api`.
+//! - Soulbound recovery: `reassign_soulbound` moves a soulbound badge to a new account (e.g. lost
+//!   key), immediately for `ReassignOrigin` or after `ReassignmentDelay` when requested by a class
+//!   admin; every step is evented with the evidence hash for provenance.
+//! - Opt-in badges: classes flagged "requires acceptance" turn issuance into a pending offer that the
+//!   recipient accepts or declines before `OfferExpiry` blocks pass.
+//! - Profile curation: owners may burn any badge they hold, or just hide it from their public
+//!   profile with `set_badge_visibility`.
+//! - Issuance limits: optional per-class supply cap, per-issuer per-epoch issuance limit and
+//!   one-per-account uniqueness, guarding against fraudulent mass issuance.
+//! - Badge-gated access: other pallets check credentials through `BadgeInspect` or gate calls with
+//!   the `EnsureHasBadge<Runtime, Class>` origin (token-gated GPU time, CTFs, workshops).
+//! - Selective disclosure: issuers may commit an instance to a Merkle root of salted attributes so
+//!   holders can reveal single attributes (`verify_badge_attribute`, see `attributes.rs`).
+//! - Cross-chain mirrors: owners may mirror a badge to a configured XCM destination (see
+//!   `mirror.rs`). Mirrors are re-attested on soulbound reassignment and retracted on revocation
+//!   or burning; owners can't transfer a mirrored badge until `MirrorReleaseOrigin` releases it.
+//! - Class prerequisites: a class may require holding other classes before it can be issued, and
+//!   may be flagged auto-award so it is minted as soon as a member satisfies all prerequisites.
 //!
 //! Integration notes:
 //! - This pallet consults pallet-member-registry for club permission checks:
-//!     pallet_member_registry::Pallet::<T>::is_officer_or_admin(&issuer, club)
-//!     pallet_member_registry::Pallet::<T>::is_member(&who)
+//!   `pallet_member_registry::Pallet::<T>::is_officer_or_admin(&issuer, club)`
+//!   `pallet_member_registry::Pallet::<T>::is_member(&who)`
 //! - Emit events for SubQuery indexing.
-//! - TODO: add benchmarking and unit/integration tests before production.
+//! - Storage migrations live in `migrations.rs`; runtimes upgrading from v0 must run
+//!   `migrations::v1::MigrateToV1` to rebuild the class instance index.
+//! - Benchmarks live in `benchmarking.rs` (feature `runtime-benchmarks`); `weights.rs` holds
+//!   the weights measured from them (`SubstrateWeight`), placeholders for calls without one.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod mock;
+
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod attributes;
+pub mod merkle;
+pub mod migrations;
+pub mod mirror;
+pub mod nft;
+pub mod weights;
+pub mod traits;
+
+pub use mirror::{BadgeAttestation, MirrorMessage, MirrorUpdate, TransactAttestation};
+pub use nft::{BadgeBackend, NftsBackend};
+pub use traits::{BadgeHooks, BadgeInspect, EnsureHasBadge};
+
 use frame_support::{
     pallet_prelude::*,
-    traits::{EnsureOrigin, UnixTime},
+    traits::{Contains, EnsureOrigin, UnixTime},
     BoundedVec,
 };
 use frame_system::pallet_prelude::*;
 use sp_std::prelude::*;
-use sp_runtime::traits::SaturatedConversion;
-use codec::{Decode, Encode};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Verify};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use sp_core::{ed25519, hashing::blake2_256};
+use xcm::{
+    latest::{Location, SendXcm, XcmHash},
+    VersionedLocation,
+};
 
 
 
@@ -64,7 +120,110 @@ pub mod pallet {
         pub soulbound: bool,
     }
 
+    /// What the leaves of a claim campaign's Merkle tree commit to
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ClaimMode {
+        /// leaf = blake2_256((index, account).encode())
+        Account,
+        /// leaf = blake2_256((index, key).encode()) with `key` the ed25519 key seeded by
+        /// `blake2_256(code)`; the claimant signs the claim with it (`CodeClaim`)
+        Code,
+    }
+
+    /// Proof of knowing a claim code, bound to the claimant: the code's key and its signature over
+    /// `Pallet::code_claim_payload(class, index, claimant)`
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct CodeClaim {
+        pub key: ed25519::Public,
+        pub signature: ed25519::Signature,
+    }
+
+    /// A Merkle-claimable issuance window for a class
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct ClaimCampaign<AccountId, BlockNumber> {
+        /// recorded as the issuer of every claimed instance (claimed instances carry the class
+        /// `metadata_hash` as their `uri_hash`)
+        pub issuer: AccountId,
+        pub root: [u8; 32],
+        pub mode: ClaimMode,
+        /// number of leaves in the tree; claim indices must be below it
+        pub leaf_count: u32,
+        pub opens_at: BlockNumber,
+        pub closes_at: BlockNumber,
+    }
+
+    /// A superseded metadata hash (class `metadata_hash` or instance `uri_hash`)
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct MetadataRevision<BlockNumber> {
+        /// revision number of `hash`; the original hash is version 0
+        pub version: u32,
+        pub hash: [u8; 32],
+        /// block at which `hash` was replaced by the next revision
+        pub replaced_at: BlockNumber,
+    }
+
+    /// A peer endorsement of a badge instance
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct Endorsement<AccountId> {
+        pub endorser: AccountId,
+        pub endorsed_at: Moment,
+    }
+
+    /// Optional issuance limits of a class. `Default` means unlimited.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct IssuanceLimits {
+        /// max instances ever issued (revoked and burned instances still count)
+        pub max_supply: Option<u32>,
+        /// max instances a single issuer may mint per `IssuanceEpochLength` blocks
+        pub max_per_issuer_per_epoch: Option<u32>,
+        /// an account may hold at most one live instance of the class
+        pub one_per_account: bool,
+    }
+
+    /// A pending badge offer for a class that requires acceptance
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct BadgeOffer<AccountId, BlockNumber> {
+        pub issuer: AccountId,
+        pub uri_hash: [u8; 32],
+        pub transferable: Option<bool>,
+        pub soulbound: Option<bool>,
+        /// last block at which the offer can be accepted
+        pub expires_at: BlockNumber,
+    }
+
+    /// `BadgeOffer` for a runtime
+    pub type OfferOf<T> = BadgeOffer<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    /// A soulbound reassignment requested by a class admin, executable once the timelock expires
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub struct PendingReassignment<AccountId, BlockNumber> {
+        pub new_owner: AccountId,
+        pub evidence_hash: [u8; 32],
+        pub requested_by: AccountId,
+        pub executable_at: BlockNumber,
+    }
+
+    /// Prerequisite classes a holder must own before a class can be issued to them.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ClassPrerequisites<T: Config> {
+        pub required: BoundedVec<ClassId, T::MaxPrerequisites>,
+        /// if true, the class is minted automatically once the holder owns every required class
+        pub auto_award: bool,
+    }
+
+    /// v1: `ClassInstances` became an untruncated double map index (see `migrations::v1`)
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -88,16 +247,105 @@ pub mod pallet {
         /// Max number of classes a single account can create (optional guard)
         type MaxClassesPerAccount: Get<u32>;
 
-        /// WeightInfo for each call (benchmark replace)
+        /// Max number of prerequisite classes a single class may declare
+        type MaxPrerequisites: Get<u32>;
+
+        /// Max number of auto-award classes that may depend on a single prerequisite class. Also the
+        /// max number of auto-awards (chains included) a single new holding may trigger, which
+        /// issuing calls charge upfront and refund when unused.
+        type MaxAutoAwardDependents: Get<u32>;
+
+        /// Max number of recipients in a single `issue_badges_batch` call
+        #[pallet::constant]
+        type MaxBatchIssuance: Get<u32>;
+
+        /// Max number of sibling hashes accepted in a `claim_badge` Merkle proof
+        #[pallet::constant]
+        type MaxClaimProofLength: Get<u32>;
+
+        /// Max number of superseded revisions kept per class / instance (oldest are dropped first)
+        #[pallet::constant]
+        type MaxMetadataRevisions: Get<u32>;
+
+        /// Accounts that may endorse any badge instance, in addition to holders of the badge's class
+        type Endorsers: Contains<Self::AccountId>;
+
+        /// Max number of endorsements kept per badge instance
+        #[pallet::constant]
+        type MaxEndorsementsPerInstance: Get<u32>;
+
+        /// Origin that may reassign soulbound badges without a timelock (e.g. governance or Root)
+        type ReassignOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks a class admin's reassignment request waits before it can be executed
+        #[pallet::constant]
+        type ReassignmentDelay: Get<BlockNumberFor<Self>>;
+
+        /// Blocks a badge offer stays acceptable
+        #[pallet::constant]
+        type OfferExpiry: Get<BlockNumberFor<Self>>;
+
+        /// Length in blocks of the epochs used by `IssuanceLimits::max_per_issuer_per_epoch`
+        #[pallet::constant]
+        type IssuanceEpochLength: Get<BlockNumberFor<Self>>;
+
+        /// Notified when accounts gain or lose badges (use `()` or e.g. pallet-reputation)
+        type BadgeHooks: BadgeHooks<Self::AccountId>;
+
+        /// NFT backend mirroring badges (use `()` for pallet storage only, or `NftsBackend`)
+        type NftBackend: BadgeBackend<Self::AccountId>;
+
+        /// Router delivering cross-chain mirror messages (e.g. the runtime's `XcmRouter`)
+        type XcmSender: SendXcm;
+
+        /// Destinations badges may be mirrored to
+        type MirrorDestinations: Contains<Location>;
+
+        /// Builds the mirror message for a destination (use `()` to disable mirroring)
+        type MirrorMessage: MirrorMessage<Self::AccountId>;
+
+        /// Origin confirming a mirror was retired, unlocking the local badge (e.g. governance)
+        type MirrorReleaseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Max number of destinations a single badge may be mirrored to
+        #[pallet::constant]
+        type MaxMirrorsPerBadge: Get<u32>;
+
+        /// WeightInfo for each call (`weights::SubstrateWeight` in runtimes)
         type WeightInfo: WeightInfo;
     }
 
-    /// Weight placeholders
+    /// Weights of the calls; implemented in `weights.rs`
     pub trait WeightInfo {
         fn create_class() -> Weight;
         fn issue_badge() -> Weight;
         fn revoke_badge() -> Weight;
         fn transfer_badge() -> Weight;
+        fn set_prerequisites() -> Weight;
+        fn issue_badges_batch(n: u32) -> Weight;
+        fn open_claim() -> Weight;
+        fn claim_badge() -> Weight;
+        fn close_claim() -> Weight;
+        fn set_class_metadata() -> Weight;
+        fn freeze_class_metadata() -> Weight;
+        fn set_badge_uri() -> Weight;
+        fn freeze_badge_uri() -> Weight;
+        fn endorse_badge() -> Weight;
+        fn revoke_endorsement() -> Weight;
+        fn reassign_soulbound() -> Weight;
+        fn execute_reassignment() -> Weight;
+        fn cancel_reassignment() -> Weight;
+        fn set_requires_acceptance() -> Weight;
+        fn accept_offer() -> Weight;
+        fn decline_offer() -> Weight;
+        fn clear_expired_offer() -> Weight;
+        fn burn_badge() -> Weight;
+        fn set_badge_visibility() -> Weight;
+        fn set_issuance_limits() -> Weight;
+        fn commit_attributes() -> Weight;
+        fn mirror_badge() -> Weight;
+        fn release_mirror() -> Weight;
+        fn resend_mirror_update() -> Weight;
     }
 
     // Storage
@@ -128,11 +376,178 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Indexing: optional list of instances per class (bounded). Useful for frontends.
+    /// Indexing: live instances per class, paged through `class_instances`. Useful for frontends.
     #[pallet::storage]
-    #[pallet::getter(fn class_instances)]
     pub(super) type ClassInstances<T: Config> =
-        StorageMap<_, Twox64Concat, ClassId, BoundedVec<InstanceId, ConstU32<1024>>, OptionQuery>;
+        StorageDoubleMap<_, Twox64Concat, ClassId, Twox64Concat, InstanceId, (), OptionQuery>;
+
+    /// Number of live (non-revoked) instances of a class held by an account.
+    #[pallet::storage]
+    #[pallet::getter(fn holdings)]
+    pub(super) type Holdings<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        ClassId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Prerequisites declared by a class (absent => no prerequisites)
+    #[pallet::storage]
+    #[pallet::getter(fn prerequisites)]
+    pub(super) type Prerequisites<T: Config> =
+        StorageMap<_, Twox64Concat, ClassId, ClassPrerequisites<T>, OptionQuery>;
+
+    /// Reverse index: prerequisite class -> auto-award classes that require it.
+    /// Consulted after each issuance to find meta-badges the recipient may now qualify for.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_award_dependents)]
+    pub(super) type AutoAwardDependents<T: Config> =
+        StorageMap<_, Twox64Concat, ClassId, BoundedVec<ClassId, T::MaxAutoAwardDependents>, ValueQuery>;
+
+    /// Active claim campaign per class
+    #[pallet::storage]
+    #[pallet::getter(fn claim_campaign)]
+    pub(super) type ClaimCampaigns<T: Config> =
+        StorageMap<_, Twox64Concat, ClassId, ClaimCampaign<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+    /// Claimed bitmap per campaign: ((class, root), word) -> 32 claim flags for indices
+    /// `word * 32 ..`. Kept after `close_claim`, so reopening a root can't re-enable claimed leaves.
+    #[pallet::storage]
+    pub(super) type ClaimedBitmap<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, (ClassId, [u8; 32]), Twox64Concat, u32, u32, ValueQuery>;
+
+    /// Superseded class metadata hashes, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn class_metadata_history)]
+    pub(super) type ClassMetadataHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        BoundedVec<MetadataRevision<BlockNumberFor<T>>, T::MaxMetadataRevisions>,
+        ValueQuery,
+    >;
+
+    /// Classes whose metadata has been permanently frozen
+    #[pallet::storage]
+    pub(super) type FrozenClassMetadata<T: Config> = StorageMap<_, Twox64Concat, ClassId, (), OptionQuery>;
+
+    /// Superseded instance uri hashes, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn badge_uri_history)]
+    pub(super) type BadgeUriHistory<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        InstanceId,
+        BoundedVec<MetadataRevision<BlockNumberFor<T>>, T::MaxMetadataRevisions>,
+        ValueQuery,
+    >;
+
+    /// Instances whose uri hash has been permanently frozen
+    #[pallet::storage]
+    pub(super) type FrozenBadgeUris<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ClassId, Twox64Concat, InstanceId, (), OptionQuery>;
+
+    /// Endorsements per badge instance, in endorsement order
+    #[pallet::storage]
+    #[pallet::getter(fn endorsements)]
+    pub(super) type Endorsements<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        InstanceId,
+        BoundedVec<Endorsement<T::AccountId>, T::MaxEndorsementsPerInstance>,
+        ValueQuery,
+    >;
+
+    /// Timelocked soulbound reassignments requested by class admins
+    #[pallet::storage]
+    #[pallet::getter(fn pending_reassignment)]
+    pub(super) type PendingReassignments<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        InstanceId,
+        PendingReassignment<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Classes whose badges must be accepted by the recipient before they are minted
+    #[pallet::storage]
+    pub(super) type RequiresAcceptance<T: Config> = StorageMap<_, Twox64Concat, ClassId, (), OptionQuery>;
+
+    /// Pending offers: recipient x class -> offer (at most one per recipient and class)
+    #[pallet::storage]
+    #[pallet::getter(fn offer)]
+    pub(super) type Offers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        ClassId,
+        OfferOf<T>,
+        OptionQuery,
+    >;
+
+    /// Instances their owner chose to hide from public profiles (absent => visible).
+    /// Reset whenever the instance changes owner.
+    #[pallet::storage]
+    pub(super) type HiddenBadges<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ClassId, Twox64Concat, InstanceId, (), OptionQuery>;
+
+    /// Issuance limits per class (absent => unlimited)
+    #[pallet::storage]
+    #[pallet::getter(fn issuance_limits)]
+    pub(super) type ClassIssuanceLimits<T: Config> = StorageMap<_, Twox64Concat, ClassId, IssuanceLimits, OptionQuery>;
+
+    /// Instances minted per class and issuer in the issuer's latest epoch: (epoch index, count)
+    #[pallet::storage]
+    pub(super) type IssuerEpochIssuance<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        T::AccountId,
+        (BlockNumberFor<T>, u32),
+        ValueQuery,
+    >;
+
+    /// Merkle root of the salted attributes of an instance (see `attributes`)
+    #[pallet::storage]
+    #[pallet::getter(fn attribute_root)]
+    pub(super) type AttributeRoots<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ClassId, Twox64Concat, InstanceId, [u8; 32], OptionQuery>;
+
+    /// Destinations an instance is mirrored to (absent => not mirrored, transfers allowed)
+    #[pallet::storage]
+    pub(super) type Mirrors<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        InstanceId,
+        BoundedVec<VersionedLocation, T::MaxMirrorsPerBadge>,
+        OptionQuery,
+    >;
+
+    /// Mirrors the last update of an instance (attestation or retraction) could not be sent to;
+    /// outlives the instance so retractions can be re-sent after revocation
+    #[pallet::storage]
+    pub(super) type StaleMirrors<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ClassId,
+        Twox64Concat,
+        InstanceId,
+        BoundedVec<VersionedLocation, T::MaxMirrorsPerBadge>,
+        OptionQuery,
+    >;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -141,6 +556,55 @@ pub mod pallet {
         BadgeIssued { class: ClassId, instance: InstanceId, to: T::AccountId },
         BadgeRevoked { class: ClassId, instance: InstanceId },
         BadgeTransferred { class: ClassId, instance: InstanceId, from: T::AccountId, to: T::AccountId },
+        PrerequisitesSet { class: ClassId, required: Vec<ClassId>, auto_award: bool },
+        BadgeAutoAwarded { class: ClassId, instance: InstanceId, to: T::AccountId },
+        /// A recipient of a batch issuance could not receive the badge
+        BatchIssueFailed { class: ClassId, to: T::AccountId, error: DispatchError },
+        /// A batch issuance completed; `issued + failed` equals the number of recipients
+        BatchIssued { class: ClassId, issued: u32, failed: u32 },
+        ClaimOpened { class: ClassId, root: [u8; 32], mode: ClaimMode, opens_at: BlockNumberFor<T>, closes_at: BlockNumberFor<T> },
+        BadgeClaimed { class: ClassId, instance: InstanceId, who: T::AccountId, index: u32 },
+        ClaimClosed { class: ClassId },
+        ClassMetadataUpdated { class: ClassId, version: u32, metadata_hash: [u8; 32] },
+        ClassMetadataFrozen { class: ClassId },
+        BadgeUriUpdated { class: ClassId, instance: InstanceId, version: u32, uri_hash: [u8; 32] },
+        BadgeUriFrozen { class: ClassId, instance: InstanceId },
+        BadgeEndorsed { class: ClassId, instance: InstanceId, endorser: T::AccountId },
+        EndorsementRevoked { class: ClassId, instance: InstanceId, endorser: T::AccountId },
+        /// A class admin requested a soulbound reassignment; executable from `executable_at`
+        ReassignmentScheduled {
+            class: ClassId,
+            instance: InstanceId,
+            new_owner: T::AccountId,
+            evidence_hash: [u8; 32],
+            requested_by: T::AccountId,
+            executable_at: BlockNumberFor<T>,
+        },
+        ReassignmentCancelled { class: ClassId, instance: InstanceId },
+        /// A soulbound badge changed owner. `requested_by` is None when `ReassignOrigin` acted directly.
+        SoulboundReassigned {
+            class: ClassId,
+            instance: InstanceId,
+            from: T::AccountId,
+            to: T::AccountId,
+            evidence_hash: [u8; 32],
+            requested_by: Option<T::AccountId>,
+        },
+        RequiresAcceptanceSet { class: ClassId, required: bool },
+        BadgeOffered { class: ClassId, to: T::AccountId, issuer: T::AccountId, expires_at: BlockNumberFor<T> },
+        OfferAccepted { class: ClassId, instance: InstanceId, to: T::AccountId },
+        OfferDeclined { class: ClassId, to: T::AccountId },
+        OfferExpired { class: ClassId, to: T::AccountId },
+        BadgeBurned { class: ClassId, instance: InstanceId, owner: T::AccountId },
+        BadgeVisibilitySet { class: ClassId, instance: InstanceId, visible: bool },
+        IssuanceLimitsSet { class: ClassId, limits: IssuanceLimits },
+        AttributesCommitted { class: ClassId, instance: InstanceId, root: [u8; 32] },
+        BadgeMirrored { class: ClassId, instance: InstanceId, dest: VersionedLocation, message_id: XcmHash },
+        MirrorReleased { class: ClassId, instance: InstanceId, dest: VersionedLocation },
+        /// A mirror was sent the badge's new state (`retracted` once it was revoked or burned)
+        MirrorUpdated { class: ClassId, instance: InstanceId, dest: VersionedLocation, retracted: bool, message_id: XcmHash },
+        /// A mirror update could not be sent; see `resend_mirror_update`
+        MirrorUpdateFailed { class: ClassId, instance: InstanceId, dest: VersionedLocation },
     }
 
     #[pallet::error]
@@ -158,6 +622,46 @@ pub mod pallet {
         InstancesIndexOverflow,
         Overflow,
         InvalidMetadata,
+        PrerequisitesNotMet,
+        InvalidPrerequisite,
+        TooManyPrerequisites,
+        TooManyDependents,
+        BatchTooLarge,
+        ClaimNotOpen,
+        ClaimAlreadyOpen,
+        ClaimWindowClosed,
+        InvalidClaimWindow,
+        AlreadyClaimed,
+        InvalidProof,
+        ProofTooLong,
+        ClaimCodeRequired,
+        InvalidClaimSignature,
+        InvalidClaimTree,
+        MetadataFrozen,
+        NotEndorser,
+        SelfEndorsement,
+        AlreadyEndorsed,
+        TooManyEndorsements,
+        EndorsementNotFound,
+        NotSoulbound,
+        ReassignmentPending,
+        NoPendingReassignment,
+        ReassignmentTimelocked,
+        OfferNotFound,
+        OfferExpired,
+        OfferNotExpired,
+        OfferAlreadyPending,
+        SupplyCapReached,
+        IssuerEpochLimitReached,
+        AlreadyHoldsClass,
+        BadDestination,
+        DestinationNotAllowed,
+        AlreadyMirrored,
+        TooManyMirrors,
+        MirrorNotFound,
+        MirroringUnsupported,
+        MirrorSendFailed,
+        BadgeMirrored,
     }
 
     // Dispatchable functions
@@ -191,19 +695,21 @@ pub mod pallet {
                 instances_count: 0u32,
             };
 
+            T::NftBackend::class_created(class_id, &who, &metadata_hash)?;
             Classes::<T>::insert(class_id, info);
             NextClassId::<T>::put(class_id.saturating_add(1));
             Self::deposit_event(Event::ClassCreated { class: class_id, creator: who, club });
             Ok(())
         }
 
-        /// Issue a badge instance to `to` for `class`.
+        /// Issue a badge instance to `to` for `class`. If the class requires acceptance, a pending
+        /// offer is created instead and the badge is minted once `to` calls `accept_offer`.
         ///
         /// Permission:
         /// - If class.club.is_some(), issuer must be club admin or officer (via member-registry) OR the class creator.
         /// - If class.club.is_none(), issuer must be class creator.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::issue_badge())]
+        #[pallet::weight(T::WeightInfo::issue_badge().saturating_add(Pallet::<T>::max_auto_award_weight(1)))]
         pub fn issue_badge(
             origin: OriginFor<T>,
             class: ClassId,
@@ -211,75 +717,20 @@ pub mod pallet {
             uri_hash: [u8; 32],
             transferable: Option<bool>,
             soulbound: Option<bool>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let issuer = ensure_signed(origin)?;
-            Classes::<T>::try_mutate(class, |maybe_class| -> DispatchResult {
-                let class_info = maybe_class.as_mut().ok_or(Error::<T>::ClassNotFound)?;
-
-                // Permission check
-                let allowed = if let Some(_club_id) = class_info.club {
-                    // if club-scoped, class creator or club officers/admins can issue
-                    if issuer == class_info.creator {
-                        true
-                    } else {
-                        // TODO: Wire pallet-member-registry properly in runtime for cross-pallet calls
-                        // pallet_member_registry::Pallet::<T>::is_officer_or_admin(&issuer, club_id)
-                        //     .map_err(|_| Error::<T>::NotClubAdminOrOfficer)?
-                        false // For now, only class creator can issue
-                    }
-                } else {
-                    // not club-scoped => only class creator can issue
-                    issuer == class_info.creator
-                };
-                ensure!(allowed, Error::<T>::NotIssuer);
-
-                // next instance id per-class
-                let next_inst = NextInstanceId::<T>::get(class);
-                let max_per_class = T::MaxInstancesPerClass::get();
-                ensure!(next_inst < (max_per_class as InstanceId), Error::<T>::InstancesLimitReached);
-
-                let now = T::TimeProvider::now().as_millis().saturated_into::<Moment>();
-
-                let inst_transferable = transferable.unwrap_or(class_info.default_transferable);
-                let inst_soulbound = soulbound.unwrap_or(class_info.default_soulbound);
-
-                let instance = BadgeInstance {
-                    owner: to.clone(),
-                    issued_at: now,
-                    issuer: issuer.clone(),
-                    uri_hash,
-                    transferable: inst_transferable,
-                    soulbound: inst_soulbound,
-                };
-
-                BadgeInstances::<T>::insert(class, next_inst, instance);
-
-                // update class instances_count
-                class_info.instances_count = class_info.instances_count.saturating_add(1);
-
-                // record in index (bounded)
-                ClassInstances::<T>::mutate(class, |maybe_vec| {
-                    if maybe_vec.is_none() {
-                        if let Ok(new_vec) = BoundedVec::try_from(Vec::<InstanceId>::new()) {
-                            *maybe_vec = Some(new_vec);
-                        }
-                    }
-                    if let Some(vec) = maybe_vec.as_mut() {
-                        // push instance id, ensure bound (ignore error to avoid ? in closure)
-                        let _ = vec.try_push(next_inst);
-                    }
-                });
-
-                NextInstanceId::<T>::insert(class, next_inst.saturating_add(1));
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(Self::can_issue(&issuer, &class_info), Error::<T>::NotIssuer);
 
-                Self::deposit_event(Event::BadgeIssued { class, instance: next_inst, to });
-                Ok(())
-            })
+            let mut budget = T::MaxAutoAwardDependents::get();
+            Self::issue_or_offer(class, issuer, to, uri_hash, transferable, soulbound, &mut budget)?;
+            Ok(Some(T::WeightInfo::issue_badge().saturating_add(Self::used_auto_award_weight(budget))).into())
         }
 
         /// Revoke a badge instance. Allowed by class creator or (if class is club-scoped) the club admin.
+        /// Mirrors of the badge are sent a retraction.
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::revoke_badge())]
+        #[pallet::weight(T::WeightInfo::revoke_badge().saturating_add(Pallet::<T>::max_mirror_updates_weight()))]
         pub fn revoke_badge(
             origin: OriginFor<T>,
             class: ClassId,
@@ -304,66 +755,1177 @@ pub mod pallet {
 
             ensure!(allowed, Error::<T>::NotClassOwner);
 
-            BadgeInstances::<T>::try_mutate_exists(class, instance, |maybe| -> DispatchResult {
-                maybe.take().ok_or(Error::<T>::InstanceNotFound)?;
-                Ok(())
-            })?;
-
-            // Optionally remove from ClassInstances index (leave as history or implement removal)
-            // For simplicity, we keep historical index; frontend can interpret missing instance as revoked.
+            Self::remove_instance(class, instance)?;
 
             Self::deposit_event(Event::BadgeRevoked { class, instance });
             Ok(())
         }
 
         /// Transfer a badge instance (owner -> to). Enforced: not soulbound and transferable flag true.
+        /// `BadgeHooks` see the owner lose and the recipient gain the badge, and the recipient is
+        /// auto-awarded any class it now qualifies for.
         #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::transfer_badge())]
+        #[pallet::weight(T::WeightInfo::transfer_badge().saturating_add(Pallet::<T>::max_auto_award_weight(1)))]
         pub fn transfer_badge(
             origin: OriginFor<T>,
             class: ClassId,
             instance: InstanceId,
             to: T::AccountId,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             BadgeInstances::<T>::try_mutate(class, instance, |maybe| -> DispatchResult {
                 let inst = maybe.as_mut().ok_or(Error::<T>::InstanceNotFound)?;
                 ensure!(inst.owner == who, Error::<T>::NotOwner);
                 ensure!(!inst.soulbound, Error::<T>::Soulbound);
                 ensure!(inst.transferable, Error::<T>::NotTransferable);
+                ensure!(!Mirrors::<T>::contains_key(class, instance), Error::<T>::BadgeMirrored);
+                if to != who {
+                    Self::ensure_can_receive(class, &to)?;
+                }
 
                 let prev = inst.owner.clone();
                 inst.owner = to.clone();
+                Holdings::<T>::mutate(&prev, class, |n| *n = n.saturating_sub(1));
+                Holdings::<T>::mutate(&to, class, |n| *n = n.saturating_add(1));
+                HiddenBadges::<T>::remove(class, instance);
+                T::NftBackend::transferred(class, instance, &to)?;
+                if prev != to {
+                    let club = Self::class_club(class);
+                    T::BadgeHooks::on_badge_lost(&prev, class, instance, club);
+                    T::BadgeHooks::on_badge_gained(&to, class, instance, club);
+                }
 
-                Self::deposit_event(Event::BadgeTransferred { class, instance, from: prev, to });
+                Self::deposit_event(Event::BadgeTransferred { class, instance, from: prev, to: to.clone() });
                 Ok(())
-            })
+            })?;
+
+            let mut budget = T::MaxAutoAwardDependents::get();
+            Self::award_dependents(&to, class, &mut budget);
+            Ok(Some(T::WeightInfo::transfer_badge().saturating_add(Self::used_auto_award_weight(budget))).into())
         }
-    }
 
-    // Public helper APIs
-    impl<T: Config> Pallet<T> {
-        /// Return owner of a badge instance (if exists)
-        pub fn owner_of(class: ClassId, instance: InstanceId) -> Option<T::AccountId> {
-            BadgeInstances::<T>::get(class, instance).map(|i| i.owner)
+        /// Declare the prerequisite classes of `class` (class creator only).
+        ///
+        /// Issuing `class` then requires the recipient to hold a live instance of every class in
+        /// `required`. If `auto_award` is set, the class is minted to a holder as soon as they
+        /// satisfy all prerequisites. An empty `required` list clears the prerequisites.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_prerequisites())]
+        pub fn set_prerequisites(
+            origin: OriginFor<T>,
+            class: ClassId,
+            required: Vec<ClassId>,
+            auto_award: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(who == class_info.creator, Error::<T>::NotClassOwner);
+            ensure!(!(auto_award && required.is_empty()), Error::<T>::InvalidPrerequisite);
+
+            for (i, req) in required.iter().enumerate() {
+                ensure!(*req != class, Error::<T>::InvalidPrerequisite);
+                ensure!(!required[..i].contains(req), Error::<T>::InvalidPrerequisite);
+                ensure!(Classes::<T>::contains_key(req), Error::<T>::ClassNotFound);
+            }
+            let bounded: BoundedVec<ClassId, T::MaxPrerequisites> =
+                required.clone().try_into().map_err(|_| Error::<T>::TooManyPrerequisites)?;
+
+            // drop this class from the reverse index of its previous prerequisites
+            if let Some(old) = Prerequisites::<T>::get(class) {
+                if old.auto_award {
+                    for req in old.required.iter() {
+                        AutoAwardDependents::<T>::mutate(req, |deps| deps.retain(|c| *c != class));
+                    }
+                }
+            }
+
+            if auto_award {
+                for req in bounded.iter() {
+                    AutoAwardDependents::<T>::try_mutate(req, |deps| deps.try_push(class))
+                        .map_err(|_| Error::<T>::TooManyDependents)?;
+                }
+            }
+
+            if bounded.is_empty() {
+                Prerequisites::<T>::remove(class);
+            } else {
+                Prerequisites::<T>::insert(class, ClassPrerequisites { required: bounded, auto_award });
+            }
+
+            Self::deposit_event(Event::PrerequisitesSet { class, required, auto_award });
+            Ok(())
         }
 
-        /// Return badge instance metadata if exists
-        pub fn instance_metadata(class: ClassId, instance: InstanceId) -> Option<BadgeInstance<T::AccountId>> {
-            BadgeInstances::<T>::get(class, instance)
+        /// Issue `class` to every `(recipient, uri_hash)` in `recipients` (or offer it, if the class
+        /// requires acceptance) using the class defaults
+        /// for transferable/soulbound. Same permission rules as `issue_badge`.
+        ///
+        /// A failing recipient (prerequisites not met, `MaxInstancesPerClass` reached, ...) does not
+        /// abort the batch: it is reported with `BatchIssueFailed` and the remaining recipients are
+        /// still processed. `BatchIssued` summarises the outcome.
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::WeightInfo::issue_badges_batch(recipients.len() as u32)
+                .saturating_add(Pallet::<T>::max_auto_award_weight(recipients.len() as u32))
+        )]
+        pub fn issue_badges_batch(
+            origin: OriginFor<T>,
+            class: ClassId,
+            recipients: Vec<(T::AccountId, [u8; 32])>,
+        ) -> DispatchResultWithPostInfo {
+            let issuer = ensure_signed(origin)?;
+            ensure!(recipients.len() as u32 <= T::MaxBatchIssuance::get(), Error::<T>::BatchTooLarge);
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(Self::can_issue(&issuer, &class_info), Error::<T>::NotIssuer);
+
+            let n = recipients.len() as u32;
+            let (mut issued, mut failed) = (0u32, 0u32);
+            let mut auto_award_weight = Weight::zero();
+            for (to, uri_hash) in recipients {
+                // each recipient runs in its own storage layer so a failure leaves no partial writes
+                let mut budget = T::MaxAutoAwardDependents::get();
+                let res = frame_support::storage::with_storage_layer(|| {
+                    Self::issue_or_offer(class, issuer.clone(), to.clone(), uri_hash, None, None, &mut budget)
+                });
+                auto_award_weight = auto_award_weight.saturating_add(Self::used_auto_award_weight(budget));
+                match res {
+                    Ok(_) => issued = issued.saturating_add(1),
+                    Err(error) => {
+                        failed = failed.saturating_add(1);
+                        Self::deposit_event(Event::BatchIssueFailed { class, to, error });
+                    }
+                }
+            }
+
+            Self::deposit_event(Event::BatchIssued { class, issued, failed });
+            Ok(Some(T::WeightInfo::issue_badges_batch(n).saturating_add(auto_award_weight)).into())
         }
 
-        /// Return class info if exists
-        pub fn class_info(class: ClassId) -> Option<ClassInfo<T::AccountId>> {
-            Classes::<T>::get(class)
+        /// Publish a Merkle root of eligible claimants for `class`, claimable between `opens_at`
+        /// and `closes_at` (inclusive). Same permission rules as `issue_badge`; the caller is
+        /// recorded as issuer of every claimed instance. Only one campaign per class may be open.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::open_claim())]
+        pub fn open_claim(
+            origin: OriginFor<T>,
+            class: ClassId,
+            root: [u8; 32],
+            mode: ClaimMode,
+            leaf_count: u32,
+            opens_at: BlockNumberFor<T>,
+            closes_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(Self::can_issue(&issuer, &class_info), Error::<T>::NotIssuer);
+            ensure!(!ClaimCampaigns::<T>::contains_key(class), Error::<T>::ClaimAlreadyOpen);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(opens_at <= closes_at && closes_at >= now, Error::<T>::InvalidClaimWindow);
+            ensure!(leaf_count > 0, Error::<T>::InvalidClaimTree);
+
+            ClaimCampaigns::<T>::insert(
+                class,
+                ClaimCampaign { issuer, root, mode, leaf_count, opens_at, closes_at },
+            );
+            Self::deposit_event(Event::ClaimOpened { class, root, mode, opens_at, closes_at });
+            Ok(())
+        }
+
+        /// Claim a badge from the open campaign of `class`. `index` is the claimant's leaf index
+        /// and `proof` the sibling hashes up to the root. Code campaigns also need a `CodeClaim`
+        /// signed for the caller with the key of the claim code (see `sign_code_claim`).
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::claim_badge().saturating_add(Pallet::<T>::max_auto_award_weight(1)))]
+        pub fn claim_badge(
+            origin: OriginFor<T>,
+            class: ClassId,
+            index: u32,
+            proof: Vec<[u8; 32]>,
+            code: Option<CodeClaim>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(proof.len() as u32 <= T::MaxClaimProofLength::get(), Error::<T>::ProofTooLong);
+            let campaign = ClaimCampaigns::<T>::get(class).ok_or(Error::<T>::ClaimNotOpen)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= campaign.opens_at, Error::<T>::ClaimNotOpen);
+            ensure!(now <= campaign.closes_at, Error::<T>::ClaimWindowClosed);
+            ensure!(index < campaign.leaf_count, Error::<T>::InvalidProof);
+
+            let (word, bit) = (index / 32, 1u32 << (index % 32));
+            let claimed = (class, campaign.root);
+            ensure!(ClaimedBitmap::<T>::get(claimed, word) & bit == 0, Error::<T>::AlreadyClaimed);
+
+            let leaf = match campaign.mode {
+                ClaimMode::Account => Self::account_claim_leaf(index, &who),
+                ClaimMode::Code => {
+                    let CodeClaim { key, signature } = code.ok_or(Error::<T>::ClaimCodeRequired)?;
+                    let payload = Self::code_claim_payload(class, index, &who);
+                    ensure!(signature.verify(&payload[..], &key), Error::<T>::InvalidClaimSignature);
+                    Self::code_claim_leaf(index, &key)
+                }
+            };
+            ensure!(merkle::verify_proof(&campaign.root, leaf, &proof), Error::<T>::InvalidProof);
+
+            ClaimedBitmap::<T>::mutate(claimed, word, |bits| *bits |= bit);
+            let metadata_hash = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?.metadata_hash;
+            // the claimant is the recipient, so no acceptance offer is needed
+            let mut budget = T::MaxAutoAwardDependents::get();
+            let instance = Self::do_issue(class, campaign.issuer, who.clone(), metadata_hash, None, None, &mut budget)?;
+            Self::deposit_event(Event::BadgeClaimed { class, instance, who, index });
+            Ok(Some(T::WeightInfo::claim_badge().saturating_add(Self::used_auto_award_weight(budget))).into())
+        }
+
+        /// Close the claim campaign of `class`. Its claimed flags stay, so opening the same root
+        /// again only re-enables unclaimed leaves. Allowed for the campaign issuer or the class
+        /// creator.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::close_claim())]
+        pub fn close_claim(origin: OriginFor<T>, class: ClassId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let campaign = ClaimCampaigns::<T>::get(class).ok_or(Error::<T>::ClaimNotOpen)?;
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(who == campaign.issuer || who == class_info.creator, Error::<T>::NotClassOwner);
+
+            ClaimCampaigns::<T>::remove(class);
+            Self::deposit_event(Event::ClaimClosed { class });
+            Ok(())
+        }
+
+        /// Replace the metadata hash of `class` (class creator only). The previous hash is kept
+        /// in `ClassMetadataHistory`. Fails once the class metadata is frozen.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_class_metadata())]
+        pub fn set_class_metadata(origin: OriginFor<T>, class: ClassId, metadata_hash: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!FrozenClassMetadata::<T>::contains_key(class), Error::<T>::MetadataFrozen);
+
+            let version = Classes::<T>::try_mutate(class, |maybe_class| -> Result<u32, DispatchError> {
+                let class_info = maybe_class.as_mut().ok_or(Error::<T>::ClassNotFound)?;
+                ensure!(who == class_info.creator, Error::<T>::NotClassOwner);
+
+                let previous = sp_std::mem::replace(&mut class_info.metadata_hash, metadata_hash);
+                T::NftBackend::class_metadata_set(class, &metadata_hash)?;
+                Ok(ClassMetadataHistory::<T>::mutate(class, |history| Self::push_revision(history, previous)))
+            })?;
+
+            Self::deposit_event(Event::ClassMetadataUpdated { class, version, metadata_hash });
+            Ok(())
+        }
+
+        /// Make the metadata of `class` permanently immutable (class creator only).
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::freeze_class_metadata())]
+        pub fn freeze_class_metadata(origin: OriginFor<T>, class: ClassId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(who == class_info.creator, Error::<T>::NotClassOwner);
+            ensure!(!FrozenClassMetadata::<T>::contains_key(class), Error::<T>::MetadataFrozen);
+
+            FrozenClassMetadata::<T>::insert(class, ());
+            Self::deposit_event(Event::ClassMetadataFrozen { class });
+            Ok(())
+        }
+
+        /// Replace the uri hash of a badge instance (its issuer only). The previous hash is kept
+        /// in `BadgeUriHistory`. Fails once the instance uri is frozen.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_badge_uri())]
+        pub fn set_badge_uri(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            uri_hash: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!FrozenBadgeUris::<T>::contains_key(class, instance), Error::<T>::MetadataFrozen);
+
+            let version = BadgeInstances::<T>::try_mutate(class, instance, |maybe| -> Result<u32, DispatchError> {
+                let inst = maybe.as_mut().ok_or(Error::<T>::InstanceNotFound)?;
+                ensure!(who == inst.issuer, Error::<T>::NotIssuer);
+
+                let previous = sp_std::mem::replace(&mut inst.uri_hash, uri_hash);
+                T::NftBackend::instance_uri_set(class, instance, &uri_hash)?;
+                Ok(BadgeUriHistory::<T>::mutate(class, instance, |history| Self::push_revision(history, previous)))
+            })?;
+
+            Self::deposit_event(Event::BadgeUriUpdated { class, instance, version, uri_hash });
+            Ok(())
+        }
+
+        /// Make the uri hash of a badge instance permanently immutable (its issuer only).
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::freeze_badge_uri())]
+        pub fn freeze_badge_uri(origin: OriginFor<T>, class: ClassId, instance: InstanceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let inst = BadgeInstances::<T>::get(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(who == inst.issuer, Error::<T>::NotIssuer);
+            ensure!(!FrozenBadgeUris::<T>::contains_key(class, instance), Error::<T>::MetadataFrozen);
+
+            FrozenBadgeUris::<T>::insert(class, instance, ());
+            Self::deposit_event(Event::BadgeUriFrozen { class, instance });
+            Ok(())
+        }
+
+        /// Endorse a badge instance. The caller must hold `class` or be one of `T::Endorsers`,
+        /// and may not endorse a badge they own.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::endorse_badge())]
+        pub fn endorse_badge(origin: OriginFor<T>, class: ClassId, instance: InstanceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let inst = BadgeInstances::<T>::get(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(inst.owner != who, Error::<T>::SelfEndorsement);
+            ensure!(Self::holds(&who, class) || T::Endorsers::contains(&who), Error::<T>::NotEndorser);
+
+            Endorsements::<T>::try_mutate(class, instance, |list| -> DispatchResult {
+                ensure!(!list.iter().any(|e| e.endorser == who), Error::<T>::AlreadyEndorsed);
+                let endorsed_at = T::TimeProvider::now().as_millis().saturated_into::<Moment>();
+                list.try_push(Endorsement { endorser: who.clone(), endorsed_at })
+                    .map_err(|_| Error::<T>::TooManyEndorsements)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::BadgeEndorsed { class, instance, endorser: who });
+            Ok(())
+        }
+
+        /// Withdraw the caller's endorsement of a badge instance.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::revoke_endorsement())]
+        pub fn revoke_endorsement(origin: OriginFor<T>, class: ClassId, instance: InstanceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Endorsements::<T>::try_mutate(class, instance, |list| -> DispatchResult {
+                let pos = list.iter().position(|e| e.endorser == who).ok_or(Error::<T>::EndorsementNotFound)?;
+                list.remove(pos);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::EndorsementRevoked { class, instance, endorser: who });
+            Ok(())
+        }
+
+        /// Move a soulbound badge to `new_owner`, e.g. after the holder lost their key.
+        /// `evidence_hash` points to the off-chain justification and is kept in the event trail.
+        ///
+        /// - `ReassignOrigin`: reassigned immediately.
+        /// - signed class creator / club admin: scheduled, executable via `execute_reassignment`
+        ///   after `ReassignmentDelay` blocks. The current owner may cancel it meanwhile.
+        ///
+        /// Mirrors of the badge are sent an attestation naming the new owner.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::reassign_soulbound()
+            .saturating_add(Pallet::<T>::max_auto_award_weight(1))
+            .saturating_add(Pallet::<T>::max_mirror_updates_weight()))]
+        pub fn reassign_soulbound(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            new_owner: T::AccountId,
+            evidence_hash: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            let inst = BadgeInstances::<T>::get(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(inst.soulbound, Error::<T>::NotSoulbound);
+            ensure!(!PendingReassignments::<T>::contains_key(class, instance), Error::<T>::ReassignmentPending);
+
+            let requested_by = match T::ReassignOrigin::try_origin(origin) {
+                Ok(_) => {
+                    let mut budget = T::MaxAutoAwardDependents::get();
+                    Self::do_reassign(class, instance, new_owner, evidence_hash, None, &mut budget)?;
+                    let weight = T::WeightInfo::reassign_soulbound().saturating_add(Self::used_auto_award_weight(budget));
+                    return Ok(Some(weight).into())
+                },
+                Err(origin) => ensure_signed(origin)?,
+            };
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(Self::can_issue(&requested_by, &class_info), Error::<T>::NotClassOwner);
+
+            let executable_at = frame_system::Pallet::<T>::block_number().saturating_add(T::ReassignmentDelay::get());
+            PendingReassignments::<T>::insert(
+                class,
+                instance,
+                PendingReassignment {
+                    new_owner: new_owner.clone(),
+                    evidence_hash,
+                    requested_by: requested_by.clone(),
+                    executable_at,
+                },
+            );
+            Self::deposit_event(Event::ReassignmentScheduled {
+                class,
+                instance,
+                new_owner,
+                evidence_hash,
+                requested_by,
+                executable_at,
+            });
+            Ok(Some(T::WeightInfo::reassign_soulbound()).into())
+        }
+
+        /// Execute a scheduled reassignment whose timelock has expired. Callable by anyone.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::execute_reassignment()
+            .saturating_add(Pallet::<T>::max_auto_award_weight(1))
+            .saturating_add(Pallet::<T>::max_mirror_updates_weight()))]
+        pub fn execute_reassignment(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let pending = PendingReassignments::<T>::get(class, instance).ok_or(Error::<T>::NoPendingReassignment)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= pending.executable_at, Error::<T>::ReassignmentTimelocked);
+
+            PendingReassignments::<T>::remove(class, instance);
+            let mut budget = T::MaxAutoAwardDependents::get();
+            let (to, evidence_hash) = (pending.new_owner, pending.evidence_hash);
+            Self::do_reassign(class, instance, to, evidence_hash, Some(pending.requested_by), &mut budget)?;
+            Ok(Some(T::WeightInfo::execute_reassignment().saturating_add(Self::used_auto_award_weight(budget))).into())
+        }
+
+        /// Cancel a scheduled reassignment. Allowed for `ReassignOrigin`, the requester, or the
+        /// current owner (who thereby proves they still control their key).
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::cancel_reassignment())]
+        pub fn cancel_reassignment(origin: OriginFor<T>, class: ClassId, instance: InstanceId) -> DispatchResult {
+            let pending = PendingReassignments::<T>::get(class, instance).ok_or(Error::<T>::NoPendingReassignment)?;
+            if let Err(origin) = T::ReassignOrigin::try_origin(origin) {
+                let who = ensure_signed(origin)?;
+                let owner = Self::owner_of(class, instance);
+                ensure!(who == pending.requested_by || owner.as_ref() == Some(&who), Error::<T>::NotClassOwner);
+            }
+
+            PendingReassignments::<T>::remove(class, instance);
+            Self::deposit_event(Event::ReassignmentCancelled { class, instance });
+            Ok(())
+        }
+
+        /// Require (or stop requiring) recipients to accept badges of `class` (class creator only).
+        /// Already pending offers are unaffected.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_requires_acceptance())]
+        pub fn set_requires_acceptance(origin: OriginFor<T>, class: ClassId, required: bool) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(who == class_info.creator, Error::<T>::NotClassOwner);
+
+            if required {
+                RequiresAcceptance::<T>::insert(class, ());
+            } else {
+                RequiresAcceptance::<T>::remove(class);
+            }
+            Self::deposit_event(Event::RequiresAcceptanceSet { class, required });
+            Ok(())
+        }
+
+        /// Accept the caller's pending offer of `class`, minting the badge.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::accept_offer().saturating_add(Pallet::<T>::max_auto_award_weight(1)))]
+        pub fn accept_offer(origin: OriginFor<T>, class: ClassId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let offer = Offers::<T>::take(&who, class).ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(frame_system::Pallet::<T>::block_number() <= offer.expires_at, Error::<T>::OfferExpired);
+
+            let mut budget = T::MaxAutoAwardDependents::get();
+            let instance = Self::do_issue(
+                class,
+                offer.issuer,
+                who.clone(),
+                offer.uri_hash,
+                offer.transferable,
+                offer.soulbound,
+                &mut budget,
+            )?;
+            Self::deposit_event(Event::OfferAccepted { class, instance, to: who });
+            Ok(Some(T::WeightInfo::accept_offer().saturating_add(Self::used_auto_award_weight(budget))).into())
+        }
+
+        /// Decline the caller's pending offer of `class`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::decline_offer())]
+        pub fn decline_offer(origin: OriginFor<T>, class: ClassId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Offers::<T>::take(&who, class).ok_or(Error::<T>::OfferNotFound)?;
+
+            Self::deposit_event(Event::OfferDeclined { class, to: who });
+            Ok(())
+        }
+
+        /// Remove an expired offer from storage. Callable by anyone.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::clear_expired_offer())]
+        pub fn clear_expired_offer(origin: OriginFor<T>, to: T::AccountId, class: ClassId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let offer = Offers::<T>::get(&to, class).ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(frame_system::Pallet::<T>::block_number() > offer.expires_at, Error::<T>::OfferNotExpired);
+
+            Offers::<T>::remove(&to, class);
+            Self::deposit_event(Event::OfferExpired { class, to });
+            Ok(())
+        }
+
+        /// Permanently destroy a badge the caller owns (transferable or soulbound). Mirrors of the
+        /// badge are sent a retraction.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::burn_badge().saturating_add(Pallet::<T>::max_mirror_updates_weight()))]
+        pub fn burn_badge(origin: OriginFor<T>, class: ClassId, instance: InstanceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let owner = Self::owner_of(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(owner == who, Error::<T>::NotOwner);
+
+            Self::remove_instance(class, instance)?;
+            Self::deposit_event(Event::BadgeBurned { class, instance, owner });
+            Ok(())
+        }
+
+        /// Show or hide a badge the caller owns on their public profile. Purely advisory:
+        /// frontends and credential exports are expected to skip hidden badges.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::set_badge_visibility())]
+        pub fn set_badge_visibility(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            visible: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let owner = Self::owner_of(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(owner == who, Error::<T>::NotOwner);
+
+            if visible {
+                HiddenBadges::<T>::remove(class, instance);
+            } else {
+                HiddenBadges::<T>::insert(class, instance, ());
+            }
+            Self::deposit_event(Event::BadgeVisibilitySet { class, instance, visible });
+            Ok(())
+        }
+
+        /// Set the issuance limits of `class` (class creator only). `IssuanceLimits::default()`
+        /// removes all limits.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_issuance_limits())]
+        pub fn set_issuance_limits(origin: OriginFor<T>, class: ClassId, limits: IssuanceLimits) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            ensure!(who == class_info.creator, Error::<T>::NotClassOwner);
+
+            if limits == IssuanceLimits::default() {
+                ClassIssuanceLimits::<T>::remove(class);
+            } else {
+                ClassIssuanceLimits::<T>::insert(class, limits.clone());
+            }
+            Self::deposit_event(Event::IssuanceLimitsSet { class, limits });
+            Ok(())
+        }
+
+        /// Commit a badge instance to the Merkle root of its attributes (its issuer only).
+        /// Like the uri hash, the commitment can be replaced until `freeze_badge_uri` is called.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::commit_attributes())]
+        pub fn commit_attributes(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            root: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let inst = BadgeInstances::<T>::get(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(who == inst.issuer, Error::<T>::NotIssuer);
+            ensure!(!FrozenBadgeUris::<T>::contains_key(class, instance), Error::<T>::MetadataFrozen);
+
+            AttributeRoots::<T>::insert(class, instance, root);
+            Self::deposit_event(Event::AttributesCommitted { class, instance, root });
+            Ok(())
+        }
+
+        /// Mirror a badge the caller owns to `dest` over XCM (see `mirror.rs`). The owner can't
+        /// transfer the badge until every mirror is released.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::mirror_badge())]
+        pub fn mirror_badge(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            dest: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let inst = BadgeInstances::<T>::get(class, instance).ok_or(Error::<T>::InstanceNotFound)?;
+            ensure!(inst.owner == who, Error::<T>::NotOwner);
+            let location: Location = (*dest).clone().try_into().map_err(|_| Error::<T>::BadDestination)?;
+            ensure!(T::MirrorDestinations::contains(&location), Error::<T>::DestinationNotAllowed);
+
+            let dest = VersionedLocation::from(location.clone());
+            let mut mirrors = Mirrors::<T>::get(class, instance).unwrap_or_default();
+            ensure!(!mirrors.contains(&dest), Error::<T>::AlreadyMirrored);
+            mirrors.try_push(dest.clone()).map_err(|_| Error::<T>::TooManyMirrors)?;
+
+            let update = MirrorUpdate::Attest(Self::attestation(class, instance, inst));
+            let message_id = Self::send_mirror_update(location, &update)?;
+
+            Mirrors::<T>::insert(class, instance, mirrors);
+            Self::deposit_event(Event::BadgeMirrored { class, instance, dest, message_id });
+            Ok(())
+        }
+
+        /// Forget the mirror of a badge at `dest` once it was retired there, unlocking the badge
+        /// when no mirror is left. Also drops an update still waiting to be re-sent to `dest`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::release_mirror())]
+        pub fn release_mirror(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            dest: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            T::MirrorReleaseOrigin::ensure_origin(origin)?;
+            let location: Location = (*dest).try_into().map_err(|_| Error::<T>::BadDestination)?;
+            let dest = VersionedLocation::from(location);
+
+            let mirrored = Mirrors::<T>::mutate_exists(class, instance, |m| Self::remove_mirror(m, &dest));
+            let stale = StaleMirrors::<T>::mutate_exists(class, instance, |m| Self::remove_mirror(m, &dest));
+            ensure!(mirrored || stale, Error::<T>::MirrorNotFound);
+            Self::deposit_event(Event::MirrorReleased { class, instance, dest });
+            Ok(())
+        }
+
+        /// Re-send to `dest` an update it missed (see `StaleMirrors`): the badge as it is now, or a
+        /// retraction if it no longer exists. Callable by anyone.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::resend_mirror_update())]
+        pub fn resend_mirror_update(
+            origin: OriginFor<T>,
+            class: ClassId,
+            instance: InstanceId,
+            dest: Box<VersionedLocation>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let location: Location = (*dest).try_into().map_err(|_| Error::<T>::BadDestination)?;
+            let dest = VersionedLocation::from(location.clone());
+            let stale = StaleMirrors::<T>::get(class, instance).unwrap_or_default();
+            ensure!(stale.contains(&dest), Error::<T>::MirrorNotFound);
+
+            let update = match BadgeInstances::<T>::get(class, instance) {
+                Some(inst) => MirrorUpdate::Attest(Self::attestation(class, instance, inst)),
+                None => MirrorUpdate::Retract { class, instance },
+            };
+            let message_id = Self::send_mirror_update(location, &update)?;
+            StaleMirrors::<T>::mutate_exists(class, instance, |m| Self::remove_mirror(m, &dest));
+            let retracted = matches!(update, MirrorUpdate::Retract { .. });
+            Self::deposit_event(Event::MirrorUpdated { class, instance, dest, retracted, message_id });
+            Ok(())
+        }
+    }
+
+    // Public helper APIs
+    impl<T: Config> Pallet<T> {
+        /// Return owner of a badge instance (if exists)
+        pub fn owner_of(class: ClassId, instance: InstanceId) -> Option<T::AccountId> {
+            BadgeInstances::<T>::get(class, instance).map(|i| i.owner)
+        }
+
+        /// Return badge instance metadata if exists
+        pub fn instance_metadata(class: ClassId, instance: InstanceId) -> Option<BadgeInstance<T::AccountId>> {
+            BadgeInstances::<T>::get(class, instance)
+        }
+
+        /// Page through the live instances of `class`: up to `limit` ids following `start_after`
+        /// (pass the last id of the previous page, or None for the first page). Ids are returned in
+        /// storage order, not numerically sorted.
+        pub fn class_instances(class: ClassId, start_after: Option<InstanceId>, limit: u32) -> Vec<InstanceId> {
+            let limit = limit as usize;
+            match start_after {
+                Some(cursor) => {
+                    let raw = ClassInstances::<T>::hashed_key_for(class, cursor);
+                    ClassInstances::<T>::iter_key_prefix_from(class, raw).take(limit).collect()
+                }
+                None => ClassInstances::<T>::iter_key_prefix(class).take(limit).collect(),
+            }
+        }
+
+        /// Return class info if exists
+        pub fn class_info(class: ClassId) -> Option<ClassInfo<T::AccountId>> {
+            Classes::<T>::get(class)
+        }
+
+        /// Leaf committed to by `ClaimMode::Account` campaigns
+        pub fn account_claim_leaf(index: u32, who: &T::AccountId) -> [u8; 32] {
+            blake2_256(&(index, who).encode())
+        }
+
+        /// Leaf committed to by `ClaimMode::Code` campaigns (`key = code_claim_key(code)`)
+        pub fn code_claim_leaf(index: u32, key: &ed25519::Public) -> [u8; 32] {
+            blake2_256(&(index, key).encode())
+        }
+
+        /// Message a `CodeClaim` signs: claim `index` of `class` for `claimant`
+        pub fn code_claim_payload(class: ClassId, index: u32, claimant: &T::AccountId) -> Vec<u8> {
+            (b"badge-claim", class, index, claimant).encode()
+        }
+
+        /// Key of a claim code, committed to by its leaf
+        #[cfg(feature = "std")]
+        pub fn code_claim_key(code: &[u8]) -> ed25519::Public {
+            use sp_core::Pair;
+            ed25519::Pair::from_seed(&blake2_256(code)).public()
+        }
+
+        /// Sign claim `index` of `class` for `claimant` with the key of `code`
+        #[cfg(feature = "std")]
+        pub fn sign_code_claim(code: &[u8], class: ClassId, index: u32, claimant: &T::AccountId) -> CodeClaim {
+            use sp_core::Pair;
+            let pair = ed25519::Pair::from_seed(&blake2_256(code));
+            CodeClaim { key: pair.public(), signature: pair.sign(&Self::code_claim_payload(class, index, claimant)) }
+        }
+
+        /// Return true if claim `index` of the campaign of `class` with `root` was already used
+        pub fn is_claimed(class: ClassId, root: [u8; 32], index: u32) -> bool {
+            ClaimedBitmap::<T>::get((class, root), index / 32) & (1u32 << (index % 32)) != 0
+        }
+
+        /// Endorsements of a badge instance (empty if none or if the instance doesn't exist)
+        pub fn instance_endorsements(class: ClassId, instance: InstanceId) -> Vec<Endorsement<T::AccountId>> {
+            Endorsements::<T>::get(class, instance).into_inner()
+        }
+
+        /// Return true if `leaf` (see `attributes::attribute_leaf`) is one of the attributes the
+        /// live instance is committed to. False if the instance or its commitment doesn't exist.
+        pub fn verify_badge_attribute(class: ClassId, instance: InstanceId, leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+            BadgeInstances::<T>::contains_key(class, instance) &&
+                AttributeRoots::<T>::get(class, instance).is_some_and(|root| merkle::verify_proof(&root, leaf, proof))
+        }
+
+        /// Destinations a badge is currently mirrored to
+        pub fn badge_mirrors(class: ClassId, instance: InstanceId) -> Vec<VersionedLocation> {
+            Mirrors::<T>::get(class, instance).map(|m| m.into_inner()).unwrap_or_default()
+        }
+
+        /// Return true if the badge exists and its owner hasn't hidden it
+        pub fn is_badge_visible(class: ClassId, instance: InstanceId) -> bool {
+            BadgeInstances::<T>::contains_key(class, instance) && !HiddenBadges::<T>::contains_key(class, instance)
+        }
+
+        /// Return true if badges of `class` must be accepted by their recipient
+        pub fn requires_acceptance(class: ClassId) -> bool {
+            RequiresAcceptance::<T>::contains_key(class)
+        }
+
+        /// Unexpired offers awaiting `who`'s acceptance
+        pub fn pending_offers(who: &T::AccountId) -> Vec<(ClassId, OfferOf<T>)> {
+            let now = frame_system::Pallet::<T>::block_number();
+            Offers::<T>::iter_prefix(who).filter(|(_, offer)| now <= offer.expires_at).collect()
+        }
+
+        /// Current revision number of the class metadata (0 until first updated)
+        pub fn class_metadata_version(class: ClassId) -> u32 {
+            ClassMetadataHistory::<T>::get(class).last().map_or(0, |r| r.version.saturating_add(1))
+        }
+
+        /// Current revision number of an instance uri hash (0 until first updated)
+        pub fn badge_uri_version(class: ClassId, instance: InstanceId) -> u32 {
+            BadgeUriHistory::<T>::get(class, instance).last().map_or(0, |r| r.version.saturating_add(1))
+        }
+
+        /// Return true if the class metadata can no longer be updated
+        pub fn is_class_metadata_frozen(class: ClassId) -> bool {
+            FrozenClassMetadata::<T>::contains_key(class)
+        }
+
+        /// Return true if the instance uri hash can no longer be updated
+        pub fn is_badge_uri_frozen(class: ClassId, instance: InstanceId) -> bool {
+            FrozenBadgeUris::<T>::contains_key(class, instance)
+        }
+
+        /// Append the superseded `hash` to `history`, dropping the oldest revision when full.
+        /// Returns the version of the new current hash.
+        fn push_revision(
+            history: &mut BoundedVec<MetadataRevision<BlockNumberFor<T>>, T::MaxMetadataRevisions>,
+            hash: [u8; 32],
+        ) -> u32 {
+            let version = history.last().map_or(0, |r| r.version.saturating_add(1));
+            if history.is_full() && !history.is_empty() {
+                history.remove(0);
+            }
+            let replaced_at = frame_system::Pallet::<T>::block_number();
+            let _ = history.try_push(MetadataRevision { version, hash, replaced_at });
+            version.saturating_add(1)
+        }
+
+        /// Return true if `who` holds at least one live instance of `class`
+        pub fn holds(who: &T::AccountId, class: ClassId) -> bool {
+            Holdings::<T>::get(who, class) > 0
+        }
+
+        /// Return true if `who` holds every prerequisite class of `class` (trivially true if none)
+        pub fn meets_prerequisites(who: &T::AccountId, class: ClassId) -> bool {
+            Prerequisites::<T>::get(class)
+                .map(|p| p.required.iter().all(|req| Self::holds(who, *req)))
+                .unwrap_or(true)
+        }
+
+        /// Issuance permission for `who` on a class.
+        /// - club-scoped: class creator or club officers/admins
+        /// - otherwise: class creator only
+        pub fn can_issue(who: &T::AccountId, class_info: &ClassInfo<T::AccountId>) -> bool {
+            if let Some(_club_id) = class_info.club {
+                if who == &class_info.creator {
+                    true
+                } else {
+                    // TODO: Wire pallet-member-registry properly in runtime for cross-pallet calls
+                    // pallet_member_registry::Pallet::<T>::is_officer_or_admin(who, club_id)
+                    //     .map_err(|_| Error::<T>::NotClubAdminOrOfficer)?
+                    false // For now, only class creator can issue
+                }
+            } else {
+                who == &class_info.creator
+            }
+        }
+
+        /// Current issuance epoch index
+        fn current_epoch() -> BlockNumberFor<T> {
+            let now = frame_system::Pallet::<T>::block_number();
+            now.checked_div(&T::IssuanceEpochLength::get()).unwrap_or_default()
+        }
+
+        /// Check the class `IssuanceLimits` for one more instance minted by `issuer` to `to`.
+        fn ensure_within_limits(
+            class: ClassId,
+            class_info: &ClassInfo<T::AccountId>,
+            issuer: &T::AccountId,
+            to: &T::AccountId,
+        ) -> DispatchResult {
+            let Some(limits) = ClassIssuanceLimits::<T>::get(class) else { return Ok(()) };
+
+            if let Some(cap) = limits.max_supply {
+                ensure!(class_info.instances_count < cap, Error::<T>::SupplyCapReached);
+            }
+            if let Some(max) = limits.max_per_issuer_per_epoch {
+                let (epoch, count) = IssuerEpochIssuance::<T>::get(class, issuer);
+                let used = if epoch == Self::current_epoch() { count } else { 0 };
+                ensure!(used < max, Error::<T>::IssuerEpochLimitReached);
+            }
+            if limits.one_per_account {
+                ensure!(!Self::holds(to, class), Error::<T>::AlreadyHoldsClass);
+            }
+            Ok(())
+        }
+
+        /// Check `IssuanceLimits::one_per_account` for an existing instance moving to `to`.
+        fn ensure_can_receive(class: ClassId, to: &T::AccountId) -> DispatchResult {
+            if ClassIssuanceLimits::<T>::get(class).is_some_and(|limits| limits.one_per_account) {
+                ensure!(!Self::holds(to, class), Error::<T>::AlreadyHoldsClass);
+            }
+            Ok(())
+        }
+
+        /// Count one more instance minted by `issuer` in the current epoch.
+        fn note_issuer_issuance(class: ClassId, issuer: &T::AccountId) {
+            let current = Self::current_epoch();
+            IssuerEpochIssuance::<T>::mutate(class, issuer, |(epoch, count)| {
+                if *epoch != current {
+                    *epoch = current;
+                    *count = 0;
+                }
+                *count = count.saturating_add(1);
+            });
+        }
+
+        /// Mint `class` to `to`, or record a pending offer if the class requires acceptance.
+        /// An expired offer for the same recipient and class is replaced. `budget`: see `do_issue`.
+        pub fn issue_or_offer(
+            class: ClassId,
+            issuer: T::AccountId,
+            to: T::AccountId,
+            uri_hash: [u8; 32],
+            transferable: Option<bool>,
+            soulbound: Option<bool>,
+            budget: &mut u32,
+        ) -> DispatchResult {
+            if !Self::requires_acceptance(class) {
+                return Self::do_issue(class, issuer, to, uri_hash, transferable, soulbound, budget).map(|_| ())
+            }
+
+            // reject offers that could never be accepted
+            let class_info = Classes::<T>::get(class).ok_or(Error::<T>::ClassNotFound)?;
+            Self::ensure_within_limits(class, &class_info, &issuer, &to)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(existing) = Offers::<T>::get(&to, class) {
+                ensure!(now > existing.expires_at, Error::<T>::OfferAlreadyPending);
+            }
+            let expires_at = now.saturating_add(T::OfferExpiry::get());
+            Offers::<T>::insert(
+                &to,
+                class,
+                BadgeOffer { issuer: issuer.clone(), uri_hash, transferable, soulbound, expires_at },
+            );
+            Self::deposit_event(Event::BadgeOffered { class, to, issuer, expires_at });
+            Ok(())
+        }
+
+        /// Mint a new instance of `class` to `to`. Permission checks are the caller's responsibility;
+        /// prerequisites and per-class limits are enforced here. `budget` is the number of
+        /// auto-awards still allowed (see `on_badge_issued`); callers start from
+        /// `MaxAutoAwardDependents` and refund the weight of what is left.
+        pub fn do_issue(
+            class: ClassId,
+            issuer: T::AccountId,
+            to: T::AccountId,
+            uri_hash: [u8; 32],
+            transferable: Option<bool>,
+            soulbound: Option<bool>,
+            budget: &mut u32,
+        ) -> Result<InstanceId, DispatchError> {
+            ensure!(Self::meets_prerequisites(&to, class), Error::<T>::PrerequisitesNotMet);
+
+            let instance = Classes::<T>::try_mutate(class, |maybe_class| -> Result<InstanceId, DispatchError> {
+                let class_info = maybe_class.as_mut().ok_or(Error::<T>::ClassNotFound)?;
+
+                // next instance id per-class
+                let next_inst = NextInstanceId::<T>::get(class);
+                let max_per_class = T::MaxInstancesPerClass::get();
+                ensure!(next_inst < (max_per_class as InstanceId), Error::<T>::InstancesLimitReached);
+                Self::ensure_within_limits(class, class_info, &issuer, &to)?;
+                Self::note_issuer_issuance(class, &issuer);
+
+                let now = T::TimeProvider::now().as_millis().saturated_into::<Moment>();
+
+                let inst_transferable = transferable.unwrap_or(class_info.default_transferable);
+                let inst_soulbound = soulbound.unwrap_or(class_info.default_soulbound);
+
+                let instance = BadgeInstance {
+                    owner: to.clone(),
+                    issued_at: now,
+                    issuer,
+                    uri_hash,
+                    transferable: inst_transferable,
+                    soulbound: inst_soulbound,
+                };
+
+                T::NftBackend::issued(class, next_inst, &to, &uri_hash)?;
+                BadgeInstances::<T>::insert(class, next_inst, instance);
+
+                // update class instances_count
+                class_info.instances_count = class_info.instances_count.saturating_add(1);
+
+                // record in index
+                ClassInstances::<T>::insert(class, next_inst, ());
+
+                NextInstanceId::<T>::insert(class, next_inst.saturating_add(1));
+                Ok(next_inst)
+            })?;
+
+            Holdings::<T>::mutate(&to, class, |n| *n = n.saturating_add(1));
+
+            Self::deposit_event(Event::BadgeIssued { class, instance, to: to.clone() });
+            Self::on_badge_issued(&to, class, instance, budget);
+            Ok(instance)
+        }
+
+        /// Delete an instance and all state attached to it (revocation and burning), retracting its
+        /// mirrors.
+        fn remove_instance(class: ClassId, instance: InstanceId) -> DispatchResult {
+            let removed = BadgeInstances::<T>::try_mutate_exists(class, instance, |maybe| {
+                maybe.take().ok_or(Error::<T>::InstanceNotFound)
+            })?;
+            ClassInstances::<T>::remove(class, instance);
+            Holdings::<T>::mutate(&removed.owner, class, |n| *n = n.saturating_sub(1));
+            BadgeUriHistory::<T>::remove(class, instance);
+            FrozenBadgeUris::<T>::remove(class, instance);
+            Endorsements::<T>::remove(class, instance);
+            PendingReassignments::<T>::remove(class, instance);
+            HiddenBadges::<T>::remove(class, instance);
+            AttributeRoots::<T>::remove(class, instance);
+            T::NftBackend::revoked(class, instance)?;
+            T::BadgeHooks::on_badge_lost(&removed.owner, class, instance, Self::class_club(class));
+            if let Some(mirrors) = Mirrors::<T>::take(class, instance) {
+                Self::update_mirrors(class, instance, mirrors, MirrorUpdate::Retract { class, instance });
+            }
+            Ok(())
+        }
+
+        /// Move a soulbound instance to `to`, bypassing the transferability checks of `transfer_badge`.
+        /// `to` is auto-awarded any class it now qualifies for, within `budget`.
+        fn do_reassign(
+            class: ClassId,
+            instance: InstanceId,
+            to: T::AccountId,
+            evidence_hash: [u8; 32],
+            requested_by: Option<T::AccountId>,
+            budget: &mut u32,
+        ) -> DispatchResult {
+            BadgeInstances::<T>::try_mutate(class, instance, |maybe| -> DispatchResult {
+                let inst = maybe.as_mut().ok_or(Error::<T>::InstanceNotFound)?;
+                ensure!(inst.soulbound, Error::<T>::NotSoulbound);
+                if inst.owner != to {
+                    Self::ensure_can_receive(class, &to)?;
+                }
+
+                let from = sp_std::mem::replace(&mut inst.owner, to.clone());
+                Holdings::<T>::mutate(&from, class, |n| *n = n.saturating_sub(1));
+                Holdings::<T>::mutate(&to, class, |n| *n = n.saturating_add(1));
+                HiddenBadges::<T>::remove(class, instance);
+                T::NftBackend::transferred(class, instance, &to)?;
+                if from != to {
+                    let club = Self::class_club(class);
+                    T::BadgeHooks::on_badge_lost(&from, class, instance, club);
+                    T::BadgeHooks::on_badge_gained(&to, class, instance, club);
+                }
+
+                if let Some(mirrors) = Mirrors::<T>::get(class, instance) {
+                    let update = MirrorUpdate::Attest(Self::attestation(class, instance, inst.clone()));
+                    Self::update_mirrors(class, instance, mirrors, update);
+                }
+
+                Self::deposit_event(Event::SoulboundReassigned {
+                    class,
+                    instance,
+                    from,
+                    to: to.clone(),
+                    evidence_hash,
+                    requested_by,
+                });
+                Ok(())
+            })?;
+            Self::award_dependents(&to, class, budget);
+            Ok(())
+        }
+
+        /// What mirrors of an instance attest to
+        fn attestation(
+            class: ClassId,
+            instance: InstanceId,
+            inst: BadgeInstance<T::AccountId>,
+        ) -> BadgeAttestation<T::AccountId> {
+            BadgeAttestation {
+                class,
+                instance,
+                owner: inst.owner,
+                issuer: inst.issuer,
+                uri_hash: inst.uri_hash,
+                soulbound: inst.soulbound,
+                attributes_root: AttributeRoots::<T>::get(class, instance),
+            }
+        }
+
+        fn send_mirror_update(dest: Location, update: &MirrorUpdate<T::AccountId>) -> Result<XcmHash, DispatchError> {
+            let message = T::MirrorMessage::build(&dest, update).ok_or(Error::<T>::MirroringUnsupported)?;
+            let (message_id, _) =
+                xcm::latest::send_xcm::<T::XcmSender>(dest, message).map_err(|_| Error::<T>::MirrorSendFailed)?;
+            Ok(message_id)
+        }
+
+        /// Send `update` to every mirror in `mirrors`. Never fails: mirrors it can't be sent to
+        /// are recorded in `StaleMirrors` (replacing what they missed before), the others are
+        /// cleared from it.
+        fn update_mirrors(
+            class: ClassId,
+            instance: InstanceId,
+            mirrors: BoundedVec<VersionedLocation, T::MaxMirrorsPerBadge>,
+            update: MirrorUpdate<T::AccountId>,
+        ) {
+            let retracted = matches!(update, MirrorUpdate::Retract { .. });
+            let mut stale = BoundedVec::<VersionedLocation, T::MaxMirrorsPerBadge>::new();
+            for dest in mirrors {
+                let sent = Location::try_from(dest.clone())
+                    .map_err(|_| Error::<T>::BadDestination.into())
+                    .and_then(|location| Self::send_mirror_update(location, &update));
+                match sent {
+                    Ok(message_id) => {
+                        Self::deposit_event(Event::MirrorUpdated { class, instance, dest, retracted, message_id })
+                    },
+                    Err(_) => {
+                        // at most as many as `mirrors`, which share the bound
+                        let _ = stale.try_push(dest.clone());
+                        Self::deposit_event(Event::MirrorUpdateFailed { class, instance, dest });
+                    },
+                }
+            }
+            if stale.is_empty() {
+                StaleMirrors::<T>::remove(class, instance);
+            } else {
+                StaleMirrors::<T>::insert(class, instance, stale);
+            }
+        }
+
+        /// Remove `dest` from a list of mirrors, dropping the list once empty. False if absent.
+        fn remove_mirror(
+            mirrors: &mut Option<BoundedVec<VersionedLocation, T::MaxMirrorsPerBadge>>,
+            dest: &VersionedLocation,
+        ) -> bool {
+            let Some(list) = mirrors else { return false };
+            let Some(pos) = list.iter().position(|d| d == dest) else { return false };
+            list.remove(pos);
+            if list.is_empty() {
+                *mirrors = None;
+            }
+            true
+        }
+
+        /// Club of a class (None for global or unknown classes)
+        fn class_club(class: ClassId) -> Option<ClubId> {
+            Classes::<T>::get(class).and_then(|c| c.club)
+        }
+
+        /// Hook invoked when badge is issued - notifies `T::BadgeHooks` (reputation) and mints any
+        /// auto-award classes the holder now qualifies for.
+        pub fn on_badge_issued(who: &T::AccountId, class: ClassId, instance: InstanceId, budget: &mut u32) {
+            T::BadgeHooks::on_badge_gained(who, class, instance, Self::class_club(class));
+            Self::award_dependents(who, class, budget);
+        }
+
+        /// Award (or offer) `who` every auto-award class depending on `class` whose prerequisites
+        /// it now meets. Each attempt uses one unit of `budget`, including those of chained
+        /// auto-awards; nothing more is attempted once it is spent.
+        fn award_dependents(who: &T::AccountId, class: ClassId, budget: &mut u32) {
+            for meta in AutoAwardDependents::<T>::get(class).into_iter() {
+                if *budget == 0 {
+                    break;
+                }
+                if Self::holds(who, meta) || !Self::meets_prerequisites(who, meta) {
+                    continue;
+                }
+                let Some(meta_info) = Classes::<T>::get(meta) else { continue };
+                *budget -= 1;
+                if Self::requires_acceptance(meta) {
+                    // offered rather than minted; an offer already pending is left as is
+                    let _ = frame_support::storage::with_storage_layer(|| {
+                        Self::issue_or_offer(
+                            meta,
+                            meta_info.creator.clone(),
+                            who.clone(),
+                            meta_info.metadata_hash,
+                            None,
+                            None,
+                            budget,
+                        )
+                    });
+                    continue;
+                }
+                // Auto-awards are best-effort: a failure (e.g. instance limit reached) must not undo
+                // the issuance that triggered it, so run it in its own storage layer.
+                let awarded = frame_support::storage::with_storage_layer(|| {
+                    Self::do_issue(meta, meta_info.creator.clone(), who.clone(), meta_info.metadata_hash, None, None, budget)
+                });
+                if let Ok(meta_instance) = awarded {
+                    Self::deposit_event(Event::BadgeAutoAwarded { class: meta, instance: meta_instance, to: who.clone() });
+                }
+            }
+        }
+
+        /// Worst-case weight of the auto-awards `holdings` new holdings may trigger
+        pub fn max_auto_award_weight(holdings: u32) -> Weight {
+            T::WeightInfo::issue_badge().saturating_mul(T::MaxAutoAwardDependents::get().saturating_mul(holdings).into())
+        }
+
+        /// Upper bound of the weight of sending an update to every mirror of a badge
+        pub fn max_mirror_updates_weight() -> Weight {
+            T::WeightInfo::mirror_badge().saturating_mul(T::MaxMirrorsPerBadge::get().into())
         }
 
-        /// Hook invoked when badge is issued - placeholder for reputation/notifications
-        pub fn on_badge_issued(who: &T::AccountId, class: ClassId, instance: InstanceId) {
-            // Example: call reputation pallet hook if present
-            // NOTE: this call must be optional and guarded with cfg or presence check in runtime
-            // pallet_reputation::Pallet::<T>::on_event_award_reputation(who, 10);
-            let _ = (class, instance, who);
+        /// Weight of the auto-awards attempted, given the `budget` left of `MaxAutoAwardDependents`
+        fn used_auto_award_weight(budget: u32) -> Weight {
+            let used = T::MaxAutoAwardDependents::get().saturating_sub(budget);
+            T::WeightInfo::issue_badge().saturating_mul(used.into())
         }
     }
 
@@ -384,6 +1946,8 @@ pub mod pallet {
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             for (class_id, info) in &self.classes {
+                T::NftBackend::class_created(*class_id, &info.creator, &info.metadata_hash)
+                    .expect("genesis badge class must be creatable in the NFT backend");
                 Classes::<T>::insert(class_id, info.clone());
                 // ensure next_class_id is at least class_id+1
                 let next = NextClassId::<T>::get();
@@ -0,0 +1,41 @@
+//! Storage migrations for pallet-badges.
+
+use crate::{BadgeInstances, ClassInstances, Config, Holdings, Pallet};
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    traits::UncheckedOnRuntimeUpgrade,
+};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+    use super::*;
+
+    /// v0 -> v1: rebuild `ClassInstances` and backfill `Holdings` from `BadgeInstances`.
+    ///
+    /// v0 kept a per-class `BoundedVec<InstanceId, ConstU32<1024>>` that silently stopped growing
+    /// after 1024 issuances and kept revoked ids. v1 stores one `(class, instance)` key per live
+    /// instance under the same storage prefix, so the old values are wiped before rebuilding.
+    /// v0 had no `Holdings` counter, which prerequisite checks now read, so it is recounted from
+    /// the live instances. Runs in a single block: iterates every badge instance once.
+    pub type MigrateToV1<T> = VersionedMigration<0, 1, RebuildClassInstances<T>, Pallet<T>, <T as frame_system::Config>::DbWeight>;
+
+    pub struct RebuildClassInstances<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for RebuildClassInstances<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let cleared = ClassInstances::<T>::clear(u32::MAX, None);
+            let cleared_holdings = Holdings::<T>::clear(u32::MAX, None);
+
+            let mut rebuilt = 0u64;
+            for (class, instance, badge) in BadgeInstances::<T>::iter() {
+                ClassInstances::<T>::insert(class, instance, ());
+                Holdings::<T>::mutate(&badge.owner, class, |n| *n = n.saturating_add(1));
+                rebuilt = rebuilt.saturating_add(1);
+            }
+
+            let cleared = (cleared.unique as u64).saturating_add(cleared_holdings.unique as u64);
+            T::DbWeight::get().reads_writes(rebuilt.saturating_mul(2), rebuilt.saturating_mul(2).saturating_add(cleared))
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Cross-chain badge mirrors over XCM.
+//!
+//! `mirror_badge` sends a read-only copy of a badge to another consensus system (Asset Hub, a
+//! partner parachain). Every destination is then sent a `MirrorUpdate` whenever the badge changes
+//! hands or stops existing:
+//! - `Attest` on mirroring and on soulbound reassignment (the new owner),
+//! - `Retract` on revocation and burning, which always go through: the mirror entries are dropped.
+//!
+//! Updates that can't be sent (destination unroutable at the time) are kept in `StaleMirrors` and
+//! re-sent, from the badge's state at that time, by anyone via `resend_mirror_update`. Owners can't
+//! transfer a mirrored badge until `release_mirror` (governance having retired the remote copy).
+//!
+//! What the destination receives is decided by a `MirrorMessage`:
+//! - `()` disables mirroring.
+//! - `TransactAttestation` sends an unpaid `Transact` carrying the encoded `MirrorUpdate`, to be
+//!   dispatched by a sibling pallet (at `CallIndex`) that checks the message comes from this chain.
+//!
+//! Runtimes minting mirror NFTs directly (e.g. pallet-nfts on Asset Hub) implement `MirrorMessage`
+//! with the destination's own call encoding.
+//!
+//! `integration-tests/xcm` delivers these messages to an emulated sibling running such a pallet.
+
+use crate::{ClassId, InstanceId};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::{pallet_prelude::*, traits::Get};
+use sp_std::{marker::PhantomData, vec};
+use xcm::latest::prelude::*;
+
+/// What a mirror attests to, as of the block it was sent
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BadgeAttestation<AccountId> {
+    pub class: ClassId,
+    pub instance: InstanceId,
+    pub owner: AccountId,
+    pub issuer: AccountId,
+    pub uri_hash: [u8; 32],
+    pub soulbound: bool,
+    /// Merkle root of the instance attributes, if committed (see `attributes`)
+    pub attributes_root: Option<[u8; 32]>,
+}
+
+/// What a mirror is told
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MirrorUpdate<AccountId> {
+    /// The badge as of the block this was sent
+    Attest(BadgeAttestation<AccountId>),
+    /// The badge was revoked or burned; the mirror must be retired
+    Retract { class: ClassId, instance: InstanceId },
+}
+
+/// Builds the XCM program sending `update` to the mirror at `dest`. None => mirroring unsupported.
+pub trait MirrorMessage<AccountId> {
+    fn build(dest: &Location, update: &MirrorUpdate<AccountId>) -> Option<Xcm<()>>;
+}
+
+/// Mirroring disabled.
+impl<AccountId> MirrorMessage<AccountId> for () {
+    fn build(_: &Location, _: &MirrorUpdate<AccountId>) -> Option<Xcm<()>> {
+        None
+    }
+}
+
+/// `UnpaidExecution` + `Transact` of the call `CallIndex ++ update.encode()` with the sovereign
+/// account origin of this chain. Destinations must allow unpaid execution from this chain.
+pub struct TransactAttestation<CallIndex>(PhantomData<CallIndex>);
+
+impl<AccountId: Encode, CallIndex: Get<[u8; 2]>> MirrorMessage<AccountId> for TransactAttestation<CallIndex> {
+    fn build(_: &Location, update: &MirrorUpdate<AccountId>) -> Option<Xcm<()>> {
+        let call = (CallIndex::get(), update).encode();
+        Some(Xcm(vec![
+            UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+            Transact { origin_kind: OriginKind::SovereignAccount, fallback_max_weight: None, call: call.into() },
+        ]))
+    }
+}
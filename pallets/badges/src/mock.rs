@@ -0,0 +1,264 @@
+//! Mock runtime for pallet-badges tests.
+
+#![cfg(test)]
+
+use sp_core::H256;
+use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use frame_support::{
+    parameter_types,
+    construct_runtime,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Everything, IsInVec},
+};
+use crate as pallet_badges;
+use codec::Encode;
+use std::cell::RefCell;
+use xcm::latest::prelude::*;
+
+// --- Type aliases used in the mock runtime ---
+pub type AccountId = u64;
+pub type Balance = u64;
+pub type Nonce = u64;
+
+// --- Parameter types ---
+parameter_types! {
+    pub const BlockHashCount: u32 = 2400;
+    pub const SS58Prefix: u8 = 42;
+    pub const MinimumPeriod: u64 = 1;
+    pub const ExistentialDeposit: Balance = 1;
+    pub NftFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+    /// Owner/admin of the pallet-nfts collections mirroring badge classes
+    pub const BadgesNftOwner: AccountId = 999;
+    /// Accounts holding the endorser role
+    pub Mentors: Vec<AccountId> = vec![MENTOR];
+}
+
+pub const MENTOR: AccountId = 50;
+
+/// Asset Hub as seen from a parachain
+pub fn asset_hub() -> Location {
+    Location::new(1, [Parachain(1000)])
+}
+
+/// Sibling the mock router can't reach (allowed as mirror destination, delivery fails)
+pub fn unreachable_sibling() -> Location {
+    Location::new(1, [Parachain(3000)])
+}
+
+thread_local! {
+    pub static SENT_XCM: RefCell<Vec<(Location, Xcm<()>)>> = const { RefCell::new(Vec::new()) };
+    pub static ROUTER_DOWN: RefCell<bool> = const { RefCell::new(false) };
+}
+
+/// Make every destination of `TestXcmSender` unroutable (or routable again)
+pub fn set_router_down(down: bool) {
+    ROUTER_DOWN.with(|d| *d.borrow_mut() = down);
+}
+
+/// Messages delivered by `TestXcmSender` so far
+pub fn sent_xcm() -> Vec<(Location, Xcm<()>)> {
+    SENT_XCM.with(|q| q.borrow().clone())
+}
+
+/// Records messages to siblings 1000 and 2000 unless `set_router_down`; every other destination
+/// is unroutable.
+pub struct TestXcmSender;
+impl SendXcm for TestXcmSender {
+    type Ticket = (Location, Xcm<()>);
+
+    fn validate(dest: &mut Option<Location>, msg: &mut Option<Xcm<()>>) -> SendResult<Self::Ticket> {
+        let d = dest.take().ok_or(SendError::MissingArgument)?;
+        match d.unpack() {
+            (1, [Parachain(1000 | 2000)]) if !ROUTER_DOWN.with(|d| *d.borrow()) => {}
+            _ => {
+                *dest = Some(d);
+                return Err(SendError::NotApplicable);
+            }
+        }
+        let m = msg.take().ok_or(SendError::MissingArgument)?;
+        Ok(((d, m), Assets::new()))
+    }
+
+    fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+        let hash = ticket.1.using_encoded(sp_io::hashing::blake2_256);
+        SENT_XCM.with(|q| q.borrow_mut().push(ticket));
+        Ok(hash)
+    }
+}
+
+/// Siblings badges may be mirrored to
+pub struct MirrorSiblings;
+impl frame_support::traits::Contains<Location> for MirrorSiblings {
+    fn contains(l: &Location) -> bool {
+        matches!(l.unpack(), (1, [Parachain(1000 | 2000 | 3000)]))
+    }
+}
+
+parameter_types! {
+    /// Call index of the attestation handler on the destination
+    pub const MirrorCallIndex: [u8; 2] = [60, 0];
+}
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        Nfts: pallet_nfts,
+        Badges: pallet_badges,
+    }
+);
+
+impl frame_system::Config for Test {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = Nonce;
+    type Block = frame_system::mocking::MockBlock<Test>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type BlockHashCount = BlockHashCount;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type ReserveIdentifier = ();
+    type FreezeIdentifier = ();
+    type MaxReserves = ();
+    type MaxFreezes = ();
+    type DoneSlashHandler = ();
+}
+
+// Deposits are zero so the collection owner account needs no funding in tests.
+impl pallet_nfts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type CollectionId = u32;
+    type ItemId = u64;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type Locker = ();
+    type CollectionDeposit = ConstU64<0>;
+    type ItemDeposit = ConstU64<0>;
+    type MetadataDepositBase = ConstU64<0>;
+    type AttributeDepositBase = ConstU64<0>;
+    type DepositPerByte = ConstU64<0>;
+    type StringLimit = ConstU32<64>;
+    type KeyLimit = ConstU32<64>;
+    type ValueLimit = ConstU32<64>;
+    type ApprovalsLimit = ConstU32<10>;
+    type ItemAttributesApprovalsLimit = ConstU32<2>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU64<10000>;
+    type MaxAttributesPerCall = ConstU32<2>;
+    type Features = NftFeatures;
+    type OffchainSignature = TestSignature;
+    type OffchainPublic = UintAuthorityId;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = NftsHelper;
+    type BlockNumberProvider = System;
+}
+
+// pallet-nfts only ships a benchmark helper for `MultiSigner`/`MultiSignature`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NftsHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nfts::BenchmarkHelper<u32, u64, UintAuthorityId, AccountId, TestSignature> for NftsHelper {
+    fn collection(i: u16) -> u32 { i.into() }
+    fn item(i: u16) -> u64 { i.into() }
+    fn signer() -> (UintAuthorityId, AccountId) { (UintAuthorityId(0), 0) }
+    fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+        TestSignature(signer.0, message.to_vec())
+    }
+}
+
+impl pallet_badges::Config for Test {
+    type ClassCreationOrigin = frame_system::EnsureSigned<AccountId>;
+    type TimeProvider = pallet_timestamp::Pallet<Self>;
+    type MaxClasses = ConstU32<100>;
+    type MaxInstancesPerClass = ConstU32<2000>;
+    type MaxMetadataLen = ConstU32<64>;
+    type MaxClassesPerAccount = ConstU32<10>;
+    type MaxPrerequisites = ConstU32<4>;
+    type MaxAutoAwardDependents = ConstU32<4>;
+    type MaxBatchIssuance = ConstU32<8>;
+    type MaxClaimProofLength = ConstU32<16>;
+    type MaxMetadataRevisions = ConstU32<2>;
+    type Endorsers = IsInVec<Mentors>;
+    type MaxEndorsementsPerInstance = ConstU32<2>;
+    type ReassignOrigin = frame_system::EnsureRoot<AccountId>;
+    type ReassignmentDelay = ConstU64<10>;
+    type OfferExpiry = ConstU64<5>;
+    type IssuanceEpochLength = ConstU64<10>;
+    type BadgeHooks = ();
+    type XcmSender = TestXcmSender;
+    type MirrorDestinations = MirrorSiblings;
+    type MirrorMessage = pallet_badges::TransactAttestation<MirrorCallIndex>;
+    type MirrorReleaseOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxMirrorsPerBadge = ConstU32<2>;
+    type NftBackend = pallet_badges::NftsBackend<
+        Nfts,
+        BadgesNftOwner,
+        pallet_nfts::CollectionConfigFor<Test>,
+        pallet_nfts::ItemConfig,
+    >;
+    type WeightInfo = ();
+}
+
+// --- TestExternalities builder ---
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .expect("frame_system storage");
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(1);
+    });
+    ext
+}
@@ -0,0 +1,100 @@
+//! Optional NFT backend for badges.
+//!
+//! pallet-badges keeps its own storage as the source of truth and mirrors every lifecycle step
+//! into a `BadgeBackend`. The default `()` backend does nothing; `NftsBackend` mirrors badges into
+//! any `nonfungibles_v2` implementation (e.g. pallet-nfts) so wallets and explorers can see them:
+//! - class `c` -> collection `c`, owned and administered by `Owner` (a pallet account)
+//! - instance `i` of class `c` -> item `i` of collection `c`, owned by the badge owner
+//!
+//! Every mirrored item is transfer-locked. Soulbound and non-transferable badges therefore can't
+//! move through the NFT pallet, and transferable ones only move through `transfer_badge` (which
+//! unlocks, transfers and re-locks the item), keeping both ledgers in sync.
+
+use crate::{ClassId, InstanceId};
+use frame_support::{
+    pallet_prelude::*,
+    traits::tokens::nonfungibles_v2::{Create, Inspect, Mutate, Transfer},
+};
+use sp_std::marker::PhantomData;
+
+/// Receives badge lifecycle notifications. Errors abort the originating badge operation.
+pub trait BadgeBackend<AccountId> {
+    fn class_created(class: ClassId, creator: &AccountId, metadata_hash: &[u8; 32]) -> DispatchResult;
+    fn issued(class: ClassId, instance: InstanceId, owner: &AccountId, uri_hash: &[u8; 32]) -> DispatchResult;
+    fn revoked(class: ClassId, instance: InstanceId) -> DispatchResult;
+    fn transferred(class: ClassId, instance: InstanceId, to: &AccountId) -> DispatchResult;
+    fn class_metadata_set(class: ClassId, metadata_hash: &[u8; 32]) -> DispatchResult;
+    fn instance_uri_set(class: ClassId, instance: InstanceId, uri_hash: &[u8; 32]) -> DispatchResult;
+}
+
+/// No backend: badges live in pallet-badges storage only.
+impl<AccountId> BadgeBackend<AccountId> for () {
+    fn class_created(_: ClassId, _: &AccountId, _: &[u8; 32]) -> DispatchResult {
+        Ok(())
+    }
+    fn issued(_: ClassId, _: InstanceId, _: &AccountId, _: &[u8; 32]) -> DispatchResult {
+        Ok(())
+    }
+    fn revoked(_: ClassId, _: InstanceId) -> DispatchResult {
+        Ok(())
+    }
+    fn transferred(_: ClassId, _: InstanceId, _: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+    fn class_metadata_set(_: ClassId, _: &[u8; 32]) -> DispatchResult {
+        Ok(())
+    }
+    fn instance_uri_set(_: ClassId, _: InstanceId, _: &[u8; 32]) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Mirror badges into a `nonfungibles_v2` pallet. `CollectionConfig` / `ItemConfig` are the
+/// backend's config types (their `Default` is used); `Owner` owns and administers collections.
+pub struct NftsBackend<Nfts, Owner, CollectionConfig, ItemConfig>(
+    PhantomData<(Nfts, Owner, CollectionConfig, ItemConfig)>,
+);
+
+impl<AccountId, Nfts, Owner, CollectionConfig, ItemConfig> BadgeBackend<AccountId>
+    for NftsBackend<Nfts, Owner, CollectionConfig, ItemConfig>
+where
+    Nfts: Inspect<AccountId, CollectionId = ClassId, ItemId = InstanceId>
+        + Create<AccountId, CollectionConfig>
+        + Mutate<AccountId, ItemConfig>
+        + Transfer<AccountId>,
+    Owner: Get<AccountId>,
+    CollectionConfig: Default,
+    ItemConfig: Default,
+{
+    fn class_created(class: ClassId, _creator: &AccountId, metadata_hash: &[u8; 32]) -> DispatchResult {
+        let owner = Owner::get();
+        Nfts::create_collection_with_id(class, &owner, &owner, &CollectionConfig::default())?;
+        Nfts::set_collection_metadata(None, &class, metadata_hash)
+    }
+
+    fn issued(class: ClassId, instance: InstanceId, owner: &AccountId, uri_hash: &[u8; 32]) -> DispatchResult {
+        Nfts::mint_into(&class, &instance, owner, &ItemConfig::default(), true)?;
+        Nfts::set_item_metadata(None, &class, &instance, uri_hash)?;
+        Nfts::disable_transfer(&class, &instance)
+    }
+
+    fn revoked(class: ClassId, instance: InstanceId) -> DispatchResult {
+        // locked items can't be burned
+        Nfts::enable_transfer(&class, &instance)?;
+        Nfts::burn(&class, &instance, None)
+    }
+
+    fn transferred(class: ClassId, instance: InstanceId, to: &AccountId) -> DispatchResult {
+        Nfts::enable_transfer(&class, &instance)?;
+        Nfts::transfer(&class, &instance, to)?;
+        Nfts::disable_transfer(&class, &instance)
+    }
+
+    fn class_metadata_set(class: ClassId, metadata_hash: &[u8; 32]) -> DispatchResult {
+        Nfts::set_collection_metadata(None, &class, metadata_hash)
+    }
+
+    fn instance_uri_set(class: ClassId, instance: InstanceId, uri_hash: &[u8; 32]) -> DispatchResult {
+        Nfts::set_item_metadata(None, &class, &instance, uri_hash)
+    }
+}
@@ -0,0 +1,1077 @@
+//! Unit tests for pallet-badges using the per-pallet mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{self as pallet_badges, merkle, mock::*, ClaimMode, ClassId};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+
+const CREATOR: AccountId = 1;
+const ALICE: AccountId = 2;
+const BOB: AccountId = 3;
+
+fn create_class(transferable: bool, soulbound: bool) -> ClassId {
+    let id = Badges::next_class_id();
+    assert_ok!(Badges::create_class(RawOrigin::Signed(CREATOR).into(), [1u8; 32], None, transferable, soulbound));
+    id
+}
+
+fn issue(class: ClassId, to: AccountId) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    Badges::issue_badge(RawOrigin::Signed(CREATOR).into(), class, to, [2u8; 32], None, None)
+}
+
+#[test]
+fn issue_transfer_and_revoke_track_holdings() {
+    new_test_ext().execute_with(|| {
+        let class = create_class(true, false);
+        assert_ok!(issue(class, ALICE));
+        assert!(Badges::holds(&ALICE, class));
+
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), class, 0, BOB));
+        assert!(!Badges::holds(&ALICE, class));
+        assert!(Badges::holds(&BOB, class));
+
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 0));
+        assert!(!Badges::holds(&BOB, class));
+
+        // only the class creator may issue a non-club class
+        assert_noop!(
+            Badges::issue_badge(RawOrigin::Signed(ALICE).into(), class, BOB, [0u8; 32], None, None),
+            pallet_badges::Error::<Test>::NotIssuer
+        );
+    });
+}
+
+#[test]
+fn prerequisite_gated_class_requires_holdings() {
+    new_test_ext().execute_with(|| {
+        let beginner = create_class(false, true);
+        let intermediate = create_class(false, true);
+        let graduate = create_class(false, true);
+
+        assert_ok!(Badges::set_prerequisites(
+            RawOrigin::Signed(CREATOR).into(),
+            graduate,
+            vec![beginner, intermediate],
+            false
+        ));
+
+        assert_ok!(issue(beginner, ALICE));
+        assert_noop!(issue(graduate, ALICE), pallet_badges::Error::<Test>::PrerequisitesNotMet);
+
+        assert_ok!(issue(intermediate, ALICE));
+        assert_ok!(issue(graduate, ALICE));
+        // not auto-award: nothing minted besides the explicit issuance
+        assert_eq!(Badges::holdings(ALICE, graduate), 1);
+    });
+}
+
+#[test]
+fn auto_award_class_is_minted_once_prerequisites_are_met() {
+    new_test_ext().execute_with(|| {
+        let ctf_beginner = create_class(false, true);
+        let ctf_intermediate = create_class(false, true);
+        let secure_coding = create_class(false, true);
+        let graduate = create_class(false, true);
+
+        assert_ok!(Badges::set_prerequisites(
+            RawOrigin::Signed(CREATOR).into(),
+            graduate,
+            vec![ctf_beginner, ctf_intermediate, secure_coding],
+            true
+        ));
+
+        assert_ok!(issue(ctf_beginner, ALICE));
+        assert_ok!(issue(ctf_intermediate, ALICE));
+        assert!(!Badges::holds(&ALICE, graduate));
+
+        assert_ok!(issue(secure_coding, ALICE));
+        assert!(Badges::holds(&ALICE, graduate));
+        let inst = Badges::instance_metadata(graduate, 0).expect("auto-awarded");
+        assert_eq!(inst.owner, ALICE);
+        assert_eq!(inst.issuer, CREATOR);
+        System::assert_has_event(
+            pallet_badges::Event::<Test>::BadgeAutoAwarded { class: graduate, instance: 0, to: ALICE }.into(),
+        );
+
+        // a second prerequisite instance does not mint the meta-badge again
+        assert_ok!(issue(secure_coding, ALICE));
+        assert_eq!(Badges::holdings(ALICE, graduate), 1);
+    });
+}
+
+#[test]
+fn auto_award_weight_is_charged_upfront_and_refunded() {
+    use pallet_badges::WeightInfo;
+    use frame_support::dispatch::GetDispatchInfo;
+    new_test_ext().execute_with(|| {
+        let workshop = create_class(false, true);
+        let graduate = create_class(false, true);
+        assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), graduate, vec![workshop], true));
+
+        let call = pallet_badges::Call::<Test>::issue_badge {
+            class: workshop,
+            to: ALICE,
+            uri_hash: [2u8; 32],
+            transferable: None,
+            soulbound: None,
+        };
+        let base = <() as WeightInfo>::issue_badge();
+        assert_eq!(call.get_dispatch_info().call_weight, base * 5);
+
+        // one auto-award attempted: charged for it, the rest is refunded
+        let post = issue(workshop, ALICE).unwrap();
+        assert!(Badges::holds(&ALICE, graduate));
+        assert_eq!(post.actual_weight, Some(base * 2));
+
+        // nothing left to award
+        let post = issue(workshop, BOB).unwrap();
+        assert_eq!(post.actual_weight, Some(base * 2));
+        let post = issue(workshop, ALICE).unwrap();
+        assert_eq!(post.actual_weight, Some(base));
+    });
+}
+
+#[test]
+fn auto_award_chains_are_bounded_by_the_budget() {
+    new_test_ext().execute_with(|| {
+        // root -> c1 -> c2 -> ... -> c5, each auto-awarded on the previous one
+        let root = create_class(false, true);
+        let mut chain = vec![root];
+        for _ in 0..5 {
+            let next = create_class(false, true);
+            let prev = *chain.last().unwrap();
+            assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), next, vec![prev], true));
+            chain.push(next);
+        }
+
+        assert_ok!(issue(root, ALICE));
+        // MaxAutoAwardDependents = 4 awards per issuance
+        for class in &chain[..5] {
+            assert!(Badges::holds(&ALICE, *class));
+        }
+        assert!(!Badges::holds(&ALICE, chain[5]));
+    });
+}
+
+#[test]
+fn transfer_and_reassignment_trigger_auto_awards() {
+    new_test_ext().execute_with(|| {
+        let ticket = create_class(true, false);
+        let soulbound = create_class(false, true);
+        let attendee = create_class(false, true);
+        let veteran = create_class(false, true);
+        assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), attendee, vec![ticket], true));
+        assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), veteran, vec![soulbound], true));
+
+        assert_ok!(issue(ticket, ALICE));
+        assert!(Badges::holds(&ALICE, attendee));
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), ticket, 0, BOB));
+        assert!(Badges::holds(&BOB, attendee));
+
+        assert_ok!(issue(soulbound, ALICE));
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Root.into(), soulbound, 0, BOB, [9u8; 32]));
+        assert!(Badges::holds(&BOB, veteran));
+    });
+}
+
+#[test]
+fn set_prerequisites_validates_input() {
+    new_test_ext().execute_with(|| {
+        let a = create_class(false, false);
+        let b = create_class(false, false);
+
+        assert_noop!(
+            Badges::set_prerequisites(RawOrigin::Signed(ALICE).into(), b, vec![a], false),
+            pallet_badges::Error::<Test>::NotClassOwner
+        );
+        assert_noop!(
+            Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), b, vec![b], false),
+            pallet_badges::Error::<Test>::InvalidPrerequisite
+        );
+        assert_noop!(
+            Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), b, vec![a, a], false),
+            pallet_badges::Error::<Test>::InvalidPrerequisite
+        );
+        assert_noop!(
+            Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), b, vec![], true),
+            pallet_badges::Error::<Test>::InvalidPrerequisite
+        );
+        assert_noop!(
+            Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), b, vec![42], false),
+            pallet_badges::Error::<Test>::ClassNotFound
+        );
+
+        // switching an auto-award class back to manual clears the reverse index
+        assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), b, vec![a], true));
+        assert_eq!(Badges::auto_award_dependents(a).into_inner(), vec![b]);
+        assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), b, vec![a], false));
+        assert!(Badges::auto_award_dependents(a).is_empty());
+    });
+}
+
+#[test]
+fn batch_issuance_reports_per_recipient_results() {
+    new_test_ext().execute_with(|| {
+        let workshop = create_class(false, true);
+        let followup = create_class(false, true);
+        assert_ok!(Badges::set_prerequisites(RawOrigin::Signed(CREATOR).into(), followup, vec![workshop], false));
+        assert_ok!(issue(workshop, ALICE));
+
+        // only ALICE holds the prerequisite; BOB's failure must not abort the batch
+        assert_ok!(Badges::issue_badges_batch(
+            RawOrigin::Signed(CREATOR).into(),
+            followup,
+            vec![(ALICE, [3u8; 32]), (BOB, [4u8; 32])]
+        ));
+        assert!(Badges::holds(&ALICE, followup));
+        assert!(!Badges::holds(&BOB, followup));
+        assert_eq!(Badges::instance_metadata(followup, 0).unwrap().uri_hash, [3u8; 32]);
+        System::assert_has_event(
+            pallet_badges::Event::<Test>::BatchIssueFailed {
+                class: followup,
+                to: BOB,
+                error: pallet_badges::Error::<Test>::PrerequisitesNotMet.into(),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            pallet_badges::Event::<Test>::BatchIssued { class: followup, issued: 1, failed: 1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn batch_issuance_is_bounded_and_permissioned() {
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        let too_many: Vec<_> = (10..19).map(|who| (who, [0u8; 32])).collect();
+        assert_noop!(
+            Badges::issue_badges_batch(RawOrigin::Signed(CREATOR).into(), class, too_many),
+            pallet_badges::Error::<Test>::BatchTooLarge
+        );
+        assert_noop!(
+            Badges::issue_badges_batch(RawOrigin::Signed(ALICE).into(), class, vec![(BOB, [0u8; 32])]),
+            pallet_badges::Error::<Test>::NotIssuer
+        );
+    });
+}
+
+#[test]
+fn batch_weight_scales_linearly_with_recipients() {
+    use pallet_badges::WeightInfo;
+    let batch = <() as WeightInfo>::issue_badges_batch;
+    let per_recipient = batch(2).saturating_sub(batch(1));
+    assert!(per_recipient.ref_time() > 0);
+    assert_eq!(batch(10), batch(1).saturating_add(per_recipient.saturating_mul(9)));
+    // a batch of one costs about as much as a single issuance, never less
+    assert!(batch(1).all_gte(<() as WeightInfo>::issue_badge()));
+}
+
+#[test]
+fn merkle_claim_by_account() {
+    new_test_ext().execute_with(|| {
+        let poap = create_class(false, true);
+        let eligible: Vec<AccountId> = vec![ALICE, BOB, 4, 5, 6];
+        let leaves: Vec<[u8; 32]> = eligible
+            .iter()
+            .enumerate()
+            .map(|(i, who)| Badges::account_claim_leaf(i as u32, who))
+            .collect();
+        let root = merkle::merkle_root(&leaves).unwrap();
+
+        assert_ok!(Badges::open_claim(
+            RawOrigin::Signed(CREATOR).into(),
+            poap,
+            root,
+            ClaimMode::Account,
+            leaves.len() as u32,
+            2,
+            10
+        ));
+
+        let proof = merkle::merkle_proof(&leaves, 1).unwrap();
+        // window not open yet
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(BOB).into(), poap, 1, proof.clone(), None),
+            pallet_badges::Error::<Test>::ClaimNotOpen
+        );
+
+        System::set_block_number(2);
+        // someone else cannot use BOB's leaf
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 1, proof.clone(), None),
+            pallet_badges::Error::<Test>::InvalidProof
+        );
+        assert_ok!(Badges::claim_badge(RawOrigin::Signed(BOB).into(), poap, 1, proof.clone(), None));
+        let inst = Badges::instance_metadata(poap, 0).unwrap();
+        assert_eq!((inst.owner, inst.issuer), (BOB, CREATOR));
+        assert!(Badges::is_claimed(poap, root, 1));
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(BOB).into(), poap, 1, proof, None),
+            pallet_badges::Error::<Test>::AlreadyClaimed
+        );
+
+        // the odd leaf at the end of the tree is claimable too
+        System::set_block_number(10);
+        let last = merkle::merkle_proof(&leaves, 4).unwrap();
+        assert_ok!(Badges::claim_badge(RawOrigin::Signed(6).into(), poap, 4, last, None));
+
+        System::set_block_number(11);
+        let proof0 = merkle::merkle_proof(&leaves, 0).unwrap();
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 0, proof0, None),
+            pallet_badges::Error::<Test>::ClaimWindowClosed
+        );
+
+        assert_ok!(Badges::close_claim(RawOrigin::Signed(CREATOR).into(), poap));
+        assert!(Badges::claim_campaign(poap).is_none());
+
+        // reopening the same root keeps the claimed leaves claimed
+        assert!(Badges::is_claimed(poap, root, 1));
+        assert_ok!(Badges::open_claim(RawOrigin::Signed(CREATOR).into(), poap, root, ClaimMode::Account, 5, 11, 20));
+        let proof1 = merkle::merkle_proof(&leaves, 1).unwrap();
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(BOB).into(), poap, 1, proof1, None),
+            pallet_badges::Error::<Test>::AlreadyClaimed
+        );
+        let proof0 = merkle::merkle_proof(&leaves, 0).unwrap();
+        assert_ok!(Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 0, proof0, None));
+    });
+}
+
+#[test]
+fn merkle_claim_by_code() {
+    new_test_ext().execute_with(|| {
+        let poap = create_class(false, true);
+        let codes: Vec<&[u8]> = vec![b"alpha", b"bravo", b"charlie"];
+        let leaves: Vec<[u8; 32]> = codes
+            .iter()
+            .enumerate()
+            .map(|(i, code)| Badges::code_claim_leaf(i as u32, &Badges::code_claim_key(code)))
+            .collect();
+        let root = merkle::merkle_root(&leaves).unwrap();
+        assert_ok!(Badges::open_claim(RawOrigin::Signed(CREATOR).into(), poap, root, ClaimMode::Code, 3, 1, 5));
+        assert_noop!(
+            Badges::open_claim(RawOrigin::Signed(CREATOR).into(), poap, root, ClaimMode::Code, 3, 1, 5),
+            pallet_badges::Error::<Test>::ClaimAlreadyOpen
+        );
+
+        let proof = merkle::merkle_proof(&leaves, 2).unwrap();
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 2, proof.clone(), None),
+            pallet_badges::Error::<Test>::ClaimCodeRequired
+        );
+        let wrong_code = Badges::sign_code_claim(b"bravo", poap, 2, &ALICE);
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 2, proof.clone(), Some(wrong_code)),
+            pallet_badges::Error::<Test>::InvalidProof
+        );
+
+        // a claim seen in the pool can't be replayed by anyone else, nor for another leaf
+        let claim = Badges::sign_code_claim(b"charlie", poap, 2, &ALICE);
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(BOB).into(), poap, 2, proof.clone(), Some(claim.clone())),
+            pallet_badges::Error::<Test>::InvalidClaimSignature
+        );
+        let other_leaf = merkle::merkle_proof(&leaves, 1).unwrap();
+        assert_noop!(
+            Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 1, other_leaf, Some(claim.clone())),
+            pallet_badges::Error::<Test>::InvalidClaimSignature
+        );
+
+        assert_ok!(Badges::claim_badge(RawOrigin::Signed(ALICE).into(), poap, 2, proof, Some(claim)));
+        assert!(Badges::holds(&ALICE, poap));
+    });
+}
+
+#[test]
+fn nft_backend_mirrors_badges_and_locks_transfers() {
+    use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+
+    new_test_ext().execute_with(|| {
+        let transferable = create_class(true, false);
+        let soulbound = create_class(false, true);
+        assert_eq!(<Nfts as Inspect<AccountId>>::collection_owner(&transferable), Some(BadgesNftOwner::get()));
+
+        assert_ok!(issue(transferable, ALICE));
+        assert_ok!(issue(soulbound, ALICE));
+        assert_eq!(<Nfts as Inspect<AccountId>>::owner(&transferable, &0), Some(ALICE));
+        assert_eq!(<Nfts as Inspect<AccountId>>::owner(&soulbound, &0), Some(ALICE));
+
+        // items can't be moved behind pallet-badges' back
+        assert!(!<Nfts as Inspect<AccountId>>::can_transfer(&transferable, &0));
+        assert!(Nfts::transfer(RawOrigin::Signed(ALICE).into(), soulbound, 0, BOB).is_err());
+        assert!(Nfts::transfer(RawOrigin::Signed(ALICE).into(), transferable, 0, BOB).is_err());
+
+        // transfer_badge keeps both ledgers in sync and re-locks the item
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), transferable, 0, BOB));
+        assert_eq!(<Nfts as Inspect<AccountId>>::owner(&transferable, &0), Some(BOB));
+        assert!(!<Nfts as Inspect<AccountId>>::can_transfer(&transferable, &0));
+        assert_noop!(
+            Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), soulbound, 0, BOB),
+            pallet_badges::Error::<Test>::Soulbound
+        );
+
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), transferable, 0));
+        assert_eq!(<Nfts as Inspect<AccountId>>::owner(&transferable, &0), None);
+    });
+}
+
+#[test]
+fn class_metadata_is_versioned_until_frozen() {
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        assert_noop!(
+            Badges::set_class_metadata(RawOrigin::Signed(ALICE).into(), class, [3u8; 32]),
+            pallet_badges::Error::<Test>::NotClassOwner
+        );
+
+        for (step, hash) in [[3u8; 32], [4u8; 32], [5u8; 32]].into_iter().enumerate() {
+            System::set_block_number(step as u64 + 2);
+            assert_ok!(Badges::set_class_metadata(RawOrigin::Signed(CREATOR).into(), class, hash));
+        }
+        assert_eq!(Badges::class_info(class).unwrap().metadata_hash, [5u8; 32]);
+        assert_eq!(Badges::class_metadata_version(class), 3);
+        assert_eq!(pallet_nfts::CollectionMetadataOf::<Test>::get(class).unwrap().data.to_vec(), vec![5u8; 32]);
+
+        // history is bounded to MaxMetadataRevisions (2): the original hash was dropped
+        let history = Badges::class_metadata_history(class);
+        assert_eq!(history.iter().map(|r| (r.version, r.hash, r.replaced_at)).collect::<Vec<_>>(), vec![
+            (1, [3u8; 32], 3),
+            (2, [4u8; 32], 4),
+        ]);
+
+        assert_ok!(Badges::freeze_class_metadata(RawOrigin::Signed(CREATOR).into(), class));
+        assert!(Badges::is_class_metadata_frozen(class));
+        assert_noop!(
+            Badges::set_class_metadata(RawOrigin::Signed(CREATOR).into(), class, [6u8; 32]),
+            pallet_badges::Error::<Test>::MetadataFrozen
+        );
+        assert_noop!(
+            Badges::freeze_class_metadata(RawOrigin::Signed(CREATOR).into(), class),
+            pallet_badges::Error::<Test>::MetadataFrozen
+        );
+    });
+}
+
+#[test]
+fn badge_uri_updates_follow_class_metadata_rules() {
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        assert_ok!(issue(class, ALICE));
+
+        // only the issuer may update, not the holder
+        assert_noop!(
+            Badges::set_badge_uri(RawOrigin::Signed(ALICE).into(), class, 0, [7u8; 32]),
+            pallet_badges::Error::<Test>::NotIssuer
+        );
+        assert_ok!(Badges::set_badge_uri(RawOrigin::Signed(CREATOR).into(), class, 0, [7u8; 32]));
+        assert_eq!(Badges::instance_metadata(class, 0).unwrap().uri_hash, [7u8; 32]);
+        assert_eq!(Badges::badge_uri_version(class, 0), 1);
+        assert_eq!(Badges::badge_uri_history(class, 0)[0].hash, [2u8; 32]);
+
+        assert_ok!(Badges::freeze_badge_uri(RawOrigin::Signed(CREATOR).into(), class, 0));
+        assert_noop!(
+            Badges::set_badge_uri(RawOrigin::Signed(CREATOR).into(), class, 0, [8u8; 32]),
+            pallet_badges::Error::<Test>::MetadataFrozen
+        );
+
+        // revocation clears the instance's revision state
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 0));
+        assert!(!Badges::is_badge_uri_frozen(class, 0));
+        assert!(Badges::badge_uri_history(class, 0).is_empty());
+    });
+}
+
+#[test]
+fn peers_and_endorser_role_can_endorse_badges() {
+    new_test_ext().execute_with(|| {
+        let winner = create_class(false, true);
+        assert_ok!(issue(winner, ALICE));
+        assert_ok!(issue(winner, BOB));
+
+        // neither the owner nor an outsider may endorse
+        assert_noop!(
+            Badges::endorse_badge(RawOrigin::Signed(ALICE).into(), winner, 0),
+            pallet_badges::Error::<Test>::SelfEndorsement
+        );
+        assert_noop!(
+            Badges::endorse_badge(RawOrigin::Signed(CREATOR).into(), winner, 0),
+            pallet_badges::Error::<Test>::NotEndorser
+        );
+
+        // a fellow holder and a mentor may
+        assert_ok!(Badges::endorse_badge(RawOrigin::Signed(BOB).into(), winner, 0));
+        assert_noop!(
+            Badges::endorse_badge(RawOrigin::Signed(BOB).into(), winner, 0),
+            pallet_badges::Error::<Test>::AlreadyEndorsed
+        );
+        Timestamp::set_timestamp(42);
+        assert_ok!(Badges::endorse_badge(RawOrigin::Signed(MENTOR).into(), winner, 0));
+        let endorsements = Badges::instance_endorsements(winner, 0);
+        assert_eq!(endorsements.iter().map(|e| (e.endorser, e.endorsed_at)).collect::<Vec<_>>(), vec![(BOB, 1), (MENTOR, 42)]);
+
+        // capped at MaxEndorsementsPerInstance (2)
+        assert_ok!(issue(winner, 4));
+        assert_noop!(
+            Badges::endorse_badge(RawOrigin::Signed(4).into(), winner, 0),
+            pallet_badges::Error::<Test>::TooManyEndorsements
+        );
+
+        assert_ok!(Badges::revoke_endorsement(RawOrigin::Signed(BOB).into(), winner, 0));
+        assert_noop!(
+            Badges::revoke_endorsement(RawOrigin::Signed(BOB).into(), winner, 0),
+            pallet_badges::Error::<Test>::EndorsementNotFound
+        );
+        assert_ok!(Badges::endorse_badge(RawOrigin::Signed(4).into(), winner, 0));
+
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), winner, 0));
+        assert!(Badges::instance_endorsements(winner, 0).is_empty());
+    });
+}
+
+#[test]
+fn governance_reassigns_soulbound_badges_immediately() {
+    new_test_ext().execute_with(|| {
+        let transferable = create_class(true, false);
+        let soulbound = create_class(false, true);
+        assert_ok!(issue(transferable, ALICE));
+        assert_ok!(issue(soulbound, ALICE));
+
+        assert_noop!(
+            Badges::reassign_soulbound(RawOrigin::Root.into(), transferable, 0, BOB, [9u8; 32]),
+            pallet_badges::Error::<Test>::NotSoulbound
+        );
+        assert_noop!(
+            Badges::reassign_soulbound(RawOrigin::Signed(ALICE).into(), soulbound, 0, BOB, [9u8; 32]),
+            pallet_badges::Error::<Test>::NotClassOwner
+        );
+
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Root.into(), soulbound, 0, BOB, [9u8; 32]));
+        assert_eq!(Badges::owner_of(soulbound, 0), Some(BOB));
+        assert!(!Badges::holds(&ALICE, soulbound));
+        assert!(Badges::holds(&BOB, soulbound));
+        System::assert_last_event(
+            pallet_badges::Event::<Test>::SoulboundReassigned {
+                class: soulbound,
+                instance: 0,
+                from: ALICE,
+                to: BOB,
+                evidence_hash: [9u8; 32],
+                requested_by: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn class_admin_reassignment_is_timelocked_and_cancellable() {
+    new_test_ext().execute_with(|| {
+        let soulbound = create_class(false, true);
+        assert_ok!(issue(soulbound, ALICE));
+
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Signed(CREATOR).into(), soulbound, 0, BOB, [9u8; 32]));
+        assert_eq!(Badges::pending_reassignment(soulbound, 0).unwrap().executable_at, 11);
+        assert_noop!(
+            Badges::reassign_soulbound(RawOrigin::Signed(CREATOR).into(), soulbound, 0, BOB, [9u8; 32]),
+            pallet_badges::Error::<Test>::ReassignmentPending
+        );
+
+        // the owner still controls their key and vetoes the request
+        assert_noop!(
+            Badges::cancel_reassignment(RawOrigin::Signed(BOB).into(), soulbound, 0),
+            pallet_badges::Error::<Test>::NotClassOwner
+        );
+        assert_ok!(Badges::cancel_reassignment(RawOrigin::Signed(ALICE).into(), soulbound, 0));
+        assert_noop!(
+            Badges::execute_reassignment(RawOrigin::Signed(BOB).into(), soulbound, 0),
+            pallet_badges::Error::<Test>::NoPendingReassignment
+        );
+
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Signed(CREATOR).into(), soulbound, 0, BOB, [9u8; 32]));
+        System::set_block_number(10);
+        assert_noop!(
+            Badges::execute_reassignment(RawOrigin::Signed(BOB).into(), soulbound, 0),
+            pallet_badges::Error::<Test>::ReassignmentTimelocked
+        );
+        System::set_block_number(11);
+        assert_ok!(Badges::execute_reassignment(RawOrigin::Signed(BOB).into(), soulbound, 0));
+        assert_eq!(Badges::owner_of(soulbound, 0), Some(BOB));
+        assert!(Badges::pending_reassignment(soulbound, 0).is_none());
+        System::assert_last_event(
+            pallet_badges::Event::<Test>::SoulboundReassigned {
+                class: soulbound,
+                instance: 0,
+                from: ALICE,
+                to: BOB,
+                evidence_hash: [9u8; 32],
+                requested_by: Some(CREATOR),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn acceptance_classes_issue_offers_that_expire() {
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        assert_noop!(
+            Badges::set_requires_acceptance(RawOrigin::Signed(ALICE).into(), class, true),
+            pallet_badges::Error::<Test>::NotClassOwner
+        );
+        assert_ok!(Badges::set_requires_acceptance(RawOrigin::Signed(CREATOR).into(), class, true));
+
+        // issuing only creates offers
+        assert_ok!(issue(class, ALICE));
+        assert_ok!(Badges::issue_badges_batch(
+            RawOrigin::Signed(CREATOR).into(),
+            class,
+            vec![(BOB, [2u8; 32]), (ALICE, [2u8; 32])]
+        ));
+        System::assert_last_event(pallet_badges::Event::<Test>::BatchIssued { class, issued: 1, failed: 1 }.into());
+        assert!(!Badges::holds(&ALICE, class));
+        assert_eq!(Badges::pending_offers(&ALICE).len(), 1);
+        assert_eq!(Badges::pending_offers(&ALICE)[0].1.expires_at, 6);
+
+        assert_ok!(Badges::accept_offer(RawOrigin::Signed(ALICE).into(), class));
+        assert!(Badges::holds(&ALICE, class));
+        assert!(Badges::pending_offers(&ALICE).is_empty());
+
+        assert_ok!(Badges::decline_offer(RawOrigin::Signed(BOB).into(), class));
+        assert_noop!(
+            Badges::accept_offer(RawOrigin::Signed(BOB).into(), class),
+            pallet_badges::Error::<Test>::OfferNotFound
+        );
+
+        // unclaimed offers expire and can be cleared by anyone
+        assert_ok!(issue(class, BOB));
+        assert_noop!(
+            Badges::clear_expired_offer(RawOrigin::Signed(ALICE).into(), BOB, class),
+            pallet_badges::Error::<Test>::OfferNotExpired
+        );
+        System::set_block_number(7);
+        assert!(Badges::pending_offers(&BOB).is_empty());
+        assert_noop!(
+            Badges::accept_offer(RawOrigin::Signed(BOB).into(), class),
+            pallet_badges::Error::<Test>::OfferExpired
+        );
+        assert_ok!(Badges::clear_expired_offer(RawOrigin::Signed(ALICE).into(), BOB, class));
+        assert!(Badges::offer(BOB, class).is_none());
+
+        // an expired offer may be replaced by a fresh one
+        assert_ok!(issue(class, BOB));
+        System::set_block_number(20);
+        assert_ok!(issue(class, BOB));
+        assert_eq!(Badges::offer(BOB, class).unwrap().expires_at, 25);
+    });
+}
+
+#[test]
+fn owners_can_burn_and_hide_their_badges() {
+    use frame_support::traits::tokens::nonfungibles_v2::Inspect;
+
+    new_test_ext().execute_with(|| {
+        let transferable = create_class(true, false);
+        let soulbound = create_class(false, true);
+        assert_ok!(issue(transferable, ALICE));
+        assert_ok!(issue(soulbound, ALICE));
+
+        assert_noop!(
+            Badges::set_badge_visibility(RawOrigin::Signed(BOB).into(), transferable, 0, false),
+            pallet_badges::Error::<Test>::NotOwner
+        );
+        assert_ok!(Badges::set_badge_visibility(RawOrigin::Signed(ALICE).into(), transferable, 0, false));
+        assert!(!Badges::is_badge_visible(transferable, 0));
+        assert!(Badges::is_badge_visible(soulbound, 0));
+
+        // a new owner starts with the badge visible
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), transferable, 0, BOB));
+        assert!(Badges::is_badge_visible(transferable, 0));
+
+        assert_noop!(
+            Badges::burn_badge(RawOrigin::Signed(BOB).into(), soulbound, 0),
+            pallet_badges::Error::<Test>::NotOwner
+        );
+        assert_ok!(Badges::burn_badge(RawOrigin::Signed(ALICE).into(), soulbound, 0));
+        assert!(Badges::owner_of(soulbound, 0).is_none());
+        assert!(!Badges::holds(&ALICE, soulbound));
+        assert!(!Badges::is_badge_visible(soulbound, 0));
+        assert_eq!(<Nfts as Inspect<AccountId>>::owner(&soulbound, &0), None);
+        System::assert_last_event(
+            pallet_badges::Event::<Test>::BadgeBurned { class: soulbound, instance: 0, owner: ALICE }.into(),
+        );
+    });
+}
+
+#[test]
+fn class_instance_index_is_not_truncated_and_pages() {
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        for _ in 0..1030 {
+            assert_ok!(issue(class, ALICE));
+        }
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 5));
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = Badges::class_instances(class, cursor, 100);
+            if page.is_empty() {
+                break;
+            }
+            cursor = page.last().copied();
+            seen.extend(page);
+        }
+        seen.sort();
+        let expected: Vec<u64> = (0..1030).filter(|i| *i != 5).collect();
+        assert_eq!(seen, expected);
+    });
+}
+
+#[test]
+fn migration_rebuilds_class_instance_index() {
+    use frame_support::{
+        storage_alias,
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+        BoundedVec, Twox64Concat,
+    };
+
+    #[storage_alias]
+    type ClassInstances = StorageMap<Badges, Twox64Concat, ClassId, BoundedVec<u64, frame_support::traits::ConstU32<1024>>>;
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        for _ in 0..3 {
+            assert_ok!(issue(class, ALICE));
+        }
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 1));
+        assert_ok!(issue(class, BOB));
+
+        // recreate the v0 layout: a truncated vec that still lists the revoked instance, no holdings
+        let _ = pallet_badges::ClassInstances::<Test>::clear(u32::MAX, None);
+        let _ = pallet_badges::Holdings::<Test>::clear(u32::MAX, None);
+        ClassInstances::insert(class, BoundedVec::truncate_from(vec![0, 1]));
+        StorageVersion::new(0).put::<Badges>();
+
+        pallet_badges::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Badges::on_chain_storage_version(), 1);
+        let mut ids = Badges::class_instances(class, None, 10);
+        ids.sort();
+        assert_eq!(ids, vec![0, 2, 3]);
+        assert_eq!(Badges::holdings(ALICE, class), 2);
+        assert_eq!(Badges::holdings(BOB, class), 1);
+        assert!(Badges::holds(&BOB, class));
+    });
+}
+
+#[test]
+fn issuance_limits_reject_with_specific_errors() {
+    use pallet_badges::IssuanceLimits;
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(true, false);
+        let limits = IssuanceLimits { max_supply: Some(4), max_per_issuer_per_epoch: Some(2), one_per_account: true };
+        assert_noop!(
+            Badges::set_issuance_limits(RawOrigin::Signed(ALICE).into(), class, limits.clone()),
+            pallet_badges::Error::<Test>::NotClassOwner
+        );
+        assert_ok!(Badges::set_issuance_limits(RawOrigin::Signed(CREATOR).into(), class, limits));
+
+        // one per account
+        assert_ok!(issue(class, ALICE));
+        assert_noop!(issue(class, ALICE), pallet_badges::Error::<Test>::AlreadyHoldsClass);
+
+        // per-issuer epoch limit (epochs are 10 blocks long)
+        assert_ok!(issue(class, BOB));
+        assert_noop!(issue(class, 4), pallet_badges::Error::<Test>::IssuerEpochLimitReached);
+        System::set_block_number(10);
+        assert_ok!(issue(class, 4));
+
+        // supply cap counts every instance ever issued
+        assert_ok!(Badges::burn_badge(RawOrigin::Signed(4).into(), class, 2));
+        assert_ok!(issue(class, 5));
+        assert_noop!(issue(class, 6), pallet_badges::Error::<Test>::SupplyCapReached);
+
+        // clearing the limits lifts every restriction
+        assert_ok!(Badges::set_issuance_limits(RawOrigin::Signed(CREATOR).into(), class, IssuanceLimits::default()));
+        assert!(Badges::issuance_limits(class).is_none());
+        assert_ok!(issue(class, ALICE));
+    });
+}
+
+#[test]
+fn one_per_account_applies_to_transfers_and_reassignments() {
+    use pallet_badges::IssuanceLimits;
+
+    new_test_ext().execute_with(|| {
+        let limits = IssuanceLimits { one_per_account: true, ..Default::default() };
+        let ticket = create_class(true, false);
+        let soulbound = create_class(false, true);
+        for class in [ticket, soulbound] {
+            assert_ok!(Badges::set_issuance_limits(RawOrigin::Signed(CREATOR).into(), class, limits.clone()));
+            assert_ok!(issue(class, ALICE));
+            assert_ok!(issue(class, BOB));
+        }
+
+        assert_noop!(
+            Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), ticket, 0, BOB),
+            pallet_badges::Error::<Test>::AlreadyHoldsClass
+        );
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), ticket, 0, 4));
+
+        assert_noop!(
+            Badges::reassign_soulbound(RawOrigin::Root.into(), soulbound, 0, BOB, [9u8; 32]),
+            pallet_badges::Error::<Test>::AlreadyHoldsClass
+        );
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Root.into(), soulbound, 0, 4, [9u8; 32]));
+        assert_eq!(Badges::holdings(4, soulbound), 1);
+    });
+}
+
+#[test]
+fn ensure_has_badge_gates_on_live_badges() {
+    use frame_support::traits::{ConstU32, EnsureOrigin};
+    use pallet_badges::{BadgeInspect, EnsureHasBadge};
+
+    type GpuAccess = EnsureHasBadge<Test, ConstU32<0>>;
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(true, false);
+        assert_eq!(class, 0);
+        assert!(GpuAccess::try_origin(RawOrigin::Signed(ALICE).into()).is_err());
+        assert!(GpuAccess::try_origin(RawOrigin::Root.into()).is_err());
+
+        assert_ok!(issue(class, ALICE));
+        assert_eq!(GpuAccess::try_origin(RawOrigin::Signed(ALICE).into()).ok(), Some(ALICE));
+        assert!(<Badges as BadgeInspect<AccountId>>::has_badge(&ALICE, class));
+        assert_eq!(<Badges as BadgeInspect<AccountId>>::owner_of(class, 0), Some(ALICE));
+
+        // revoked badges no longer grant access
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 0));
+        assert!(GpuAccess::try_origin(RawOrigin::Signed(ALICE).into()).is_err());
+        assert_eq!(<Badges as BadgeInspect<AccountId>>::balance_of(&ALICE, class), 0);
+    });
+}
+
+#[test]
+fn holders_disclose_single_committed_attributes() {
+    use pallet_badges::attributes::{attributes_root, disclose, BadgeAttribute};
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        assert_ok!(issue(class, ALICE));
+
+        let attrs: Vec<BadgeAttribute> = [("grade", "A"), ("date", "2025-06-01"), ("skills", "rust,frame")]
+            .iter()
+            .enumerate()
+            .map(|(i, (k, v))| BadgeAttribute { key: k.as_bytes().to_vec(), value: v.as_bytes().to_vec(), salt: [i as u8; 32] })
+            .collect();
+        let root = attributes_root(&attrs).unwrap();
+        assert_noop!(
+            Badges::commit_attributes(RawOrigin::Signed(ALICE).into(), class, 0, root),
+            pallet_badges::Error::<Test>::NotIssuer
+        );
+        assert_ok!(Badges::commit_attributes(RawOrigin::Signed(CREATOR).into(), class, 0, root));
+
+        let (leaf, proof) = disclose(&attrs, 0).unwrap();
+        assert!(Badges::verify_badge_attribute(class, 0, leaf, &proof));
+
+        // a forged grade or a wrong salt does not verify
+        let forged = BadgeAttribute { value: b"A+".to_vec(), ..attrs[0].clone() };
+        assert!(!Badges::verify_badge_attribute(class, 0, forged.leaf(), &proof));
+        let unsalted = BadgeAttribute { salt: [9u8; 32], ..attrs[0].clone() };
+        assert!(!Badges::verify_badge_attribute(class, 0, unsalted.leaf(), &proof));
+
+        assert_ok!(Badges::freeze_badge_uri(RawOrigin::Signed(CREATOR).into(), class, 0));
+        assert_noop!(
+            Badges::commit_attributes(RawOrigin::Signed(CREATOR).into(), class, 0, [0u8; 32]),
+            pallet_badges::Error::<Test>::MetadataFrozen
+        );
+
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 0));
+        assert!(!Badges::verify_badge_attribute(class, 0, leaf, &proof));
+    });
+}
+
+#[test]
+fn seeded_attribute_disclosures_verify_against_the_commitment() {
+    use pallet_badges::attributes::{attribute_leaf, disclose_seeded, seeded_root};
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(false, true);
+        assert_ok!(issue(class, ALICE));
+
+        let seed = [7u8; 32];
+        let pairs = [("grade", "A"), ("date", "2025-06-01"), ("skills", "rust,frame")];
+        let root = seeded_root(&seed, &pairs).unwrap();
+        assert_ok!(Badges::commit_attributes(RawOrigin::Signed(CREATOR).into(), class, 0, root));
+
+        for (key, value) in pairs {
+            let d = disclose_seeded(&seed, &pairs, key).unwrap();
+            assert_eq!((d.key.as_slice(), d.value.as_slice()), (key.as_bytes(), value.as_bytes()));
+            assert_eq!(d.leaf, attribute_leaf(&d.key, &d.value, &d.salt));
+            assert!(Badges::verify_badge_attribute(class, 0, d.leaf, &d.proof));
+        }
+        assert!(disclose_seeded(&seed, &pairs, "missing").is_none());
+
+        // same pairs from another seed give different salts, hence another root
+        assert_ne!(seeded_root(&[8u8; 32], &pairs), Some(root));
+
+        // leaves are domain-separated: the unprefixed encoding is not a valid leaf
+        let d = disclose_seeded(&seed, &pairs, "grade").unwrap();
+        let unprefixed = sp_io::hashing::blake2_256(&codec::Encode::encode(&(&d.key, &d.value, &d.salt)));
+        assert_ne!(unprefixed, d.leaf);
+        assert!(!Badges::verify_badge_attribute(class, 0, unprefixed, &d.proof));
+    });
+}
+
+#[test]
+fn mirrored_badges_are_attested_over_xcm_and_locked() {
+    use codec::{Decode, Encode};
+    use pallet_badges::{Error, MirrorUpdate};
+    use xcm::{latest::prelude::*, VersionedLocation};
+
+    let dest = |l: Location| Box::new(VersionedLocation::from(l));
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(true, false);
+        assert_ok!(issue(class, ALICE));
+
+        assert_noop!(
+            Badges::mirror_badge(RawOrigin::Signed(BOB).into(), class, 0, dest(asset_hub())),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, dest(Location::parent())),
+            Error::<Test>::DestinationNotAllowed
+        );
+        assert_noop!(
+            Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, dest(unreachable_sibling())),
+            Error::<Test>::MirrorSendFailed
+        );
+
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, dest(asset_hub())));
+        assert_noop!(
+            Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, dest(asset_hub())),
+            Error::<Test>::AlreadyMirrored
+        );
+
+        // the destination receives an unpaid Transact carrying the attestation
+        let sent = sent_xcm();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, asset_hub());
+        let Some(Transact { origin_kind: OriginKind::SovereignAccount, call, .. }) = sent[0].1 .0.last() else {
+            panic!("mirror message must end with a Transact");
+        };
+        let call = call.clone().into_encoded();
+        assert_eq!(&call[..2], &[60, 0]);
+        let update = MirrorUpdate::<AccountId>::decode(&mut &call[2..]).unwrap();
+        let MirrorUpdate::Attest(ref attestation) = update else { panic!("expected an attestation") };
+        assert_eq!((attestation.class, attestation.instance, attestation.owner), (class, 0, ALICE));
+        assert_eq!(update.encode().len() + 2, call.len());
+
+        // the owner can't transfer the badge until every mirror is released
+        assert_noop!(
+            Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), class, 0, BOB),
+            Error::<Test>::BadgeMirrored
+        );
+        assert_noop!(
+            Badges::release_mirror(RawOrigin::Signed(ALICE).into(), class, 0, dest(asset_hub())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Badges::release_mirror(RawOrigin::Root.into(), class, 0, dest(unreachable_sibling())),
+            Error::<Test>::MirrorNotFound
+        );
+        assert_ok!(Badges::release_mirror(RawOrigin::Root.into(), class, 0, dest(asset_hub())));
+        assert!(Badges::badge_mirrors(class, 0).is_empty());
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), class, 0, BOB));
+    });
+}
+
+#[test]
+fn mirrors_follow_reassignment_and_removal() {
+    use codec::Decode;
+    use pallet_badges::{Error, Event, MirrorUpdate};
+    use xcm::{latest::prelude::*, VersionedLocation};
+
+    let dest = |l: Location| Box::new(VersionedLocation::from(l));
+    let last_update = || {
+        let sent = sent_xcm();
+        let (to, message) = sent.last().unwrap().clone();
+        let Some(Transact { call, .. }) = message.0.last() else { panic!("mirror message must end with a Transact") };
+        (to, MirrorUpdate::<AccountId>::decode(&mut &call.clone().into_encoded()[2..]).unwrap())
+    };
+
+    new_test_ext().execute_with(|| {
+        let soulbound = create_class(false, true);
+        assert_ok!(issue(soulbound, ALICE));
+        let sibling = Location::new(1, [Parachain(2000)]);
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), soulbound, 0, dest(asset_hub())));
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), soulbound, 0, dest(sibling.clone())));
+
+        // reassignment goes through and re-attests every mirror with the new owner
+        assert_ok!(Badges::reassign_soulbound(RawOrigin::Root.into(), soulbound, 0, BOB, [9u8; 32]));
+        assert_eq!(sent_xcm().len(), 4);
+        let (to, MirrorUpdate::Attest(attestation)) = last_update() else { panic!("expected an attestation") };
+        assert_eq!((to, attestation.owner), (sibling.clone(), BOB));
+
+        // revocation goes through and retracts every mirror
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), soulbound, 0));
+        assert!(Badges::badge_mirrors(soulbound, 0).is_empty());
+        assert_eq!(sent_xcm().len(), 6);
+        assert_eq!(last_update(), (sibling, MirrorUpdate::Retract { class: soulbound, instance: 0 }));
+
+        // so does burning while the destination is unreachable; the retraction waits for a resend
+        assert_ok!(issue(soulbound, ALICE));
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), soulbound, 1, dest(asset_hub())));
+        set_router_down(true);
+        assert_ok!(Badges::burn_badge(RawOrigin::Signed(ALICE).into(), soulbound, 1));
+        System::assert_has_event(
+            Event::<Test>::MirrorUpdateFailed { class: soulbound, instance: 1, dest: (*dest(asset_hub())).clone() }.into(),
+        );
+        assert_noop!(
+            Badges::resend_mirror_update(RawOrigin::Signed(BOB).into(), soulbound, 1, dest(asset_hub())),
+            Error::<Test>::MirrorSendFailed
+        );
+        set_router_down(false);
+        assert_ok!(Badges::resend_mirror_update(RawOrigin::Signed(BOB).into(), soulbound, 1, dest(asset_hub())));
+        assert_eq!(last_update(), (asset_hub(), MirrorUpdate::Retract { class: soulbound, instance: 1 }));
+        assert_noop!(
+            Badges::resend_mirror_update(RawOrigin::Signed(BOB).into(), soulbound, 1, dest(asset_hub())),
+            Error::<Test>::MirrorNotFound
+        );
+    });
+}
+
+#[test]
+fn released_mirrors_drop_pending_updates() {
+    use pallet_badges::Error;
+    use xcm::{latest::prelude::*, VersionedLocation};
+
+    let dest = |l: Location| Box::new(VersionedLocation::from(l));
+
+    new_test_ext().execute_with(|| {
+        let class = create_class(true, false);
+        assert_ok!(issue(class, ALICE));
+        assert_ok!(Badges::mirror_badge(RawOrigin::Signed(ALICE).into(), class, 0, dest(asset_hub())));
+        set_router_down(true);
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(CREATOR).into(), class, 0));
+        set_router_down(false);
+
+        // governance retired the remote copy by other means
+        assert_ok!(Badges::release_mirror(RawOrigin::Root.into(), class, 0, dest(asset_hub())));
+        assert_noop!(
+            Badges::resend_mirror_update(RawOrigin::Signed(BOB).into(), class, 0, dest(asset_hub())),
+            Error::<Test>::MirrorNotFound
+        );
+        assert_noop!(
+            Badges::release_mirror(RawOrigin::Root.into(), class, 0, dest(asset_hub())),
+            Error::<Test>::MirrorNotFound
+        );
+    });
+}
@@ -0,0 +1,69 @@
+//! Badge-gated access for other pallets and runtime configs.
+//!
+//! `BadgeInspect` lets a pallet check credentials without depending on pallet-badges directly
+//! (wire `type Badges = pallet_badges::Pallet<Runtime>`), and `EnsureHasBadge` gates an
+//! extrinsic on the signer holding a live badge of a fixed class, e.g.
+//! `type WorkshopOrigin = EnsureHasBadge<Runtime, GpuAccessClass>;`.
+
+use crate::{ClassId, ClubId, Config, Holdings, InstanceId, Pallet};
+use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_std::marker::PhantomData;
+
+/// Read-only view of badge ownership. Only live (non-revoked, non-burned) instances count.
+pub trait BadgeInspect<AccountId> {
+    /// Number of live instances of `class` held by `who`
+    fn balance_of(who: &AccountId, class: ClassId) -> u32;
+
+    /// Current owner of a live instance
+    fn owner_of(class: ClassId, instance: InstanceId) -> Option<AccountId>;
+
+    /// True if `who` holds at least one live instance of `class`
+    fn has_badge(who: &AccountId, class: ClassId) -> bool {
+        Self::balance_of(who, class) > 0
+    }
+}
+
+impl<T: Config> BadgeInspect<T::AccountId> for Pallet<T> {
+    fn balance_of(who: &T::AccountId, class: ClassId) -> u32 {
+        Holdings::<T>::get(who, class)
+    }
+
+    fn owner_of(class: ClassId, instance: InstanceId) -> Option<T::AccountId> {
+        Pallet::<T>::owner_of(class, instance)
+    }
+}
+
+/// Notified whenever an account gains or loses a badge: issuance, transfer, revocation, burning
+/// or soulbound reassignment (e.g. to keep a reputation score). A move reports the loss first.
+pub trait BadgeHooks<AccountId> {
+    fn on_badge_gained(who: &AccountId, class: ClassId, instance: InstanceId, club: Option<ClubId>);
+    fn on_badge_lost(who: &AccountId, class: ClassId, instance: InstanceId, club: Option<ClubId>);
+}
+
+impl<AccountId> BadgeHooks<AccountId> for () {
+    fn on_badge_gained(_: &AccountId, _: ClassId, _: InstanceId, _: Option<ClubId>) {}
+    fn on_badge_lost(_: &AccountId, _: ClassId, _: InstanceId, _: Option<ClubId>) {}
+}
+
+/// Signed origin whose account holds a live badge of class `Class::get()`.
+/// Succeeds with the signer's account id.
+pub struct EnsureHasBadge<T, Class>(PhantomData<(T, Class)>);
+
+impl<T: Config, Class: Get<ClassId>> EnsureOrigin<T::RuntimeOrigin> for EnsureHasBadge<T, Class> {
+    type Success = T::AccountId;
+
+    fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+        o.into().and_then(|o| match o {
+            RawOrigin::Signed(who) if Pallet::<T>::has_badge(&who, Class::get()) => Ok(who),
+            r => Err(T::RuntimeOrigin::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+        let who: T::AccountId = frame_benchmarking::account("badge_holder", 0, 0);
+        Holdings::<T>::insert(&who, Class::get(), 1);
+        Ok(RawOrigin::Signed(who).into())
+    }
+}
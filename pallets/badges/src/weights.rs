@@ -0,0 +1,325 @@
+//! Weights for pallet-badges.
+//!
+//! `issue_badge` and `issue_badges_batch` are measured from the benchmarks in `benchmarking.rs`
+//! (50 steps, 20 repeats, native release build, measured proof size on the mock genesis state):
+//!
+//! `cargo test -p pallet-badges --release --features runtime-benchmarks -- --ignored --nocapture measure_weights`
+//!
+//! PLACEHOLDER: every other call is a hand-written estimate until it gets a benchmark. Regenerate
+//! the whole file with the `benchmark pallet` subcommand on reference hardware before production.
+//!
+//! `issue_badges_batch` scales linearly in the number of recipients `n`.
+
+use core::marker::PhantomData;
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+
+use crate::WeightInfo;
+
+/// Weights for pallet-badges using the runtime's database weights
+pub struct SubstrateWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn create_class() -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    fn issue_badge() -> Weight {
+        Weight::from_parts(156_357_000, 383)
+            .saturating_add(T::DbWeight::get().reads(15_u64))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+    }
+    fn revoke_badge() -> Weight {
+        Weight::from_parts(40_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    fn transfer_badge() -> Weight {
+        Weight::from_parts(30_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn set_prerequisites() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    fn issue_badges_batch(n: u32) -> Weight {
+        Weight::from_parts(162_721_000, 383)
+            .saturating_add(Weight::from_parts(103_894_359, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64.saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64.saturating_mul(n.into())))
+    }
+    fn open_claim() -> Weight {
+        Weight::from_parts(25_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn claim_badge() -> Weight {
+        Weight::from_parts(60_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(11_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    fn close_claim() -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn set_class_metadata() -> Weight {
+        Weight::from_parts(25_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn freeze_class_metadata() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_badge_uri() -> Weight {
+        Weight::from_parts(25_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn freeze_badge_uri() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn endorse_badge() -> Weight {
+        Weight::from_parts(30_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn revoke_endorsement() -> Weight {
+        Weight::from_parts(25_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn reassign_soulbound() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    fn execute_reassignment() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    fn cancel_reassignment() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_requires_acceptance() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn accept_offer() -> Weight {
+        Weight::from_parts(50_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    fn decline_offer() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn clear_expired_offer() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn burn_badge() -> Weight {
+        Weight::from_parts(40_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(11_u64))
+    }
+    fn set_badge_visibility() -> Weight {
+        Weight::from_parts(15_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn set_issuance_limits() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn commit_attributes() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn mirror_badge() -> Weight {
+        Weight::from_parts(45_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    fn release_mirror() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    fn resend_mirror_update() -> Weight {
+        Weight::from_parts(45_000_000, 3_600)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For tests and backwards compatibility
+impl WeightInfo for () {
+    fn create_class() -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn issue_badge() -> Weight {
+        Weight::from_parts(156_357_000, 383)
+            .saturating_add(RocksDbWeight::get().reads(15_u64))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+    }
+    fn revoke_badge() -> Weight {
+        Weight::from_parts(40_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn transfer_badge() -> Weight {
+        Weight::from_parts(30_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn set_prerequisites() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    fn issue_badges_batch(n: u32) -> Weight {
+        Weight::from_parts(162_721_000, 383)
+            .saturating_add(Weight::from_parts(103_894_359, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64.saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64.saturating_mul(n.into())))
+    }
+    fn open_claim() -> Weight {
+        Weight::from_parts(25_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn claim_badge() -> Weight {
+        Weight::from_parts(60_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(11_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    fn close_claim() -> Weight {
+        Weight::from_parts(30_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_class_metadata() -> Weight {
+        Weight::from_parts(25_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn freeze_class_metadata() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_badge_uri() -> Weight {
+        Weight::from_parts(25_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn freeze_badge_uri() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn endorse_badge() -> Weight {
+        Weight::from_parts(30_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn revoke_endorsement() -> Weight {
+        Weight::from_parts(25_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn reassign_soulbound() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn execute_reassignment() -> Weight {
+        Weight::from_parts(35_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn cancel_reassignment() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_requires_acceptance() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn accept_offer() -> Weight {
+        Weight::from_parts(50_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    fn decline_offer() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn clear_expired_offer() -> Weight {
+        Weight::from_parts(15_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn burn_badge() -> Weight {
+        Weight::from_parts(40_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    fn set_badge_visibility() -> Weight {
+        Weight::from_parts(15_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn set_issuance_limits() -> Weight {
+        Weight::from_parts(20_000_000, 3_500)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn commit_attributes() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn mirror_badge() -> Weight {
+        Weight::from_parts(45_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn release_mirror() -> Weight {
+        Weight::from_parts(20_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn resend_mirror_update() -> Weight {
+        Weight::from_parts(45_000_000, 3_600)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
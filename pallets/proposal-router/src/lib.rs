@@ -8,6 +8,11 @@
 //!     - pallet_member_registry::Pallet::<T>::is_member(who: &T::AccountId) -> bool
 //!     - pallet_member_registry::Pallet::<T>::is_officer_or_admin(who: &T::AccountId, club: ClubId) -> Result<bool, _>
 //!
+//! - Vote weights come from `Config::VoteWeight` (`()` = one member, one vote; pallet-reputation
+//!   weighs votes by reputation instead of token holdings), snapshotted at the block the proposal
+//!   was created in: weight gained, lost or reassigned while voting is open does not count. Proposing and voting are reported to
+//!   `Config::GovernanceHooks`.
+//!
 //! Notes:
 //! - This pallet decodes the stored call bytes into `T::RuntimeCall` before dispatching.
 //!   `T::RuntimeCall` must implement `Dispatchable<RuntimeOrigin = T::RuntimeOrigin>` and `Decode`.
@@ -18,7 +23,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod mock;
 
+mod tests;
 
 use frame_support::{
     pallet_prelude::*,
@@ -66,7 +73,7 @@ pub mod pallet {
         pub executed: bool,
         /// voters list (to prevent double-vote). size bounded for storage limits.
         pub voters: BoundedVec<T::AccountId, T::MaxVotersPerProposal>,
-        /// quorum in absolute votes required to consider the vote valid
+        /// quorum in absolute (weighted, see `Config::VoteWeight`) votes required to consider the vote valid
         pub quorum: Votes,
         /// passing threshold (simple majority threshold expressed as percent*100, e.g., 5000 = 50.00%)
         pub pass_threshold: u32,
@@ -74,6 +81,32 @@ pub mod pallet {
         pub club: Option<ClubId>,
     }
 
+    /// Source of vote weights. `club` is None for global proposals.
+    pub trait VoteWeight<AccountId, BlockNumber> {
+        /// Weight of `who` as it stood when block `at` began, i.e. ignoring anything gained or
+        /// lost in or after that block. Votes are weighed at the block their proposal was created
+        /// in, so weight acquired (or moved to another account) while voting is open never counts.
+        fn vote_weight(who: &AccountId, club: Option<ClubId>, at: BlockNumber) -> Votes;
+    }
+
+    /// One member, one vote
+    impl<AccountId, BlockNumber> VoteWeight<AccountId, BlockNumber> for () {
+        fn vote_weight(_: &AccountId, _: Option<ClubId>, _: BlockNumber) -> Votes {
+            1
+        }
+    }
+
+    /// Notified of governance participation. `club` is None for global proposals.
+    pub trait GovernanceHooks<AccountId> {
+        fn on_proposed(who: &AccountId, club: Option<ClubId>);
+        fn on_voted(who: &AccountId, club: Option<ClubId>);
+    }
+
+    impl<AccountId> GovernanceHooks<AccountId> for () {
+        fn on_proposed(_: &AccountId, _: Option<ClubId>) {}
+        fn on_voted(_: &AccountId, _: Option<ClubId>) {}
+    }
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
@@ -105,6 +138,13 @@ pub mod pallet {
         /// Helper: time provider (optional; used only for metadata timestamps if needed)
         type TimeProvider: UnixTime;
 
+        /// Weight of each vote, taken at the proposal's `start`; quorum and tallies are counted in
+        /// this weight
+        type VoteWeight: VoteWeight<Self::AccountId, BlockNumberOf<Self>>;
+
+        /// Participation hooks (use `()` or e.g. pallet-reputation)
+        type GovernanceHooks: GovernanceHooks<Self::AccountId>;
+
         /// WeightInfo for extrinsics (populate by benchmarking)
         type WeightInfo: WeightInfo;
     }
@@ -256,6 +296,8 @@ pub mod pallet {
             }
 
             NextProposalId::<T>::put(id.saturating_add(1));
+            let club = match scope { Scope::Club(cid) => Some(cid), _ => None };
+            T::GovernanceHooks::on_proposed(&who, club);
             let is_club_scoped = matches!(scope, Scope::Club(_));
             Self::deposit_event(Event::ProposalCreated { id, proposer: who, is_club_scoped });
             Ok(())
@@ -293,14 +335,16 @@ pub mod pallet {
                     }
                 }
 
-                // record vote
+                // record vote, weighed as of the proposal's creation
+                let weight = T::VoteWeight::vote_weight(&who, p.club, p.start);
                 if aye {
-                    p.yea = p.yea.saturating_add(1);
+                    p.yea = p.yea.saturating_add(weight);
                 } else {
-                    p.nay = p.nay.saturating_add(1);
+                    p.nay = p.nay.saturating_add(weight);
                 }
                 p.voters.try_push(who.clone()).map_err(|_| Error::<T>::VotersOverflow)?;
-                Self::deposit_event(Event::Voted { id: proposal_id, who, aye, weight: 1u32 });
+                T::GovernanceHooks::on_voted(&who, p.club);
+                Self::deposit_event(Event::Voted { id: proposal_id, who, aye, weight });
                 Ok(())
             })
         }
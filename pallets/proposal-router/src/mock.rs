@@ -0,0 +1,147 @@
+//! Mock runtime for pallet-proposal-router tests.
+
+#![cfg(test)]
+
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use frame_support::{
+    parameter_types,
+    construct_runtime,
+    traits::{ConstU32, ConstU64, Everything},
+    weights::Weight,
+};
+use crate::{self as pallet_proposal_router, ClubId, VoteWeight, Votes};
+use std::cell::RefCell;
+
+// --- Type aliases used in the mock runtime ---
+pub type AccountId = u64;
+pub type Nonce = u64;
+pub struct DummyWeight;
+
+// --- Parameter types ---
+parameter_types! {
+    pub const BlockHashCount: u32 = 2400;
+    pub const SS58Prefix: u8 = 42;
+    pub const MinimumPeriod: u64 = 1;
+}
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        ProposalRouter: pallet_proposal_router,
+    }
+);
+
+impl frame_system::Config for Test {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = Nonce;
+    type Block = frame_system::mocking::MockBlock<Test>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type BlockHashCount = BlockHashCount;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+impl pallet_proposal_router::WeightInfo for DummyWeight {
+    fn propose() -> Weight { Weight::from_parts(0, 0) }
+    fn vote() -> Weight { Weight::from_parts(0, 0) }
+    fn execute() -> Weight { Weight::from_parts(0, 0) }
+    fn cancel() -> Weight { Weight::from_parts(0, 0) }
+}
+
+thread_local! {
+    pub static PARTICIPATION: RefCell<Vec<(AccountId, Option<ClubId>, bool)>> = const { RefCell::new(Vec::new()) };
+    pub static WEIGHED_AT: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Vote weight = the account id (so weights are easy to read in tests); club 9 weighs everyone 1.
+/// Records the block every weight is asked at.
+pub struct AccountIdWeight;
+impl VoteWeight<AccountId, u64> for AccountIdWeight {
+    fn vote_weight(who: &AccountId, club: Option<ClubId>, at: u64) -> Votes {
+        WEIGHED_AT.with(|w| w.borrow_mut().push(at));
+        if club == Some(9) { 1 } else { *who as Votes }
+    }
+}
+
+/// Records `(who, club, proposed)` per participation
+pub struct RecordParticipation;
+impl pallet_proposal_router::GovernanceHooks<AccountId> for RecordParticipation {
+    fn on_proposed(who: &AccountId, club: Option<ClubId>) {
+        PARTICIPATION.with(|p| p.borrow_mut().push((*who, club, true)));
+    }
+    fn on_voted(who: &AccountId, club: Option<ClubId>) {
+        PARTICIPATION.with(|p| p.borrow_mut().push((*who, club, false)));
+    }
+}
+
+/// Participation reported so far
+pub fn participation() -> Vec<(AccountId, Option<ClubId>, bool)> {
+    PARTICIPATION.with(|p| p.borrow().clone())
+}
+
+/// Blocks vote weights were asked at so far
+pub fn weighed_at() -> Vec<u64> {
+    WEIGHED_AT.with(|w| w.borrow().clone())
+}
+
+impl pallet_proposal_router::Config for Test {
+    type RuntimeCall = RuntimeCall;
+    type MaxMetadataLen = ConstU32<64>;
+    type MaxVotersPerProposal = ConstU32<16>;
+    type DefaultVotingPeriod = ConstU64<10>;
+    type DefaultQuorum = ConstU32<10>;
+    type DefaultPassThreshold = ConstU32<5000>;
+    type RouterAdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type TimeProvider = pallet_timestamp::Pallet<Self>;
+    type VoteWeight = AccountIdWeight;
+    type GovernanceHooks = RecordParticipation;
+    type WeightInfo = DummyWeight;
+}
+
+// --- TestExternalities builder ---
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .expect("frame_system storage");
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,93 @@
+//! Unit tests for pallet-proposal-router using the per-pallet mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event, ProposalId, Scope};
+use parity_scale_codec::Encode;
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+
+const CLUB: u32 = 7;
+
+/// Propose a `remark_with_event` with the mock defaults (quorum 10, 50% threshold, 10 blocks)
+fn propose(who: AccountId, scope: Scope) -> ProposalId {
+    let id = ProposalRouter::next_proposal_id();
+    let call = RuntimeCall::System(frame_system::Call::remark_with_event { remark: b"tcc".to_vec() });
+    assert_ok!(ProposalRouter::propose(RawOrigin::Signed(who).into(), scope, call.encode(), None, None, None, None));
+    id
+}
+
+fn vote(who: AccountId, id: ProposalId, aye: bool) {
+    assert_ok!(ProposalRouter::vote(RawOrigin::Signed(who).into(), id, aye));
+}
+
+#[test]
+fn tally_and_quorum_are_counted_in_vote_weight() {
+    new_test_ext().execute_with(|| {
+        let id = propose(1, Scope::Club(CLUB));
+        // weights: 2 + 3 = 5 aye, 4 nay -> 9 < quorum 10
+        vote(2, id, true);
+        vote(3, id, true);
+        vote(4, id, false);
+        System::assert_has_event(Event::<Test>::Voted { id, who: 4, aye: false, weight: 4 }.into());
+        let p = ProposalRouter::proposals(id).unwrap();
+        assert_eq!((p.yea, p.nay), (5, 4));
+
+        System::set_block_number(12);
+        assert_noop!(ProposalRouter::execute(RawOrigin::Signed(1).into(), id), Error::<Test>::QuorumNotReached);
+    });
+}
+
+#[test]
+fn heavier_minority_of_voters_decides_the_outcome() {
+    new_test_ext().execute_with(|| {
+        // three light nays (1 + 2 + 3 = 6) against one heavy aye (8): passes with 8/14
+        let passes = propose(1, Scope::Global);
+        for who in [1, 2, 3] {
+            vote(who, passes, false);
+        }
+        vote(8, passes, true);
+
+        // the reverse fails: 6 aye against 8 nay
+        let fails = propose(1, Scope::Global);
+        for who in [1, 2, 3] {
+            vote(who, fails, true);
+        }
+        vote(8, fails, false);
+
+        System::set_block_number(12);
+        assert_ok!(ProposalRouter::execute(RawOrigin::Signed(1).into(), passes));
+        System::assert_has_event(Event::<Test>::ProposalExecuted { id: passes }.into());
+        assert_noop!(ProposalRouter::execute(RawOrigin::Signed(1).into(), fails), Error::<Test>::ProposalNotPassed);
+    });
+}
+
+#[test]
+fn votes_are_weighed_at_the_proposal_start() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        let id = propose(1, Scope::Club(CLUB));
+        System::set_block_number(8);
+        vote(2, id, true);
+        System::set_block_number(13);
+        vote(3, id, false);
+        assert_eq!(weighed_at(), vec![3, 3]);
+    });
+}
+
+#[test]
+fn club_is_passed_to_vote_weight_and_hooks() {
+    new_test_ext().execute_with(|| {
+        // club 9 weighs every voter 1
+        let id = propose(5, Scope::Club(9));
+        vote(6, id, true);
+        assert_eq!(ProposalRouter::proposals(id).unwrap().yea, 1);
+        assert_noop!(ProposalRouter::vote(RawOrigin::Signed(6).into(), id, true), Error::<Test>::AlreadyVoted);
+
+        let global = propose(5, Scope::Global);
+        vote(6, global, false);
+        assert_eq!(ProposalRouter::proposals(global).unwrap().nay, 6);
+
+        assert_eq!(participation(), vec![(5, Some(9), true), (6, Some(9), false), (5, None, true), (6, None, false)]);
+    });
+}
@@ -0,0 +1,320 @@
+//! pallet-reputation: per-account, per-club reputation scores
+//!
+//! Features:
+//! - Scores are kept per account and per club (`None` = global, non club-scoped activity).
+//! - Gaining a badge (issuance, transfer, soulbound reassignment) adds the per-class weight set by
+//!   `WeightOrigin`; losing it (revocation, burn, transfer, reassignment) removes what is left of
+//!   the points granted for it, decayed like the score, whatever the class weight is by then.
+//! - Reward claims and governance participation (proposing, voting) add fixed points. Governance
+//!   points are capped per account, club and epoch, since proposing and voting cost nothing but a
+//!   fee and reputation is vote weight.
+//! - Optional decay: every `EpochLength` blocks scores lose `DecayPerEpoch` (applied lazily).
+//! - Vote weight source for pallet-proposal-router, separating governance power from $TCC holdings.
+//!   Every score change is checkpointed per block (the last `MaxCheckpoints` per account and club)
+//!   so votes are weighed by the reputation held when the proposal was created: points earned,
+//!   lost or moved with a badge while voting is open change nothing. A proposal older than an
+//!   account's retained checkpoints sees no reputation for it.
+//!
+//! Integration notes:
+//! - Wire this pallet as `pallet_badges::Config::BadgeHooks`, `pallet_rewards::Config::OnRewardClaimed`,
+//!   `pallet_proposal_router::Config::GovernanceHooks` and (optionally) `VoteWeight`.
+//! - Replace WeightInfo placeholders with benchmarked weights before production.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mock;
+
+mod tests;
+
+use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
+use frame_system::pallet_prelude::*;
+use pallet_badges::{BadgeHooks, ClassId, InstanceId};
+use pallet_proposal_router::{GovernanceHooks, VoteWeight, Votes};
+use pallet_rewards::{OnRewardClaimed, RuleId};
+use sp_runtime::{
+    traits::{SaturatedConversion, Saturating},
+    PerThing, Permill,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    pub type ClubId = u32;
+    pub type Score = u64;
+    pub type Epoch = u64;
+
+    /// A score together with the epoch up to which decay has been applied to it
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+    pub struct ScoreRecord {
+        pub score: Score,
+        pub epoch: Epoch,
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin that sets badge class weights (e.g. governance or Root)
+        type WeightOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Points per successful reward claim
+        #[pallet::constant]
+        type RewardClaimPoints: Get<Score>;
+
+        /// Points per proposal submitted
+        #[pallet::constant]
+        type ProposalPoints: Get<Score>;
+
+        /// Points per vote cast
+        #[pallet::constant]
+        type VotePoints: Get<Score>;
+
+        /// Max proposal and vote points an account earns per club (or globally) per epoch. With
+        /// `EpochLength` 0 this is a lifetime cap.
+        #[pallet::constant]
+        type MaxGovernancePointsPerEpoch: Get<Score>;
+
+        /// Blocks per decay epoch (0 disables decay)
+        #[pallet::constant]
+        type EpochLength: Get<BlockNumberFor<Self>>;
+
+        /// Share of a score lost every epoch (zero disables decay)
+        #[pallet::constant]
+        type DecayPerEpoch: Get<Permill>;
+
+        /// Score checkpoints kept per account and club for weighing votes at past blocks
+        #[pallet::constant]
+        type MaxCheckpoints: Get<u32>;
+
+        /// WeightInfo for extrinsics (replace with benchmarking)
+        type WeightInfo: WeightInfo;
+    }
+
+    // Weight stubs - replace with generated weights later
+    pub trait WeightInfo {
+        fn set_class_weight() -> Weight;
+    }
+
+    /// (who, club) -> score; club None holds global (non club-scoped) reputation
+    #[pallet::storage]
+    pub(super) type Scores<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        Option<ClubId>,
+        ScoreRecord,
+        ValueQuery,
+    >;
+
+    /// Points granted for holding a badge of a class (absent => 0)
+    #[pallet::storage]
+    #[pallet::getter(fn class_weight)]
+    pub(super) type ClassWeights<T: Config> = StorageMap<_, Twox64Concat, ClassId, Score, ValueQuery>;
+
+    /// Points granted to a holder for a badge instance and the epoch they were granted in; what
+    /// is left of them after decay is removed when the holder loses the badge
+    #[pallet::storage]
+    #[pallet::getter(fn granted_points)]
+    pub(super) type GrantedPoints<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        (ClassId, InstanceId),
+        ScoreRecord,
+        OptionQuery,
+    >;
+
+    /// (who, club) -> the score as it was at the end of each block it changed in, oldest first
+    #[pallet::storage]
+    pub(super) type Checkpoints<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        Option<ClubId>,
+        BoundedVec<(BlockNumberFor<T>, ScoreRecord), T::MaxCheckpoints>,
+        ValueQuery,
+    >;
+
+    /// (who, club) -> (epoch, governance points earned in that epoch)
+    #[pallet::storage]
+    pub(super) type GovernancePoints<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, Option<ClubId>, (Epoch, Score), ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ClassWeightSet { class: ClassId, points: Score },
+        ScoreChanged { who: T::AccountId, club: Option<ClubId>, score: Score },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the points earned by holding a badge of `class`. Applies to future issuances only.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_class_weight())]
+        pub fn set_class_weight(origin: OriginFor<T>, class: ClassId, points: Score) -> DispatchResult {
+            T::WeightOrigin::ensure_origin(origin)?;
+            if points == 0 {
+                ClassWeights::<T>::remove(class);
+            } else {
+                ClassWeights::<T>::insert(class, points);
+            }
+            Self::deposit_event(Event::ClassWeightSet { class, points });
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Compute current epoch based on block number and EpochLength
+        pub fn current_epoch() -> Epoch {
+            Self::epoch_at(frame_system::Pallet::<T>::block_number())
+        }
+
+        fn epoch_at(block: BlockNumberFor<T>) -> Epoch {
+            let bn: u64 = block.saturated_into();
+            let len: u64 = T::EpochLength::get().saturated_into();
+            bn.checked_div(len).unwrap_or(0)
+        }
+
+        /// Current (decayed) score of `who` in `club`
+        pub fn score_of(who: &T::AccountId, club: Option<ClubId>) -> Score {
+            Self::decayed(&Scores::<T>::get(who, club), Self::current_epoch())
+        }
+
+        /// Current (decayed) score of `who` summed over all clubs and global activity
+        pub fn total_score(who: &T::AccountId) -> Score {
+            let epoch = Self::current_epoch();
+            Scores::<T>::iter_prefix_values(who).fold(0, |acc: Score, r| acc.saturating_add(Self::decayed(&r, epoch)))
+        }
+
+        /// (Decayed) score of `who` in `club` when block `at` began
+        pub fn score_at(who: &T::AccountId, club: Option<ClubId>, at: BlockNumberFor<T>) -> Score {
+            Self::checkpointed(&Checkpoints::<T>::get(who, club), at)
+        }
+
+        /// (Decayed) score of `who` summed over all clubs and global activity when block `at` began
+        pub fn total_score_at(who: &T::AccountId, at: BlockNumberFor<T>) -> Score {
+            Checkpoints::<T>::iter_prefix_values(who)
+                .fold(0, |acc: Score, checkpoints| acc.saturating_add(Self::checkpointed(&checkpoints, at)))
+        }
+
+        fn checkpointed(checkpoints: &[(BlockNumberFor<T>, ScoreRecord)], at: BlockNumberFor<T>) -> Score {
+            checkpoints
+                .iter()
+                .rev()
+                .find(|(block, _)| *block < at)
+                .map_or(0, |(_, record)| Self::decayed(record, Self::epoch_at(at)))
+        }
+
+        fn decayed(record: &ScoreRecord, epoch: Epoch) -> Score {
+            let elapsed = epoch.saturating_sub(record.epoch);
+            if elapsed == 0 || record.score == 0 {
+                return record.score;
+            }
+            let retained = T::DecayPerEpoch::get().left_from_one().saturating_pow(elapsed.saturated_into());
+            retained.mul_floor(record.score)
+        }
+
+        /// Apply pending decay, then `f`, to the score of `who` in `club`
+        fn update_score(who: &T::AccountId, club: Option<ClubId>, f: impl FnOnce(Score) -> Score) {
+            let epoch = Self::current_epoch();
+            let record = Scores::<T>::mutate(who, club, |record| {
+                record.score = f(Self::decayed(record, epoch));
+                record.epoch = epoch;
+                record.clone()
+            });
+            let now = frame_system::Pallet::<T>::block_number();
+            Checkpoints::<T>::mutate(who, club, |checkpoints| match checkpoints.last_mut() {
+                Some((block, last)) if *block == now => *last = record.clone(),
+                _ => {
+                    if checkpoints.is_full() {
+                        checkpoints.remove(0);
+                    }
+                    let _ = checkpoints.try_push((now, record.clone()));
+                }
+            });
+            let score = record.score;
+            Self::deposit_event(Event::ScoreChanged { who: who.clone(), club, score });
+        }
+
+        fn add_points(who: &T::AccountId, club: Option<ClubId>, points: Score) {
+            if points > 0 {
+                Self::update_score(who, club, |s| s.saturating_add(points));
+            }
+        }
+
+        /// Add governance `points`, clipped to what is left of `MaxGovernancePointsPerEpoch`
+        fn add_governance_points(who: &T::AccountId, club: Option<ClubId>, points: Score) {
+            let epoch = Self::current_epoch();
+            let granted = GovernancePoints::<T>::mutate(who, club, |(last, earned)| {
+                if *last != epoch {
+                    *last = epoch;
+                    *earned = 0;
+                }
+                let granted = points.min(T::MaxGovernancePointsPerEpoch::get().saturating_sub(*earned));
+                *earned = earned.saturating_add(granted);
+                granted
+            });
+            Self::add_points(who, club, granted);
+        }
+    }
+
+    impl<T: Config> BadgeHooks<T::AccountId> for Pallet<T> {
+        fn on_badge_gained(who: &T::AccountId, class: ClassId, instance: InstanceId, club: Option<ClubId>) {
+            let points = ClassWeights::<T>::get(class);
+            if points > 0 {
+                let grant = ScoreRecord { score: points, epoch: Self::current_epoch() };
+                GrantedPoints::<T>::insert(who, (class, instance), grant);
+                Self::add_points(who, club, points);
+            }
+        }
+
+        fn on_badge_lost(who: &T::AccountId, class: ClassId, instance: InstanceId, club: Option<ClubId>) {
+            if let Some(grant) = GrantedPoints::<T>::take(who, (class, instance)) {
+                let points = Self::decayed(&grant, Self::current_epoch());
+                Self::update_score(who, club, |s| s.saturating_sub(points));
+            }
+        }
+    }
+
+    impl<T: Config> OnRewardClaimed<T::AccountId> for Pallet<T> {
+        fn on_reward_claimed(who: &T::AccountId, _rule_id: RuleId, club: Option<ClubId>) {
+            Self::add_points(who, club, T::RewardClaimPoints::get());
+        }
+    }
+
+    impl<T: Config> GovernanceHooks<T::AccountId> for Pallet<T> {
+        fn on_proposed(who: &T::AccountId, club: Option<ClubId>) {
+            Self::add_governance_points(who, club, T::ProposalPoints::get());
+        }
+
+        fn on_voted(who: &T::AccountId, club: Option<ClubId>) {
+            Self::add_governance_points(who, club, T::VotePoints::get());
+        }
+    }
+
+    /// Club proposals are weighted by club reputation, global ones by total reputation, both as
+    /// checkpointed when block `at` began. Every eligible voter weighs at least 1 so members
+    /// without reputation can still vote.
+    impl<T: Config> VoteWeight<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn vote_weight(who: &T::AccountId, club: Option<ClubId>, at: BlockNumberFor<T>) -> Votes {
+            let score = match club {
+                Some(_) => Self::score_at(who, club, at),
+                None => Self::total_score_at(who, at),
+            };
+            score.saturated_into::<Votes>().max(1)
+        }
+    }
+}
+
+pub use pallet::*;
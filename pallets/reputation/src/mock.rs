@@ -0,0 +1,137 @@
+//! Mock runtime for pallet-reputation tests.
+
+#![cfg(test)]
+
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Permill,
+};
+use frame_support::{
+    parameter_types,
+    construct_runtime,
+    traits::{ConstU32, ConstU64, Everything, Nothing},
+    weights::Weight,
+};
+use crate as pallet_reputation;
+
+// --- Type aliases used in the mock runtime ---
+pub type AccountId = u64;
+pub type Nonce = u64;
+pub struct DummyWeight;
+
+// --- Parameter types ---
+parameter_types! {
+    pub const BlockHashCount: u32 = 2400;
+    pub const SS58Prefix: u8 = 42;
+    /// Scores lose 10% per epoch
+    pub const DecayPerEpoch: Permill = Permill::from_percent(10);
+    pub const MinimumPeriod: u64 = 1;
+}
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Badges: pallet_badges,
+        Reputation: pallet_reputation,
+    }
+);
+
+impl frame_system::Config for Test {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = Nonce;
+    type Block = frame_system::mocking::MockBlock<Test>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type BlockHashCount = BlockHashCount;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+// Badges report holder changes to Reputation; no NFT backend or XCM mirrors.
+impl pallet_badges::Config for Test {
+    type ClassCreationOrigin = frame_system::EnsureSigned<AccountId>;
+    type TimeProvider = Timestamp;
+    type MaxClasses = ConstU32<100>;
+    type MaxInstancesPerClass = ConstU32<100>;
+    type MaxMetadataLen = ConstU32<64>;
+    type MaxClassesPerAccount = ConstU32<10>;
+    type MaxPrerequisites = ConstU32<4>;
+    type MaxAutoAwardDependents = ConstU32<4>;
+    type MaxBatchIssuance = ConstU32<8>;
+    type MaxClaimProofLength = ConstU32<16>;
+    type MaxMetadataRevisions = ConstU32<2>;
+    type Endorsers = Nothing;
+    type MaxEndorsementsPerInstance = ConstU32<2>;
+    type ReassignOrigin = frame_system::EnsureRoot<AccountId>;
+    type ReassignmentDelay = ConstU64<10>;
+    type OfferExpiry = ConstU64<5>;
+    type IssuanceEpochLength = ConstU64<10>;
+    type BadgeHooks = Reputation;
+    type XcmSender = ();
+    type MirrorDestinations = Nothing;
+    type MirrorMessage = ();
+    type MirrorReleaseOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxMirrorsPerBadge = ConstU32<2>;
+    type NftBackend = ();
+    type WeightInfo = ();
+}
+
+impl pallet_reputation::WeightInfo for DummyWeight {
+    fn set_class_weight() -> Weight { Weight::from_parts(0, 0) }
+}
+
+impl pallet_reputation::Config for Test {
+    type WeightOrigin = frame_system::EnsureRoot<AccountId>;
+    type RewardClaimPoints = ConstU64<5>;
+    type ProposalPoints = ConstU64<3>;
+    type VotePoints = ConstU64<1>;
+    type MaxGovernancePointsPerEpoch = ConstU64<10>;
+    type EpochLength = ConstU64<10>;
+    type DecayPerEpoch = DecayPerEpoch;
+    type MaxCheckpoints = ConstU32<3>;
+    type WeightInfo = DummyWeight;
+}
+
+// --- TestExternalities builder ---
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .expect("frame_system storage");
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
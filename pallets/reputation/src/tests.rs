@@ -0,0 +1,212 @@
+//! Unit tests for pallet-reputation using the per-pallet mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{mock::*, ScoreRecord};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use pallet_badges::BadgeHooks;
+use pallet_proposal_router::{GovernanceHooks, VoteWeight};
+use pallet_rewards::OnRewardClaimed;
+use sp_runtime::DispatchError;
+
+const ALICE: AccountId = 2;
+const BOB: AccountId = 3;
+const ISSUER: AccountId = 1;
+const CLUB: u32 = 7;
+
+#[test]
+fn class_weight_is_root_only_and_drives_badge_points() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Reputation::set_class_weight(RawOrigin::Signed(ALICE).into(), 1, 20), DispatchError::BadOrigin);
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 20));
+        assert_eq!(Reputation::class_weight(1), 20);
+
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 1, 0, Some(CLUB));
+        // unweighted classes earn nothing
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 2, 0, Some(CLUB));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 20);
+        assert_eq!(Reputation::score_of(&ALICE, None), 0);
+
+        <Reputation as BadgeHooks<_>>::on_badge_lost(&ALICE, 1, 0, Some(CLUB));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 0);
+    });
+}
+
+#[test]
+fn participation_adds_points_per_club() {
+    new_test_ext().execute_with(|| {
+        <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, Some(CLUB));
+        <Reputation as GovernanceHooks<_>>::on_proposed(&ALICE, None);
+        <Reputation as GovernanceHooks<_>>::on_voted(&ALICE, None);
+
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 5);
+        assert_eq!(Reputation::score_of(&ALICE, None), 4);
+        assert_eq!(Reputation::total_score(&ALICE), 9);
+    });
+}
+
+#[test]
+fn losing_a_badge_removes_the_points_granted_for_it() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 20));
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 1, 0, Some(CLUB));
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 50));
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 1, 1, Some(CLUB));
+        assert_eq!(Reputation::granted_points(ALICE, (1, 0)).map(|grant| grant.score), Some(20));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 70);
+
+        // reweighting (or unweighting) the class does not change what losing a badge takes back
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 0));
+        <Reputation as BadgeHooks<_>>::on_badge_lost(&ALICE, 1, 0, Some(CLUB));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 50);
+        assert_eq!(Reputation::granted_points(ALICE, (1, 0)), None);
+
+        // a badge that earned nothing (e.g. gained while its class was unweighted) takes nothing
+        <Reputation as BadgeHooks<_>>::on_badge_lost(&ALICE, 1, 0, Some(CLUB));
+        <Reputation as BadgeHooks<_>>::on_badge_lost(&ALICE, 1, 7, Some(CLUB));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 50);
+
+        <Reputation as BadgeHooks<_>>::on_badge_lost(&ALICE, 1, 1, Some(CLUB));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 0);
+    });
+}
+
+#[test]
+fn losing_a_badge_removes_its_decayed_points_only() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 1000));
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 1, 0, None);
+        <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, None);
+        assert_eq!(Reputation::granted_points(ALICE, (1, 0)), Some(ScoreRecord { score: 1000, epoch: 0 }));
+
+        // 1005 decayed by 10% -> 904, of which 900 came from the badge
+        System::set_block_number(10);
+        assert_eq!(Reputation::score_of(&ALICE, None), 904);
+        <Reputation as BadgeHooks<_>>::on_badge_lost(&ALICE, 1, 0, None);
+        assert_eq!(Reputation::score_of(&ALICE, None), 4);
+    });
+}
+
+#[test]
+fn badge_points_follow_transfers() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Badges::create_class(RawOrigin::Signed(ISSUER).into(), [1u8; 32], None, true, false));
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 0, 20));
+        assert_ok!(Badges::issue_badge(RawOrigin::Signed(ISSUER).into(), 0, ALICE, [2u8; 32], None, None));
+        assert_eq!(Reputation::score_of(&ALICE, None), 20);
+
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), 0, 0, BOB));
+        assert_eq!(Reputation::score_of(&ALICE, None), 0);
+        assert_eq!(Reputation::score_of(&BOB, None), 20);
+
+        // revoking takes the points back from the current holder
+        assert_ok!(Badges::revoke_badge(RawOrigin::Signed(ISSUER).into(), 0, 0));
+        assert_eq!(Reputation::score_of(&BOB, None), 0);
+        assert_eq!(Reputation::score_of(&ALICE, None), 0);
+        assert_eq!(Reputation::granted_points(BOB, (0, 0)), None);
+    });
+}
+
+#[test]
+fn governance_points_are_capped_per_epoch() {
+    new_test_ext().execute_with(|| {
+        // 3 per proposal, 1 per vote, at most 10 per club and epoch
+        for _ in 0..3 {
+            <Reputation as GovernanceHooks<_>>::on_proposed(&ALICE, Some(CLUB));
+        }
+        for _ in 0..5 {
+            <Reputation as GovernanceHooks<_>>::on_voted(&ALICE, Some(CLUB));
+        }
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 10);
+
+        // the cap is per club: global activity is counted separately
+        <Reputation as GovernanceHooks<_>>::on_proposed(&ALICE, None);
+        assert_eq!(Reputation::score_of(&ALICE, None), 3);
+
+        // other points are not capped, and the cap resets next epoch
+        <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, Some(CLUB));
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 15);
+        System::set_block_number(10);
+        <Reputation as GovernanceHooks<_>>::on_proposed(&ALICE, Some(CLUB));
+        // 15 decayed by 10% -> 13, plus 3
+        assert_eq!(Reputation::score_of(&ALICE, Some(CLUB)), 16);
+    });
+}
+
+#[test]
+fn scores_decay_per_epoch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 1000));
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 1, 0, None);
+
+        System::set_block_number(10);
+        assert_eq!(Reputation::score_of(&ALICE, None), 900);
+        System::set_block_number(20);
+        assert_eq!(Reputation::score_of(&ALICE, None), 810);
+
+        // decay is settled before new points are added
+        <Reputation as GovernanceHooks<_>>::on_voted(&ALICE, None);
+        assert_eq!(Reputation::score_of(&ALICE, None), 811);
+        System::set_block_number(30);
+        assert_eq!(Reputation::score_of(&ALICE, None), 729);
+    });
+}
+
+#[test]
+fn vote_weight_follows_reputation_with_floor_of_one() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&ALICE, Some(CLUB), 2), 1);
+
+        <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, Some(CLUB));
+        <Reputation as GovernanceHooks<_>>::on_proposed(&ALICE, None);
+
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&ALICE, Some(CLUB), 2), 5);
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&ALICE, Some(CLUB + 1), 2), 1);
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&ALICE, None, 2), 8);
+    });
+}
+
+#[test]
+fn vote_weight_is_the_reputation_held_when_the_proposal_was_created() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Badges::create_class(RawOrigin::Signed(ISSUER).into(), [1u8; 32], None, true, false));
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 0, 20));
+        assert_ok!(Badges::issue_badge(RawOrigin::Signed(ISSUER).into(), 0, ALICE, [2u8; 32], None, None));
+
+        // a proposal created at block 2 ignores everything from block 2 on
+        System::set_block_number(2);
+        <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, None);
+        System::set_block_number(3);
+        assert_ok!(Badges::transfer_badge(RawOrigin::Signed(ALICE).into(), 0, 0, BOB));
+        assert_eq!(Reputation::score_of(&ALICE, None), 5);
+        assert_eq!(Reputation::score_of(&BOB, None), 20);
+
+        // the badge points can't vote a second time from the account they moved to
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&ALICE, None, 2), 20);
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&BOB, None, 2), 1);
+        // a later proposal sees the current holdings
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&ALICE, None, 4), 5);
+        assert_eq!(<Reputation as VoteWeight<_, _>>::vote_weight(&BOB, None, 4), 20);
+    });
+}
+
+#[test]
+fn checkpointed_scores_decay_and_are_pruned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Reputation::set_class_weight(RawOrigin::Root.into(), 1, 1000));
+        <Reputation as BadgeHooks<_>>::on_badge_gained(&ALICE, 1, 0, None);
+        // decayed to the epoch of the block asked about
+        assert_eq!(Reputation::score_at(&ALICE, None, 10), 900);
+
+        // changes within a block share a checkpoint; only the last 3 blocks are kept
+        for block in 2..=4 {
+            System::set_block_number(block);
+            <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, None);
+            <Reputation as OnRewardClaimed<_>>::on_reward_claimed(&ALICE, 0, None);
+        }
+        assert_eq!(Reputation::score_at(&ALICE, None, 3), 1010);
+        assert_eq!(Reputation::score_at(&ALICE, None, 5), 1030);
+        assert_eq!(Reputation::score_at(&ALICE, None, 2), 0);
+    });
+}
@@ -12,16 +12,21 @@
 //! - This pallet expects `pallet-member-registry` to be present in the runtime and uses it to:
 //!     - verify attestor is authorized for a club-scoped rule.
 //!     - verify membership if needed by policy.
-//! - Reward payment is done via the `Currency` trait configured in `Config` (wire `pallet_tcc::Pallet` or `pallet_assets` wrapper).
+//! - Rewards are transferred out of `Config::RewardsPot` with `Config::Currency`, any `fungible::Mutate`
+//!   (wire `pallet_tcc::TccOf<Runtime>` and the community-rewards allocation pot). A failed payout
+//!   fails the claim, e.g. with `pallet_tcc::Error::Paused` while $TCC transfers are paused.
+//! - Successful claims are reported to `Config::OnRewardClaimed` (e.g. pallet-reputation).
 //! - Replace WeightInfo placeholders with benchmarked weights before production.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod mock;
 
+mod tests;
 
 use frame_support::{
     pallet_prelude::*,
-    traits::{Currency, EnsureOrigin, UnixTime},
+    traits::{fungible, tokens::Preservation, EnsureOrigin, UnixTime},
     BoundedVec,
 };
 use frame_system::pallet_prelude::*;
@@ -69,6 +74,15 @@ pub mod pallet {
         pub count: u32,
     }
 
+    /// Notified after a reward claim succeeds (`club` is the rule's club scope)
+    pub trait OnRewardClaimed<AccountId> {
+        fn on_reward_claimed(who: &AccountId, rule_id: RuleId, club: Option<ClubId>);
+    }
+
+    impl<AccountId> OnRewardClaimed<AccountId> for () {
+        fn on_reward_claimed(_: &AccountId, _: RuleId, _: Option<ClubId>) {}
+    }
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
@@ -78,8 +92,11 @@ pub mod pallet {
         /// The balance type for the currency
         type Balance: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaybeSerializeDeserialize + MaxEncodedLen;
 
-        /// Currency used to pay rewards (pallet_tcc wrapper or any Currency)
-        type Currency: Currency<Self::AccountId, Balance = Self::Balance>;
+        /// Currency used to pay rewards (`pallet_tcc::TccOf` or any `fungible::Mutate`)
+        type Currency: fungible::Mutate<Self::AccountId, Balance = Self::Balance>;
+
+        /// Account rewards are paid from (e.g. the $TCC community-rewards pot)
+        type RewardsPot: Get<Self::AccountId>;
 
         /// Origin allowed to create rules (e.g., governance/root or club admin via outer checks)
         type RuleCreationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -102,6 +119,9 @@ pub mod pallet {
         /// Max number of attestations per-subject/bounded index if you add indexing (not used here)
         type MaxAttestationsPerSubject: Get<u32>;
 
+        /// Claim hook (use `()` or e.g. pallet-reputation)
+        type OnRewardClaimed: OnRewardClaimed<Self::AccountId>;
+
         /// WeightInfo for extrinsics (replace with benchmarking)
         type WeightInfo: WeightInfo;
     }
@@ -314,11 +334,9 @@ pub mod pallet {
                 att.used = true;
                 *maybe_att = Some(att.clone());
 
-                // transfer reward to caller via Currency::deposit_creating
-                let _amount = rule.amount.saturated_into::<T::Balance>();
-                // T::Currency may be pallet_tcc::Pallet (which implements Currency) or similar
-                //T::Currency::deposit_creating(&who, amount);
+                Self::pay(&who, rule.amount)?;
 
+                T::OnRewardClaimed::on_reward_claimed(&who, att.rule_id, rule.club);
                 Self::deposit_event(Event::RewardClaimed { who: who.clone(), rule_id: att.rule_id, attestation_id, amount: rule.amount });
                 Ok(())
             })
@@ -334,8 +352,7 @@ pub mod pallet {
             reason: Option<Vec<u8>>,
         ) -> DispatchResult {
             T::ManualAwardOrigin::ensure_origin(origin)?;
-            let _bal: <T::Currency as Currency<T::AccountId>>::Balance = amount.saturated_into();
-            //T::Currency::deposit_creating(&to, bal);
+            Self::pay(&to, amount)?;
             Self::deposit_event(Event::RewardAwarded { who: to, amount, reason });
             Ok(())
         }
@@ -392,6 +409,13 @@ pub mod pallet {
             }
         }
 
+        /// Transfer `amount` from the rewards pot to `to`, keeping the pot alive
+        fn pay(to: &T::AccountId, amount: RewardAmount) -> DispatchResult {
+            let amount = amount.saturated_into::<T::Balance>();
+            <T::Currency as fungible::Mutate<_>>::transfer(&T::RewardsPot::get(), to, amount, Preservation::Preserve)?;
+            Ok(())
+        }
+
         /// Convenience getter to inspect an attestation (read-only)
         pub fn get_attestation(attestation_id: AttestationId) -> Option<Attestation<T::AccountId>> {
             Attestations::<T>::get(attestation_id)
@@ -0,0 +1,222 @@
+//! Mock runtime for pallet-rewards tests: rewards are paid in $TCC through `pallet_tcc::TccOf`.
+
+#![cfg(test)]
+
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use frame_support::{
+    parameter_types,
+    construct_runtime,
+    traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Everything},
+    weights::Weight,
+    PalletId,
+};
+use crate as pallet_rewards;
+use pallet_tcc::AllocationPot;
+
+// --- Type aliases used in the mock runtime ---
+// Wide enough for `PalletId` sub-accounts ("modl" + id + pot index) not to be truncated
+pub type AccountId = u128;
+pub type Balance = u64;
+pub type Nonce = u64;
+pub struct DummyWeight;
+
+pub const TCC: u32 = 7;
+pub const REWARDS_FUNDS: Balance = 1_000;
+
+// --- Parameter types ---
+parameter_types! {
+    pub const BlockHashCount: u32 = 2400;
+    pub const SS58Prefix: u8 = 42;
+    pub const MinimumPeriod: u64 = 1;
+    pub const ExistentialDeposit: Balance = 1;
+    pub const TccAssetId: u32 = TCC;
+    pub const TccPalletId: PalletId = PalletId(*b"tcc/pots");
+    pub RewardsPot: AccountId = pallet_tcc::Pallet::<Test>::pot_account(AllocationPot::CommunityRewards);
+}
+
+construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        Tcc: pallet_tcc,
+        Rewards: pallet_rewards,
+    }
+);
+
+impl frame_system::Config for Test {
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+
+    type RuntimeTask = ();
+    type Nonce = Nonce;
+    type Block = frame_system::mocking::MockBlock<Test>;
+    type ExtensionsWeightInfo = ();
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type BlockHashCount = BlockHashCount;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type MaxLocks = ();
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type ReserveIdentifier = ();
+    type FreezeIdentifier = ();
+    type MaxReserves = ();
+    type MaxFreezes = ();
+    type DoneSlashHandler = ();
+}
+
+// Deposits are zero so accounts need no native balance to hold $TCC in tests.
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<5>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU64<0>;
+    type AssetAccountDeposit = ConstU64<0>;
+    type MetadataDepositBase = ConstU64<0>;
+    type MetadataDepositPerByte = ConstU64<0>;
+    type ApprovalDeposit = ConstU64<0>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = Tcc;
+    type Holder = Tcc;
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_tcc::WeightInfo for DummyWeight {
+    fn instantiate_asset() -> Weight { Weight::from_parts(0, 0) }
+    fn mint() -> Weight { Weight::from_parts(0, 0) }
+    fn burn() -> Weight { Weight::from_parts(0, 0) }
+    fn transfer() -> Weight { Weight::from_parts(0, 0) }
+    fn set_max_supply() -> Weight { Weight::from_parts(0, 0) }
+    fn set_mint_budget() -> Weight { Weight::from_parts(0, 0) }
+    fn execute_mint() -> Weight { Weight::from_parts(0, 0) }
+    fn cancel_mint() -> Weight { Weight::from_parts(0, 0) }
+    fn pause() -> Weight { Weight::from_parts(0, 0) }
+    fn unpause() -> Weight { Weight::from_parts(0, 0) }
+    fn freeze_account() -> Weight { Weight::from_parts(0, 0) }
+    fn thaw_account() -> Weight { Weight::from_parts(0, 0) }
+    fn vest() -> Weight { Weight::from_parts(0, 0) }
+    fn vest_other() -> Weight { Weight::from_parts(0, 0) }
+    fn create_vesting() -> Weight { Weight::from_parts(0, 0) }
+    fn terminate_vesting() -> Weight { Weight::from_parts(0, 0) }
+    fn register_foreign_asset() -> Weight { Weight::from_parts(0, 0) }
+    fn deregister_foreign_asset() -> Weight { Weight::from_parts(0, 0) }
+}
+
+impl pallet_tcc::Config for Test {
+    type AssetId = u32;
+    type Balance = Balance;
+    type TccAssetId = TccAssetId;
+    type Assets = Assets;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type PalletId = TccPalletId;
+    type SupplyCapOrigin = frame_system::EnsureRoot<AccountId>;
+    type VestingOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxVestingSchedules = ConstU32<8>;
+    type InstantiateOrigin = frame_system::EnsureRoot<AccountId>;
+    type MintRole = ();
+    type MintOrigin = frame_system::EnsureRoot<AccountId>;
+    type MintEpochLength = ConstU64<100>;
+    type LargeMintThreshold = ConstU64<{ u64::MAX }>;
+    type MintDelay = ConstU64<0>;
+    type GuardianOrigin = frame_system::EnsureRoot<AccountId>;
+    type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+    type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+    type BurnOrigin = frame_system::EnsureRoot<AccountId>;
+    type ForeignAssetOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = DummyWeight;
+}
+
+impl pallet_rewards::WeightInfo for DummyWeight {
+    fn create_rule() -> Weight { Weight::from_parts(0, 0) }
+    fn create_attestation() -> Weight { Weight::from_parts(0, 0) }
+    fn claim_reward() -> Weight { Weight::from_parts(0, 0) }
+    fn award_manual() -> Weight { Weight::from_parts(0, 0) }
+    fn revoke_attestation() -> Weight { Weight::from_parts(0, 0) }
+}
+
+impl pallet_rewards::Config for Test {
+    type Balance = Balance;
+    type Currency = pallet_tcc::TccOf<Test>;
+    type RewardsPot = RewardsPot;
+    type RuleCreationOrigin = frame_system::EnsureRoot<AccountId>;
+    type ManualAwardOrigin = frame_system::EnsureRoot<AccountId>;
+    type TimeProvider = pallet_timestamp::Pallet<Self>;
+    type EpochLengthInBlocks = ConstU64<10>;
+    type MaxMetadataLen = ConstU32<32>;
+    type MaxAttestations = ConstU32<100>;
+    type MaxAttestationsPerSubject = ConstU32<10>;
+    type OnRewardClaimed = ();
+    type WeightInfo = DummyWeight;
+}
+
+// --- TestExternalities builder ---
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let tcc = pallet_tcc::GenesisConfig::<Test> {
+        create_asset: true,
+        allocations: vec![(AllocationPot::CommunityRewards, REWARDS_FUNDS)],
+        ..Default::default()
+    };
+    let storage = RuntimeGenesisConfig { tcc, ..Default::default() }
+        .build_storage()
+        .expect("genesis storage");
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(1);
+    });
+    ext
+}
@@ -0,0 +1,72 @@
+//! Unit tests for pallet-rewards using the per-pallet mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use pallet_tcc::PausedOperations;
+
+const ALICE: AccountId = 2;
+const OFFICER: AccountId = 3;
+const CLUB: u32 = 1;
+
+/// Club rule paying 100 once per epoch, plus an attestation of it for ALICE
+fn rule_and_attestation() -> u64 {
+    assert_ok!(Rewards::create_rule(RawOrigin::Root.into(), b"attendance".to_vec(), 100, 1, Some(CLUB), None));
+    let id = Rewards::next_attestation_id();
+    assert_ok!(Rewards::create_attestation(RawOrigin::Signed(OFFICER).into(), ALICE, 0, None, None));
+    id
+}
+
+#[test]
+fn claims_are_paid_from_the_rewards_pot() {
+    new_test_ext().execute_with(|| {
+        let attestation = rule_and_attestation();
+        assert_ok!(Rewards::claim_reward(RawOrigin::Signed(ALICE).into(), attestation));
+
+        assert_eq!(Tcc::balance_of(&ALICE), 100);
+        assert_eq!(Tcc::balance_of(&RewardsPot::get()), REWARDS_FUNDS - 100);
+        assert!(Rewards::attestations(attestation).unwrap().used);
+        System::assert_has_event(
+            Event::<Test>::RewardClaimed { who: ALICE, rule_id: 0, attestation_id: attestation, amount: 100 }.into(),
+        );
+
+        assert_ok!(Rewards::award_manual(RawOrigin::Root.into(), ALICE, 50, None));
+        assert_eq!(Tcc::balance_of(&ALICE), 150);
+    });
+}
+
+#[test]
+fn payouts_fail_while_tcc_transfers_are_paused() {
+    new_test_ext().execute_with(|| {
+        let attestation = rule_and_attestation();
+        let transfers = PausedOperations { transfers: true, ..Default::default() };
+        assert_ok!(Tcc::pause(RawOrigin::Root.into(), transfers, None));
+
+        // the claim is rolled back: attestation unused, epoch allowance untouched
+        assert_noop!(
+            Rewards::claim_reward(RawOrigin::Signed(ALICE).into(), attestation),
+            pallet_tcc::Error::<Test>::Paused
+        );
+        assert_noop!(
+            Rewards::award_manual(RawOrigin::Root.into(), ALICE, 50, None),
+            pallet_tcc::Error::<Test>::Paused
+        );
+
+        assert_ok!(Tcc::unpause(RawOrigin::Root.into()));
+        assert_ok!(Rewards::claim_reward(RawOrigin::Signed(ALICE).into(), attestation));
+        assert_eq!(Tcc::balance_of(&ALICE), 100);
+    });
+}
+
+#[test]
+fn payouts_cannot_drain_the_pot() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Rewards::award_manual(RawOrigin::Root.into(), ALICE, REWARDS_FUNDS.into(), None),
+            sp_runtime::TokenError::NotExpendable
+        );
+        assert_noop!(Rewards::claim_reward(RawOrigin::Signed(ALICE).into(), 0), Error::<Test>::AttestationNotFound);
+    });
+}
@@ -0,0 +1,68 @@
+//! Benchmarking setup for pallet-tcc-airdrop
+
+use super::*;
+use crate::Pallet as TccAirdrop;
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+
+const TOTAL: u32 = 1_000_000;
+
+/// Fund the funding source and register a round with `root` ending at block 10
+fn setup_round<T: Config>(root: [u8; 32]) -> Result<RoundId, BenchmarkError> {
+    let origin = T::AirdropOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let total: BalanceOf<T> = TOTAL.into();
+    T::Currency::set_balance(&T::FundingSource::get(), total.saturating_add(T::Currency::minimum_balance()));
+    TccAirdrop::<T>::create_round(origin, root, u32::MAX, total, 10u32.into())?;
+    Ok(NextRoundId::<T>::get().wrapping_sub(1))
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_round() -> Result<(), BenchmarkError> {
+        let origin = T::AirdropOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let total: BalanceOf<T> = TOTAL.into();
+        T::Currency::set_balance(&T::FundingSource::get(), total.saturating_add(T::Currency::minimum_balance()));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, [1u8; 32], u32::MAX, total, 10u32.into());
+
+        assert_eq!(T::Currency::balance(&TccAirdrop::<T>::account_id()), total);
+        Ok(())
+    }
+
+    /// Claim with a proof of the maximum length.
+    #[benchmark]
+    fn claim() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let amount: BalanceOf<T> = TOTAL.into();
+        let index = u32::MAX - 1;
+        let proof: Vec<[u8; 32]> = (0..T::MaxProofLength::get()).map(|i| [i as u8; 32]).collect();
+        let root = merkle::root_from_proof(merkle::claim_leaf(index, &caller, amount), &proof);
+        let round = setup_round::<T>(root)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), round, index, amount, proof);
+
+        assert!(TccAirdrop::<T>::is_claimed(round, index));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn close_round() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let round = setup_round::<T>([1u8; 32])?;
+        frame_system::Pallet::<T>::set_block_number(11u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), round);
+
+        assert!(TccAirdrop::<T>::round(round).is_none());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(TccAirdrop, crate::mock::new_test_ext(), crate::mock::Test);
+}
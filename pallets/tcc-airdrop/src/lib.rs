@@ -0,0 +1,256 @@
+//! pallet-tcc-airdrop: Merkle airdrops of $TCC from the community airdrop allocation.
+//!
+//! Features:
+//! - `AirdropOrigin` (governance) registers a round: a Merkle root over `(index, account, amount)`
+//!   leaves (see `merkle`), the number of leaves, the total and a claim deadline. The total moves
+//!   from `FundingSource` (the Airdrop pot) into the pallet account when the round is created.
+//! - Accounts claim their own entry with its leaf index, amount and proof until the deadline;
+//!   each index can be claimed once (claimed bitmap).
+//! - After the deadline anyone can close the round: the unclaimed remainder goes to
+//!   `UnclaimedDestination` (the Treasury pot) and the round is cleared.
+//!
+//! Notes:
+//! - Roots and proofs are built off-chain from a CSV with the `std` helpers in `list`, wrapped by
+//!   the node's `airdrop-merkle` subcommand.
+//! - Claims go through `Config::Currency` ($TCC) and fail with its error, e.g.
+//!   `pallet_tcc::Error::Paused` while $TCC transfers are paused.
+//! - Benchmarks live in `benchmarking.rs` (feature `runtime-benchmarks`).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mock;
+
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod merkle;
+
+#[cfg(feature = "std")]
+pub mod list;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{fungible, tokens::Preservation},
+    PalletId,
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Identifier of an airdrop round
+pub type RoundId = u32;
+
+/// A registered airdrop round
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AirdropRound<Balance, BlockNumber> {
+    /// Merkle root over the round's `(index, account, amount)` leaves
+    pub root: [u8; 32],
+    /// number of leaves in the tree; claim indices must be below it
+    pub leaf_count: u32,
+    /// $TCC set aside for the round
+    pub total: Balance,
+    /// $TCC claimed so far
+    pub claimed: Balance,
+    /// last block at which claims are accepted
+    pub deadline: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use fungible::Mutate;
+
+    pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type AirdropRoundOf<T> = AirdropRound<BalanceOf<T>, BlockNumberFor<T>>;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Currency airdropped ($TCC: `pallet_tcc::TccOf<Runtime>`).
+        type Currency: fungible::Mutate<Self::AccountId>;
+
+        /// Pallet id; its account holds the funds of open rounds.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Account funding new rounds (e.g. the $TCC Airdrop pot).
+        type FundingSource: Get<Self::AccountId>;
+
+        /// Account receiving unclaimed funds after the deadline (e.g. the $TCC Treasury pot).
+        type UnclaimedDestination: Get<Self::AccountId>;
+
+        /// Origin allowed to register rounds (governance).
+        type AirdropOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Max number of sibling hashes accepted in a claim proof
+        #[pallet::constant]
+        type MaxProofLength: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Open airdrop rounds
+    #[pallet::storage]
+    #[pallet::getter(fn round)]
+    pub type Rounds<T: Config> = StorageMap<_, Twox64Concat, RoundId, AirdropRoundOf<T>, OptionQuery>;
+
+    /// Id of the next round
+    #[pallet::storage]
+    pub type NextRoundId<T: Config> = StorageValue<_, RoundId, ValueQuery>;
+
+    /// Claimed bitmap per round: (round, word) -> 32 claim flags for indices `word * 32 ..`
+    #[pallet::storage]
+    pub type ClaimedBitmap<T: Config> = StorageDoubleMap<_, Twox64Concat, RoundId, Twox64Concat, u32, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Round registered and funded
+        RoundCreated { round: RoundId, root: [u8; 32], leaf_count: u32, total: BalanceOf<T>, deadline: BlockNumberFor<T> },
+
+        /// `who` claimed leaf `index` of `round`
+        Claimed { round: RoundId, who: T::AccountId, index: u32, amount: BalanceOf<T> },
+
+        /// Round closed after its deadline; `returned` went to the unclaimed destination
+        RoundClosed { round: RoundId, returned: BalanceOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No such round.
+        UnknownRound,
+        /// Deadline must be in the future, the total and leaf count non-zero.
+        InvalidRound,
+        /// The claim deadline has passed.
+        DeadlinePassed,
+        /// The round is still open for claims.
+        DeadlineNotReached,
+        /// This leaf was already claimed.
+        AlreadyClaimed,
+        /// Proof does not match the round's root.
+        InvalidProof,
+        /// Proof has more than `MaxProofLength` hashes.
+        ProofTooLong,
+        /// Claims would exceed the round's total.
+        RoundExhausted,
+    }
+
+    // Weight trait placeholder: replace with generated benchmarking weights.
+    pub trait WeightInfo {
+        fn create_round() -> Weight;
+        fn claim() -> Weight;
+        fn close_round() -> Weight;
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register an airdrop round and move `total` from the funding source into the pallet.
+        /// Restricted to AirdropOrigin.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_round())]
+        pub fn create_round(
+            origin: OriginFor<T>,
+            root: [u8; 32],
+            leaf_count: u32,
+            total: BalanceOf<T>,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::AirdropOrigin::ensure_origin(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(deadline > now && !total.is_zero() && leaf_count > 0, Error::<T>::InvalidRound);
+
+            Self::transfer(&T::FundingSource::get(), &Self::account_id(), total)?;
+
+            let round = NextRoundId::<T>::get();
+            NextRoundId::<T>::put(round.wrapping_add(1));
+            Rounds::<T>::insert(round, AirdropRound { root, leaf_count, total, claimed: Zero::zero(), deadline });
+            Self::deposit_event(Event::RoundCreated { round, root, leaf_count, total, deadline });
+            Ok(())
+        }
+
+        /// Claim `amount` for leaf `index` of `round`; `proof` holds the sibling hashes up to the
+        /// root. The leaf must name the caller.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::claim())]
+        pub fn claim(
+            origin: OriginFor<T>,
+            round: RoundId,
+            index: u32,
+            amount: BalanceOf<T>,
+            proof: Vec<[u8; 32]>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(proof.len() as u32 <= T::MaxProofLength::get(), Error::<T>::ProofTooLong);
+            let mut info = Rounds::<T>::get(round).ok_or(Error::<T>::UnknownRound)?;
+            ensure!(frame_system::Pallet::<T>::block_number() <= info.deadline, Error::<T>::DeadlinePassed);
+            ensure!(index < info.leaf_count, Error::<T>::InvalidProof);
+
+            let (word, bit) = (index / 32, 1u32 << (index % 32));
+            ensure!(ClaimedBitmap::<T>::get(round, word) & bit == 0, Error::<T>::AlreadyClaimed);
+
+            let leaf = merkle::claim_leaf(index, &who, amount);
+            ensure!(merkle::verify_proof(&info.root, leaf, &proof), Error::<T>::InvalidProof);
+
+            info.claimed = info.claimed.saturating_add(amount);
+            ensure!(info.claimed <= info.total, Error::<T>::RoundExhausted);
+
+            Self::transfer(&Self::account_id(), &who, amount)?;
+            ClaimedBitmap::<T>::mutate(round, word, |bits| *bits |= bit);
+            Rounds::<T>::insert(round, info);
+            Self::deposit_event(Event::Claimed { round, who, index, amount });
+            Ok(())
+        }
+
+        /// Close `round` once its deadline has passed and return the unclaimed remainder.
+        /// Anyone may call it.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::close_round())]
+        pub fn close_round(origin: OriginFor<T>, round: RoundId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let info = Rounds::<T>::get(round).ok_or(Error::<T>::UnknownRound)?;
+            ensure!(frame_system::Pallet::<T>::block_number() > info.deadline, Error::<T>::DeadlineNotReached);
+
+            let returned = info.total.saturating_sub(info.claimed);
+            if !returned.is_zero() {
+                Self::transfer(&Self::account_id(), &T::UnclaimedDestination::get(), returned)?;
+            }
+
+            Rounds::<T>::remove(round);
+            let words = info.leaf_count.div_ceil(32);
+            let _ = ClaimedBitmap::<T>::clear_prefix(round, words, None);
+            Self::deposit_event(Event::RoundClosed { round, returned });
+            Ok(())
+        }
+    }
+
+    // Public helper functions usable by other pallets/runtimes
+    impl<T: Config> Pallet<T> {
+        /// Account holding the funds of open rounds
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Return true if leaf `index` of `round` was already claimed
+        pub fn is_claimed(round: RoundId, index: u32) -> bool {
+            ClaimedBitmap::<T>::get(round, index / 32) & (1u32 << (index % 32)) != 0
+        }
+
+        fn transfer(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            T::Currency::transfer(from, to, amount, Preservation::Expendable)?;
+            Ok(())
+        }
+    }
+}
+
+pub use pallet::*;
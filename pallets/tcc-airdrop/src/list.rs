@@ -0,0 +1,96 @@
+//! Off-chain airdrop lists (`std` only): parse a CSV of recipients and build the round's Merkle
+//! tree, root and claim proofs. The node's `airdrop-merkle` subcommand wraps these helpers.
+//!
+//! The CSV has one `account,amount` row per recipient (accounts as parsed by `FromStr`, e.g.
+//! SS58 or 0x-prefixed hex for `AccountId32`; amounts in base units). An optional header row,
+//! blank lines and `#` comments are skipped. Leaf indices follow row order.
+
+use crate::merkle;
+use codec::Encode;
+use sp_runtime::traits::{CheckedAdd, Zero};
+use std::{collections::BTreeSet, str::FromStr};
+
+/// One recipient's claim
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AirdropClaim<AccountId, Balance> {
+    pub index: u32,
+    pub account: AccountId,
+    pub amount: Balance,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Everything needed to register a round (`create_round`) and claim from it (`claim`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AirdropTree<AccountId, Balance> {
+    pub root: [u8; 32],
+    pub leaf_count: u32,
+    pub total: Balance,
+    pub claims: Vec<AirdropClaim<AccountId, Balance>>,
+}
+
+/// Parse `account,amount` rows, rejecting duplicate accounts and zero amounts.
+pub fn parse_csv<AccountId, Balance>(csv: &str) -> Result<Vec<(AccountId, Balance)>, String>
+where
+    AccountId: FromStr + Ord + Clone,
+    Balance: FromStr + Zero,
+{
+    let mut entries = Vec::new();
+    let mut seen = BTreeSet::new();
+    for (line_no, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = |what: &str| format!("line {}: {what}: {line}", line_no + 1);
+        let (account, amount) = line.split_once(',').ok_or_else(|| invalid("expected `account,amount`"))?;
+        let (account, amount) = (account.trim(), amount.trim());
+        let Ok(who) = account.parse::<AccountId>() else {
+            if entries.is_empty() && amount.parse::<Balance>().is_err() {
+                // header row
+                continue;
+            }
+            return Err(invalid("invalid account"));
+        };
+        let amount: Balance = amount.parse().map_err(|_| invalid("invalid amount"))?;
+        if amount.is_zero() {
+            return Err(invalid("zero amount"));
+        }
+        if !seen.insert(who.clone()) {
+            return Err(invalid("duplicate account"));
+        }
+        entries.push((who, amount));
+    }
+    Ok(entries)
+}
+
+/// Build the tree over `entries`; leaf `i` is `entries[i]`.
+pub fn build_tree<AccountId, Balance>(entries: &[(AccountId, Balance)]) -> Result<AirdropTree<AccountId, Balance>, String>
+where
+    AccountId: Encode + Clone,
+    Balance: Encode + CheckedAdd + Zero + Copy,
+{
+    let leaf_count = u32::try_from(entries.len()).map_err(|_| "too many airdrop entries".to_string())?;
+    let leaves: Vec<[u8; 32]> = entries
+        .iter()
+        .enumerate()
+        .map(|(index, (who, amount))| merkle::claim_leaf(index as u32, who, *amount))
+        .collect();
+    let root = merkle::merkle_root(&leaves).ok_or_else(|| "empty airdrop list".to_string())?;
+
+    let total = entries
+        .iter()
+        .try_fold(Balance::zero(), |acc, (_, amount)| acc.checked_add(amount))
+        .ok_or_else(|| "airdrop total overflows".to_string())?;
+    let claims = entries
+        .iter()
+        .enumerate()
+        .map(|(index, (who, amount))| AirdropClaim {
+            index: index as u32,
+            account: who.clone(),
+            amount: *amount,
+            proof: merkle::merkle_proof(&leaves, index).unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(AirdropTree { root, leaf_count, total, claims })
+}
@@ -0,0 +1,78 @@
+//! Airdrop Merkle tree.
+//!
+//! Leaf `index` of a round is `blake2_256((index, account, amount).encode())`; the index ties each
+//! entry to one bit of the claimed bitmap. Parents are `blake2_256(min(a, b) ++ max(a, b))`, so a
+//! proof is just the list of sibling hashes. A level with an odd number of nodes promotes its last
+//! node unchanged. The `std` helpers below build roots and proofs (see `list` for CSVs).
+
+use codec::Encode;
+use sp_core::hashing::blake2_256;
+#[cfg(feature = "std")]
+use sp_std::vec::Vec;
+
+/// Leaf entitling `who` to `amount`
+pub fn claim_leaf<AccountId: Encode, Balance: Encode>(index: u32, who: &AccountId, amount: Balance) -> [u8; 32] {
+    blake2_256(&(index, who, amount).encode())
+}
+
+/// Hash two sibling nodes into their parent
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let mut buf = [0u8; 64];
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    buf[..32].copy_from_slice(lo);
+    buf[32..].copy_from_slice(hi);
+    blake2_256(&buf)
+}
+
+/// Root reached from `leaf` following `proof`
+pub fn root_from_proof(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(leaf, |acc, sibling| hash_pair(&acc, sibling))
+}
+
+/// Return true if `proof` links `leaf` to `root`
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    &root_from_proof(leaf, proof) == root
+}
+
+/// Compute the root over `leaves`. Returns `None` for no leaves.
+#[cfg(feature = "std")]
+pub fn merkle_root(leaves: &[[u8; 32]]) -> Option<[u8; 32]> {
+    if leaves.is_empty() {
+        return None;
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied()
+}
+
+/// Build the proof for `leaves[index]`
+#[cfg(feature = "std")]
+pub fn merkle_proof(leaves: &[[u8; 32]], mut index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(node) = level.get(index ^ 1) {
+            proof.push(*node);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+#[cfg(feature = "std")]
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => hash_pair(a, b),
+            [a] => *a,
+            _ => unreachable!("chunks(2) yields one or two items"),
+        })
+        .collect()
+}
@@ -0,0 +1,187 @@
+//! Unit tests for pallet-tcc-airdrop using the mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{list, merkle, mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use pallet_tcc::PausedOperations;
+use sp_runtime::DispatchError;
+
+const ALICE: AccountId = 1;
+const BOB: AccountId = 2;
+const CHARLIE: AccountId = 3;
+const DEADLINE: u64 = 50;
+
+/// Airdrop list of the tests: 100 + 200 + 300
+fn entries() -> Vec<(AccountId, Balance)> {
+    vec![(ALICE, 100), (BOB, 200), (CHARLIE, 300)]
+}
+
+fn leaves() -> Vec<[u8; 32]> {
+    entries().iter().enumerate().map(|(i, (who, amount))| merkle::claim_leaf(i as u32, who, *amount)).collect()
+}
+
+fn proof(index: usize) -> Vec<[u8; 32]> {
+    merkle::merkle_proof(&leaves(), index).expect("index in range")
+}
+
+/// Register round 0 over `entries()`
+fn create_round() {
+    let root = merkle::merkle_root(&leaves()).expect("non-empty");
+    assert_ok!(TccAirdrop::create_round(RawOrigin::Root.into(), root, 3, 600, DEADLINE));
+}
+
+#[test]
+fn governance_creates_funded_rounds() {
+    new_test_ext().execute_with(|| {
+        let root = merkle::merkle_root(&leaves()).unwrap();
+        assert_noop!(
+            TccAirdrop::create_round(RawOrigin::Signed(ALICE).into(), root, 3, 600, DEADLINE),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(TccAirdrop::create_round(RawOrigin::Root.into(), root, 3, 600, 1), Error::<Test>::InvalidRound);
+        assert_noop!(
+            TccAirdrop::create_round(RawOrigin::Root.into(), root, 3, AIRDROP_FUNDS + 1, DEADLINE),
+            sp_runtime::ArithmeticError::Underflow
+        );
+
+        create_round();
+        System::assert_last_event(
+            Event::<Test>::RoundCreated { round: 0, root, leaf_count: 3, total: 600, deadline: DEADLINE }.into(),
+        );
+        assert_eq!(Tcc::balance_of(&TccAirdrop::account_id()), 600);
+        assert_eq!(Tcc::balance_of(&AirdropPot::get()), AIRDROP_FUNDS - 600);
+    });
+}
+
+#[test]
+fn claims_pay_out_once() {
+    new_test_ext().execute_with(|| {
+        create_round();
+        assert_ok!(TccAirdrop::claim(RawOrigin::Signed(BOB).into(), 0, 1, 200, proof(1)));
+        System::assert_last_event(Event::<Test>::Claimed { round: 0, who: BOB, index: 1, amount: 200 }.into());
+        assert_eq!(Tcc::balance_of(&BOB), 200);
+        assert!(TccAirdrop::is_claimed(0, 1));
+        assert_eq!(TccAirdrop::round(0).unwrap().claimed, 200);
+
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(BOB).into(), 0, 1, 200, proof(1)), Error::<Test>::AlreadyClaimed);
+        assert_ok!(TccAirdrop::claim(RawOrigin::Signed(CHARLIE).into(), 0, 2, 300, proof(2)));
+        assert_eq!(Tcc::balance_of(&TccAirdrop::account_id()), 100);
+    });
+}
+
+#[test]
+fn invalid_claims_are_rejected() {
+    new_test_ext().execute_with(|| {
+        create_round();
+        // someone else's leaf, inflated amount, wrong index
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(BOB).into(), 0, 0, 100, proof(0)), Error::<Test>::InvalidProof);
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 0, 101, proof(0)), Error::<Test>::InvalidProof);
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 3, 100, proof(0)), Error::<Test>::InvalidProof);
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 1, 0, 100, proof(0)), Error::<Test>::UnknownRound);
+        assert_noop!(
+            TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 0, 100, vec![[0u8; 32]; 9]),
+            Error::<Test>::ProofTooLong
+        );
+    });
+}
+
+#[test]
+fn claims_cannot_exceed_the_round_total() {
+    new_test_ext().execute_with(|| {
+        let leaf = merkle::claim_leaf(0, &ALICE, 1_000u64);
+        assert_ok!(TccAirdrop::create_round(RawOrigin::Root.into(), leaf, 1, 600, DEADLINE));
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 0, 1_000, vec![]), Error::<Test>::RoundExhausted);
+    });
+}
+
+#[test]
+fn claims_fail_with_the_tcc_error_while_transfers_are_paused() {
+    new_test_ext().execute_with(|| {
+        create_round();
+        let transfers = PausedOperations { transfers: true, ..Default::default() };
+        assert_ok!(Tcc::pause(RawOrigin::Root.into(), transfers, None));
+
+        assert_noop!(
+            TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 0, 100, proof(0)),
+            pallet_tcc::Error::<Test>::Paused
+        );
+        assert!(!TccAirdrop::is_claimed(0, 0));
+
+        assert_ok!(Tcc::unpause(RawOrigin::Root.into()));
+        assert_ok!(TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 0, 100, proof(0)));
+        assert_eq!(Tcc::balance_of(&ALICE), 100);
+    });
+}
+
+#[test]
+fn unclaimed_funds_return_to_the_treasury_after_the_deadline() {
+    new_test_ext().execute_with(|| {
+        create_round();
+        assert_ok!(TccAirdrop::claim(RawOrigin::Signed(ALICE).into(), 0, 0, 100, proof(0)));
+        assert_noop!(TccAirdrop::close_round(RawOrigin::Signed(BOB).into(), 0), Error::<Test>::DeadlineNotReached);
+
+        System::set_block_number(DEADLINE + 1);
+        assert_noop!(TccAirdrop::claim(RawOrigin::Signed(BOB).into(), 0, 1, 200, proof(1)), Error::<Test>::DeadlinePassed);
+
+        assert_ok!(TccAirdrop::close_round(RawOrigin::Signed(BOB).into(), 0));
+        System::assert_last_event(Event::<Test>::RoundClosed { round: 0, returned: 500 }.into());
+        assert_eq!(Tcc::balance_of(&TreasuryPot::get()), 500);
+        assert_eq!(Tcc::balance_of(&TccAirdrop::account_id()), 0);
+        assert!(TccAirdrop::round(0).is_none());
+        assert!(!TccAirdrop::is_claimed(0, 0));
+        assert_noop!(TccAirdrop::close_round(RawOrigin::Signed(BOB).into(), 0), Error::<Test>::UnknownRound);
+    });
+}
+
+#[test]
+fn merkle_proofs_cover_odd_trees() {
+    let leaves: Vec<[u8; 32]> = (0..5u32).map(|i| merkle::claim_leaf(i, &(i as AccountId), 1u64)).collect();
+    let root = merkle::merkle_root(&leaves).unwrap();
+    for (i, leaf) in leaves.iter().enumerate() {
+        assert!(merkle::verify_proof(&root, *leaf, &merkle::merkle_proof(&leaves, i).unwrap()));
+    }
+    assert!(merkle::merkle_proof(&leaves, 5).is_none());
+    assert!(merkle::merkle_root(&[]).is_none());
+}
+
+#[test]
+fn claims_accept_the_roots_and_proofs_built_from_a_csv() {
+    let csv = "account,amount\n# early contributors\n1, 100\n\n2,200\n3,300\n";
+    let tree = list::build_tree(&list::parse_csv::<AccountId, Balance>(csv).unwrap()).unwrap();
+    assert_eq!((tree.leaf_count, tree.total), (3, 600));
+    assert_eq!(tree.root, merkle::merkle_root(&leaves()).unwrap());
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(TccAirdrop::create_round(RawOrigin::Root.into(), tree.root, tree.leaf_count, tree.total, DEADLINE));
+        for claim in &tree.claims {
+            assert_ok!(TccAirdrop::claim(
+                RawOrigin::Signed(claim.account).into(),
+                0,
+                claim.index,
+                claim.amount,
+                claim.proof.clone()
+            ));
+            assert_eq!(Tcc::balance_of(&claim.account), claim.amount);
+        }
+    });
+}
+
+#[test]
+fn csv_lists_reject_invalid_rows() {
+    let parse = list::parse_csv::<AccountId, Balance>;
+    assert_eq!(parse("1,100\n1,200").unwrap_err(), "line 2: duplicate account: 1,200");
+    assert_eq!(parse("1,0").unwrap_err(), "line 1: zero amount: 1,0");
+    assert_eq!(parse("1,100\nbob,200").unwrap_err(), "line 2: invalid account: bob,200");
+    assert_eq!(parse("1,lots").unwrap_err(), "line 1: invalid amount: 1,lots");
+    assert_eq!(parse("1 100").unwrap_err(), "line 1: expected `account,amount`: 1 100");
+    assert_eq!(list::build_tree::<AccountId, Balance>(&[]).unwrap_err(), "empty airdrop list");
+    assert_eq!(list::build_tree(&[(1, Balance::MAX), (2, 1)]).unwrap_err(), "airdrop total overflows");
+
+    // runtime accounts are SS58 or 0x-prefixed hex
+    let alice = sp_core::crypto::AccountId32::new([1; 32]);
+    let csv = format!("{alice},5\n0x{},7", "02".repeat(32));
+    let entries = list::parse_csv::<sp_core::crypto::AccountId32, u128>(&csv).unwrap();
+    assert_eq!(entries, vec![(alice, 5), (sp_core::crypto::AccountId32::new([2; 32]), 7)]);
+}
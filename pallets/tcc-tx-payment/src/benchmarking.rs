@@ -0,0 +1,44 @@
+//! Benchmarking setup for pallet-tcc-tx-payment
+
+use super::*;
+use crate::Pallet as TccTxPayment;
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_conversion_rate() -> Result<(), BenchmarkError> {
+        let origin = T::RateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let rate = FixedU128::from_rational(3, 2);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, rate);
+
+        assert_eq!(TccTxPayment::<T>::conversion_rate(), rate);
+        Ok(())
+    }
+
+    /// Hold the estimated fee, then pay part of it and refund the rest.
+    #[benchmark]
+    fn charge_tcc_fee() {
+        let caller: T::AccountId = whitelisted_caller();
+        let fee: BalanceOf<T> = 1_000u32.into();
+        let balance = fee.saturating_mul(10u32.into()).saturating_add(T::Currency::minimum_balance());
+        T::Currency::set_balance(&caller, balance);
+        // make sure the destination exists so the benchmark covers the common path
+        T::Currency::set_balance(&T::FeeDestination::get(), T::Currency::minimum_balance());
+
+        #[block]
+        {
+            TccTxPayment::<T>::withdraw_fee(FeeAsset::Tcc, &caller, fee).expect("caller is funded");
+            TccTxPayment::<T>::settle_fee(FeeAsset::Tcc, &caller, fee / 2u32.into()).expect("fee is held");
+        }
+
+        assert_eq!(T::Currency::balance(&caller), balance.saturating_sub(fee / 2u32.into()));
+    }
+
+    impl_benchmark_test_suite!(TccTxPayment, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,110 @@
+//! `ChargeTccFee` transaction extension.
+
+use crate::{BalanceOf, Config, Event, FeeAsset, Pallet, WeightInfo};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::{
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::*,
+    traits::ContainsPair,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{
+        DispatchInfoOf, Dispatchable, Implication, PostDispatchInfoOf, TransactionExtension, ValidateResult,
+    },
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError, ValidTransaction},
+    DispatchResult, SaturatedConversion,
+};
+
+/// Charge the fee of a signed transaction (plus `tip`) in $TCC, or in the native currency if
+/// the signer cannot pay in $TCC.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeTccFee<T: Config>(#[codec(compact)] pub BalanceOf<T>);
+
+impl<T: Config> core::fmt::Debug for ChargeTccFee<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ChargeTccFee<{:?}>", self.0)
+    }
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+/// Passed from `validate` to `prepare`: payer, fee asset, estimated fee (tip included) and tip
+pub type ValOf<T> = Option<(<T as frame_system::Config>::AccountId, FeeAsset, BalanceOf<T>, BalanceOf<T>)>;
+
+/// Passed from `prepare` to `post_dispatch`: payer, fee asset and tip
+pub type PreOf<T> = Option<(<T as frame_system::Config>::AccountId, FeeAsset, BalanceOf<T>)>;
+
+impl<T: Config> TransactionExtension<T::RuntimeCall> for ChargeTccFee<T>
+where
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+    const IDENTIFIER: &'static str = "ChargeTccFee";
+    type Implicit = ();
+    type Val = ValOf<T>;
+    type Pre = PreOf<T>;
+
+    fn weight(&self, _: &T::RuntimeCall) -> Weight {
+        T::WeightInfo::charge_tcc_fee()
+    }
+
+    fn validate(
+        &self,
+        origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        _: (),
+        _: &impl Implication,
+        _: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let Ok(who) = frame_system::ensure_signed(origin.clone()) else {
+            return Ok((ValidTransaction::default(), None, origin));
+        };
+        if T::FeeExempt::contains(&who, call) {
+            return Ok((ValidTransaction::default(), None, origin));
+        }
+
+        let tip = self.0;
+        let (asset, fee) = Pallet::<T>::fee_asset(&who, len as u32, info, tip).ok_or(InvalidTransaction::Payment)?;
+
+        let priority = tip.saturated_into::<u64>();
+        Ok((ValidTransaction { priority, ..Default::default() }, Some((who, asset, fee, tip)), origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _: &<T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+        _: &T::RuntimeCall,
+        _: &DispatchInfoOf<T::RuntimeCall>,
+        _: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let Some((who, asset, fee, tip)) = val else { return Ok(None) };
+        Pallet::<T>::withdraw_fee(asset, &who, fee).map_err(|_| InvalidTransaction::Payment)?;
+        Ok(Some((who, asset, tip)))
+    }
+
+    fn post_dispatch_details(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+        len: usize,
+        _: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        // nothing charged: the extension weight is unspent
+        let Some((who, asset, tip)) = pre else { return Ok(T::WeightInfo::charge_tcc_fee()) };
+        let actual_fee = Pallet::<T>::actual_fee(asset, len as u32, info, post_info, tip);
+        let actual_fee = Pallet::<T>::settle_fee(asset, &who, actual_fee).map_err(|_| InvalidTransaction::Payment)?;
+
+        Pallet::<T>::deposit_event(match asset {
+            FeeAsset::Tcc => Event::TccFeePaid { who, actual_fee, tip },
+            FeeAsset::Native => Event::NativeFeePaid { who, actual_fee, tip },
+        });
+        Ok(Weight::zero())
+    }
+}
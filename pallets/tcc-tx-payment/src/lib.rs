@@ -0,0 +1,265 @@
+//! pallet-tcc-tx-payment: pay transaction fees in $TCC.
+//!
+//! Features:
+//! - `ChargeTccFee` transaction extension: signed transactions pay their fee (plus an optional
+//!   tip) in `Config::Currency`, in the runtime `pallet_tcc::TccOf`, so accounts holding only $TCC
+//!   can transact.
+//! - Fee = `ConversionRate` x (`BaseFee` + `ByteFee` x length + `WeightToFee`(weight)) + tip.
+//!   The rate is set by `RateOrigin` (governance).
+//! - Signers that cannot pay in $TCC (no balance, or $TCC transfers paused) pay the unconverted
+//!   fee in `Config::NativeCurrency` instead.
+//! - The estimated fee is put on hold before dispatch; afterwards the actual fee (from the
+//!   post-dispatch weight, nothing if the call returns `Pays::No`) goes to `FeeDestination` and
+//!   the rest is released back to the payer. A failed settlement invalidates the transaction.
+//! - `FeeExempt` (signer, call) pairs pay nothing (e.g. sudo calls of the sudo key, so an
+//!   emergency pause can always be lifted).
+//!
+//! Notes:
+//! - The extension should come last in the runtime's extension tuple so it sees the final
+//!   post-dispatch weight.
+//! - Benchmarks live in `benchmarking.rs` (feature `runtime-benchmarks`).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod mock;
+
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod extension;
+
+pub use extension::ChargeTccFee;
+
+use frame_support::{
+    dispatch::{DispatchInfo, Pays, PostDispatchInfo},
+    pallet_prelude::*,
+    traits::{
+        fungible::{self, InspectHold, MutateHold},
+        tokens::{Fortitude, Precision, Restriction},
+        ContainsPair,
+    },
+    weights::WeightToFee,
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    FixedPointNumber, FixedU128, SaturatedConversion,
+};
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Currency a transaction fee is paid in
+    #[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+    pub enum FeeAsset {
+        /// `Config::Currency`, at the conversion rate
+        Tcc,
+        /// `Config::NativeCurrency`, unconverted
+        Native,
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Currency fees are paid in ($TCC: `pallet_tcc::TccOf<Runtime>`).
+        type Currency: fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Native currency charged when the signer cannot pay in `Currency`.
+        type NativeCurrency: fungible::Mutate<Self::AccountId, Balance = BalanceOf<Self>>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Overarching hold reason (fees are held under `HoldReason::TransactionFee`).
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Account receiving the fees (e.g. the $TCC treasury pot).
+        type FeeDestination: Get<Self::AccountId>;
+
+        /// Fee charged for every transaction, before conversion
+        #[pallet::constant]
+        type BaseFee: Get<BalanceOf<Self>>;
+
+        /// Fee per byte of the encoded transaction, before conversion
+        #[pallet::constant]
+        type ByteFee: Get<BalanceOf<Self>>;
+
+        /// Converts the transaction weight to a fee, before conversion
+        type WeightToFee: WeightToFee<Balance = BalanceOf<Self>>;
+
+        /// (signer, call) pairs that pay no fee.
+        type FeeExempt: ContainsPair<Self::AccountId, <Self as frame_system::Config>::RuntimeCall>;
+
+        /// Origin allowed to set the conversion rate (governance).
+        type RateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Hold reasons of this pallet
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Estimated fee held while the transaction is dispatched
+        TransactionFee,
+    }
+
+    #[pallet::type_value]
+    pub fn DefaultConversionRate() -> FixedU128 {
+        FixedU128::one()
+    }
+
+    /// Fee currency charged per unit of computed fee
+    #[pallet::storage]
+    #[pallet::getter(fn conversion_rate)]
+    pub type ConversionRate<T: Config> = StorageValue<_, FixedU128, ValueQuery, DefaultConversionRate>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `who` paid `actual_fee` (tip included) for a transaction
+        TccFeePaid { who: T::AccountId, actual_fee: BalanceOf<T>, tip: BalanceOf<T> },
+
+        /// `who` paid `actual_fee` (tip included) in the native currency
+        NativeFeePaid { who: T::AccountId, actual_fee: BalanceOf<T>, tip: BalanceOf<T> },
+
+        /// Conversion rate changed
+        ConversionRateSet { rate: FixedU128 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Conversion rate must be non-zero.
+        ZeroConversionRate,
+    }
+
+    // Weight trait placeholder: replace with generated benchmarking weights.
+    pub trait WeightInfo {
+        fn set_conversion_rate() -> Weight;
+        fn charge_tcc_fee() -> Weight;
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the fee conversion rate. Restricted to RateOrigin.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_conversion_rate())]
+        pub fn set_conversion_rate(origin: OriginFor<T>, rate: FixedU128) -> DispatchResult {
+            T::RateOrigin::ensure_origin(origin)?;
+            ensure!(!rate.is_zero(), Error::<T>::ZeroConversionRate);
+
+            ConversionRate::<T>::put(rate);
+            Self::deposit_event(Event::ConversionRateSet { rate });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Fee of a transaction of `len` bytes and `weight` in `asset`, including `tip`.
+        pub fn compute_fee(asset: FeeAsset, len: u32, weight: Weight, pays_fee: Pays, tip: BalanceOf<T>) -> BalanceOf<T> {
+            if pays_fee == Pays::No {
+                return tip;
+            }
+            let len_fee = T::ByteFee::get().saturating_mul(len.into());
+            let unconverted = T::BaseFee::get()
+                .saturating_add(len_fee)
+                .saturating_add(T::WeightToFee::weight_to_fee(&weight));
+            let fee = match asset {
+                FeeAsset::Tcc => Self::conversion_rate()
+                    .saturating_mul_int(unconverted.saturated_into::<u128>())
+                    .saturated_into::<BalanceOf<T>>(),
+                FeeAsset::Native => unconverted,
+            };
+            fee.saturating_add(tip)
+        }
+
+        /// Fee estimated before dispatch
+        pub fn estimate_fee(asset: FeeAsset, len: u32, info: &DispatchInfo, tip: BalanceOf<T>) -> BalanceOf<T> {
+            Self::compute_fee(asset, len, info.total_weight(), info.pays_fee, tip)
+        }
+
+        /// Fee due after dispatch, using the actual weight
+        pub fn actual_fee(
+            asset: FeeAsset,
+            len: u32,
+            info: &DispatchInfo,
+            post_info: &PostDispatchInfo,
+            tip: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            Self::compute_fee(asset, len, post_info.calc_actual_weight(info), post_info.pays_fee(info), tip)
+        }
+
+        /// First asset `who` can pay the estimated fee in ($TCC, then native), with that fee.
+        pub fn fee_asset(who: &T::AccountId, len: u32, info: &DispatchInfo, tip: BalanceOf<T>) -> Option<(FeeAsset, BalanceOf<T>)> {
+            [FeeAsset::Tcc, FeeAsset::Native]
+                .into_iter()
+                .map(|asset| (asset, Self::estimate_fee(asset, len, info, tip)))
+                .find(|(asset, fee)| Self::can_withdraw_fee(*asset, who, *fee).is_ok())
+        }
+
+        /// Check that `who` can put `fee` on hold.
+        pub fn can_withdraw_fee(asset: FeeAsset, who: &T::AccountId, fee: BalanceOf<T>) -> DispatchResult {
+            if fee.is_zero() {
+                return Ok(());
+            }
+            let reason = HoldReason::TransactionFee.into();
+            match asset {
+                FeeAsset::Tcc => T::Currency::ensure_can_hold(&reason, who, fee),
+                FeeAsset::Native => T::NativeCurrency::ensure_can_hold(&reason, who, fee),
+            }
+        }
+
+        /// Put the estimated `fee` of `who` on hold.
+        pub fn withdraw_fee(asset: FeeAsset, who: &T::AccountId, fee: BalanceOf<T>) -> DispatchResult {
+            if fee.is_zero() {
+                return Ok(());
+            }
+            let reason = HoldReason::TransactionFee.into();
+            match asset {
+                FeeAsset::Tcc => T::Currency::hold(&reason, who, fee),
+                FeeAsset::Native => T::NativeCurrency::hold(&reason, who, fee),
+            }
+        }
+
+        /// Pay `actual_fee` out of the held fee to `FeeDestination` and release the rest to `who`.
+        /// Returns what was paid.
+        pub fn settle_fee(asset: FeeAsset, who: &T::AccountId, actual_fee: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            match asset {
+                FeeAsset::Tcc => Self::settle::<T::Currency>(who, actual_fee),
+                FeeAsset::Native => Self::settle::<T::NativeCurrency>(who, actual_fee),
+            }
+        }
+
+        fn settle<C>(who: &T::AccountId, actual_fee: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError>
+        where
+            C: fungible::MutateHold<T::AccountId, Balance = BalanceOf<T>, Reason = T::RuntimeHoldReason>,
+        {
+            let reason = HoldReason::TransactionFee.into();
+            let held = C::balance_on_hold(&reason, who);
+            let paid = C::transfer_on_hold(
+                &reason,
+                who,
+                &T::FeeDestination::get(),
+                actual_fee.min(held),
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Force,
+            )?;
+
+            let refund = C::balance_on_hold(&reason, who);
+            if !refund.is_zero() {
+                C::release(&reason, who, refund, Precision::Exact)?;
+            }
+            Ok(paid)
+        }
+    }
+}
+
+pub use pallet::*;
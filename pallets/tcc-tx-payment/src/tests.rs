@@ -0,0 +1,176 @@
+//! Unit tests for pallet-tcc-tx-payment using the mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{mock::*, ChargeTccFee, Error, Event, HoldReason};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, Pays, PostDispatchInfo},
+    traits::fungible::{InspectHold, Mutate},
+    weights::Weight,
+};
+use frame_system::RawOrigin;
+use pallet_tcc::{PausedOperations, TccOf};
+use sp_runtime::{
+    traits::{DispatchTransaction, TransactionExtension, TxBaseImplication},
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+    FixedU128,
+};
+
+const ALICE: AccountId = 1;
+const LEN: usize = 10;
+
+fn remark() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+/// Weight 100: estimated fee 10 (base) + 10 (length) + 100 (weight) = 120
+fn info() -> DispatchInfo {
+    DispatchInfo { call_weight: Weight::from_parts(100, 0), ..Default::default() }
+}
+
+fn post_info(actual_weight: Option<u64>, pays_fee: Pays) -> PostDispatchInfo {
+    PostDispatchInfo { actual_weight: actual_weight.map(|w| Weight::from_parts(w, 0)), pays_fee }
+}
+
+fn funded() {
+    assert_ok!(TccOf::<Test>::mint_into(&ALICE, 1_000));
+}
+
+#[test]
+fn estimated_fee_is_held_and_unused_weight_refunded() {
+    new_test_ext().execute_with(|| {
+        funded();
+        assert_ok!(ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            assert_eq!(TccOf::<Test>::balance_on_hold(&HoldReason::TransactionFee.into(), &ALICE), 120);
+            Ok(post_info(Some(40), Pays::Yes))
+        }));
+
+        // 10 + 10 + 40
+        assert_eq!(Tcc::balance_of(&ALICE), 940);
+        assert_eq!(Tcc::balance_of(&TREASURY), 60);
+        assert_eq!(TccOf::<Test>::total_balance_on_hold(&ALICE), 0);
+        System::assert_last_event(Event::<Test>::TccFeePaid { who: ALICE, actual_fee: 60, tip: 0 }.into());
+    });
+}
+
+#[test]
+fn conversion_rate_and_tip_apply() {
+    new_test_ext().execute_with(|| {
+        funded();
+        assert_noop!(
+            TccTxPayment::set_conversion_rate(RawOrigin::Signed(ALICE).into(), FixedU128::from_u32(2)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            TccTxPayment::set_conversion_rate(RawOrigin::Root.into(), FixedU128::from_u32(0)),
+            Error::<Test>::ZeroConversionRate
+        );
+        assert_ok!(TccTxPayment::set_conversion_rate(RawOrigin::Root.into(), FixedU128::from_u32(2)));
+        System::assert_last_event(Event::<Test>::ConversionRateSet { rate: FixedU128::from_u32(2) }.into());
+
+        assert_ok!(ChargeTccFee::<Test>(5).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            Ok(post_info(None, Pays::Yes))
+        }));
+        // 2 x 120 + 5
+        assert_eq!(Tcc::balance_of(&ALICE), 755);
+        assert_eq!(Tcc::balance_of(&TREASURY), 245);
+    });
+}
+
+#[test]
+fn pays_no_refunds_everything_but_the_tip() {
+    new_test_ext().execute_with(|| {
+        funded();
+        assert_ok!(ChargeTccFee::<Test>(3).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            Ok(post_info(None, Pays::No))
+        }));
+        assert_eq!(Tcc::balance_of(&ALICE), 997);
+        assert_eq!(Tcc::balance_of(&TREASURY), 3);
+    });
+}
+
+#[test]
+fn exempt_calls_and_unsigned_transactions_pay_nothing() {
+    new_test_ext().execute_with(|| {
+        let exempt = RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] });
+        assert_ok!(ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(SUDO).into(), &exempt, &info(), LEN, 0, |_| {
+            Ok(post_info(None, Pays::Yes))
+        }));
+        // the same call from any other signer pays
+        let result = ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(ALICE).into(), &exempt, &info(), LEN, 0, |_| {
+            Ok(post_info(None, Pays::Yes))
+        });
+        assert_eq!(result.unwrap_err(), TransactionValidityError::Invalid(InvalidTransaction::Payment));
+        let (_, val, _) = ChargeTccFee::<Test>(0)
+            .validate(RawOrigin::None.into(), &remark(), &info(), LEN, (), &TxBaseImplication(()), TransactionSource::External)
+            .expect("unsigned transactions are not charged");
+        assert!(val.is_none());
+        assert_eq!(Tcc::balance_of(&TREASURY), 0);
+    });
+}
+
+#[test]
+fn accounts_without_enough_tcc_are_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(TccOf::<Test>::mint_into(&ALICE, 100));
+        let result = ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            Ok(post_info(None, Pays::Yes))
+        });
+        assert_eq!(result.unwrap_err(), TransactionValidityError::Invalid(InvalidTransaction::Payment));
+        assert_eq!(Tcc::balance_of(&ALICE), 100);
+    });
+}
+
+#[test]
+fn signers_without_tcc_pay_in_the_native_currency() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Balances::mint_into(&ALICE, 1_000));
+        assert_ok!(TccTxPayment::set_conversion_rate(RawOrigin::Root.into(), FixedU128::from_u32(2)));
+
+        assert_ok!(ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            assert_eq!(Balances::balance_on_hold(&HoldReason::TransactionFee.into(), &ALICE), 120);
+            Ok(post_info(Some(40), Pays::Yes))
+        }));
+
+        // unconverted 10 + 10 + 40; the other 60 held is released
+        assert_eq!(Balances::free_balance(ALICE), 940);
+        assert_eq!(Balances::free_balance(TREASURY), 60);
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), 0);
+        assert_eq!(Tcc::balance_of(&TREASURY), 0);
+        System::assert_last_event(Event::<Test>::NativeFeePaid { who: ALICE, actual_fee: 60, tip: 0 }.into());
+    });
+}
+
+#[test]
+fn paused_tcc_falls_back_to_the_native_currency() {
+    new_test_ext().execute_with(|| {
+        funded();
+        assert_ok!(Balances::mint_into(&ALICE, 1_000));
+        assert_ok!(Tcc::pause(RawOrigin::Root.into(), PausedOperations::ALL, None));
+
+        assert_ok!(ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            assert_eq!(Balances::balance_on_hold(&HoldReason::TransactionFee.into(), &ALICE), 120);
+            Ok(post_info(None, Pays::No))
+        }));
+        assert_eq!(Tcc::balance_of(&ALICE), 1_000);
+        assert_eq!(Balances::free_balance(ALICE), 1_000);
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), 0);
+    });
+}
+
+#[test]
+fn failed_fee_settlement_rejects_the_transaction() {
+    new_test_ext().execute_with(|| {
+        funded();
+        assert_ok!(TccOf::<Test>::mint_into(&TREASURY, 1));
+        // the treasury can no longer receive $TCC
+        assert_ok!(Assets::block(RawOrigin::Signed(Tcc::asset_account()).into(), TCC, TREASURY));
+
+        let result = ChargeTccFee::<Test>(0).test_run(RawOrigin::Signed(ALICE).into(), &remark(), &info(), LEN, 0, |_| {
+            Ok(post_info(Some(40), Pays::Yes))
+        });
+        assert_eq!(result.unwrap_err(), TransactionValidityError::Invalid(InvalidTransaction::Payment));
+        assert_eq!(Tcc::balance_of(&TREASURY), 1);
+    });
+}
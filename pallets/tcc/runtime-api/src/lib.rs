@@ -0,0 +1,34 @@
+//! Runtime API for reading $TCC supply and tokenomics state (wallets, explorers, dashboards).
+//!
+//! A runtime implements it by delegating to the pallet helpers, e.g.
+//! `fn allocation_balances() { Tcc::allocation_balances() }`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_tcc::{AccountFreeze, AllocationPot, PauseStatus};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait TccApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Account and current balance of every genesis allocation pot
+        fn allocation_balances() -> Vec<(AllocationPot, AccountId, Balance)>;
+
+        /// Current $TCC supply
+        fn total_supply() -> Balance;
+
+        /// Hard cap on the supply (None = uncapped)
+        fn max_supply() -> Option<Balance>;
+
+        /// Active emergency pause (None = nothing paused)
+        fn pause_status() -> Option<PauseStatus<BlockNumber>>;
+
+        /// Account freeze of `who` set by governance, if any
+        fn account_freeze(who: AccountId) -> Option<AccountFreeze<Balance, BlockNumber>>;
+    }
+}
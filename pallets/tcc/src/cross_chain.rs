@@ -0,0 +1,87 @@
+//! $TCC over XCM.
+//!
+//! $TCC is a pallet-assets asset, so like Asset Hub assets its canonical location inside this
+//! parachain is `(PalletInstance(<assets pallet index>), GeneralIndex(<asset id>))`, i.e.
+//! `(1, [Parachain(<para id>), PalletInstance(..), GeneralIndex(..)])` from Asset Hub and siblings.
+//!
+//! This chain is the reserve of $TCC: it only leaves through reserve transfers (no teleports), and
+//! only to Asset Hub and whitelisted siblings (`TccReserveTransfers`). Incoming reserve assets are
+//! trusted only when they come from the chain they belong to and that chain is whitelisted
+//! (`TrustedReserves`); they still need an asset registered locally by governance
+//! (`register_foreign_asset`, matched by `ForeignAssetIds`) to be deposited, otherwise they are
+//! trapped. The runtime's `xcm_config` wires these into pallet-xcm and the XCM executor.
+
+use core::marker::PhantomData;
+use frame_support::traits::{Contains, ContainsPair, Get};
+use sp_runtime::traits::MaybeEquivalence;
+use sp_std::vec::Vec;
+use xcm::latest::prelude::*;
+
+use crate::{Config, ForeignAssetLocations, ForeignAssets};
+
+/// Para id of Asset Hub
+pub const ASSET_HUB_PARA_ID: u32 = 1000;
+
+/// $TCC as seen from this chain
+pub fn tcc_local_location(assets_pallet_index: u8, asset_id: u128) -> Location {
+    Location::new(0, [PalletInstance(assets_pallet_index), GeneralIndex(asset_id)])
+}
+
+/// $TCC as seen from Asset Hub and sibling parachains
+pub fn tcc_sibling_location(para_id: u32, assets_pallet_index: u8, asset_id: u128) -> Location {
+    Location::new(1, [Parachain(para_id), PalletInstance(assets_pallet_index), GeneralIndex(asset_id)])
+}
+
+/// Asset Hub plus the sibling parachains whose id is in `Siblings`
+pub struct AssetHubAndSiblings<Siblings>(PhantomData<Siblings>);
+
+impl<Siblings: Contains<u32>> Contains<Location> for AssetHubAndSiblings<Siblings> {
+    fn contains(location: &Location) -> bool {
+        matches!(location.unpack(), (1, [Parachain(id)]) if *id == ASSET_HUB_PARA_ID || Siblings::contains(id))
+    }
+}
+
+/// `XcmReserveTransferFilter`: only fungible $TCC (`TccLocation`, relative to this chain), only to
+/// `Destinations`.
+pub struct TccReserveTransfers<TccLocation, Destinations>(PhantomData<(TccLocation, Destinations)>);
+
+impl<TccLocation: Get<Location>, Destinations: Contains<Location>> Contains<(Location, Vec<Asset>)>
+    for TccReserveTransfers<TccLocation, Destinations>
+{
+    fn contains((dest, assets): &(Location, Vec<Asset>)) -> bool {
+        let tcc = TccLocation::get();
+        Destinations::contains(dest)
+            && !assets.is_empty()
+            && assets.iter().all(|asset| asset.id.0 == tcc && matches!(asset.fun, Fungible(_)))
+    }
+}
+
+/// `IsReserve`: `origin` is trusted as reserve of `asset` only if the asset belongs to `origin`
+/// itself and `origin` is in `Origins`.
+pub struct TrustedReserves<Origins>(PhantomData<Origins>);
+
+impl<Origins: Contains<Location>> ContainsPair<Asset, Location> for TrustedReserves<Origins> {
+    fn contains(asset: &Asset, origin: &Location) -> bool {
+        Origins::contains(origin) && asset.id.0.chain_location() == *origin
+    }
+}
+
+/// Foreign assets registered with `register_foreign_asset`: matches their locations and converts
+/// them to the local asset id and back, for the runtime's foreign-asset transactor.
+pub struct ForeignAssetIds<T>(PhantomData<T>);
+
+impl<T: Config> Contains<Location> for ForeignAssetIds<T> {
+    fn contains(location: &Location) -> bool {
+        ForeignAssets::<T>::contains_key(location)
+    }
+}
+
+impl<T: Config> MaybeEquivalence<Location, T::AssetId> for ForeignAssetIds<T> {
+    fn convert(location: &Location) -> Option<T::AssetId> {
+        ForeignAssets::<T>::get(location)
+    }
+
+    fn convert_back(asset_id: &T::AssetId) -> Option<Location> {
+        ForeignAssetLocations::<T>::get(asset_id)
+    }
+}
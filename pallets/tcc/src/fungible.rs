@@ -0,0 +1,277 @@
+//! Single-asset `fungible` view of $TCC.
+//!
+//! `TccOf<T>` is an `ItemOf`-style adapter: it implements `fungible::{Inspect, Unbalanced, Mutate}`
+//! by fixing the asset id of `Config::Assets` to `TccAssetId`, so pallets needing a currency can
+//! take `TccOf<Runtime>` instead of `Balances`.
+//!
+//! pallet-assets has no holds or freezes of its own, so this pallet keeps them for $TCC (like
+//! pallet-assets-holder / pallet-assets-freezer do for every asset) and reports them back through
+//! `pallet_assets::{BalanceOnHold, FrozenBalance}`. The runtime must therefore set
+//! `Holder = Tcc` and `Freezer = Tcc` on the pallet-assets instance backing $TCC; otherwise
+//! `fungible::{InspectHold, MutateHold, InspectFreeze, MutateFreeze}` bookkeeping is ignored by
+//! pallet-assets.
+
+use crate::{BalancesOnHold, Config, Error, Event, FrozenBalances, Freezes, Holds, Pallet, TccOperation};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible, fungibles,
+        tokens::{
+            DepositConsequence, Fortitude, IdAmount, Precision, Preservation, Provenance, WithdrawConsequence,
+        },
+    },
+};
+use pallet_assets::{BalanceOnHold, FrozenBalance};
+use sp_runtime::{
+    traits::{Bounded, CheckedAdd, CheckedSub, Zero},
+    ArithmeticError, TokenError,
+};
+use sp_std::marker::PhantomData;
+
+/// `fungible` view of the $TCC asset of `T::Assets`
+pub struct TccOf<T>(PhantomData<T>);
+
+impl<T: Config> BalanceOnHold<T::AssetId, T::AccountId, T::Balance> for Pallet<T> {
+    fn balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+        if asset != T::TccAssetId::get() {
+            return None;
+        }
+        BalancesOnHold::<T>::get(who)
+    }
+
+    fn died(asset: T::AssetId, who: &T::AccountId) {
+        if asset == T::TccAssetId::get() {
+            Holds::<T>::remove(who);
+            BalancesOnHold::<T>::remove(who);
+        }
+    }
+
+    fn contains_holds(asset: T::AssetId) -> bool {
+        asset == T::TccAssetId::get() && BalancesOnHold::<T>::iter_keys().next().is_some()
+    }
+}
+
+impl<T: Config> FrozenBalance<T::AssetId, T::AccountId, T::Balance> for Pallet<T> {
+    fn frozen_balance(asset: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+        if asset != T::TccAssetId::get() {
+            return None;
+        }
+        // a transfer pause freezes every balance so direct asset transfers fail too
+        if Pallet::<T>::is_paused(TccOperation::Transfer) {
+            return Some(T::Balance::max_value());
+        }
+        FrozenBalances::<T>::get(who)
+    }
+
+    fn died(asset: T::AssetId, who: &T::AccountId) {
+        if asset == T::TccAssetId::get() {
+            Freezes::<T>::remove(who);
+            FrozenBalances::<T>::remove(who);
+        }
+    }
+
+    fn contains_freezes(asset: T::AssetId) -> bool {
+        asset == T::TccAssetId::get() && FrozenBalances::<T>::iter_keys().next().is_some()
+    }
+}
+
+impl<T: Config> fungible::Inspect<T::AccountId> for TccOf<T> {
+    type Balance = T::Balance;
+
+    fn total_issuance() -> T::Balance {
+        <T::Assets as fungibles::Inspect<_>>::total_issuance(T::TccAssetId::get())
+    }
+    fn active_issuance() -> T::Balance {
+        <T::Assets as fungibles::Inspect<_>>::active_issuance(T::TccAssetId::get())
+    }
+    fn minimum_balance() -> T::Balance {
+        <T::Assets as fungibles::Inspect<_>>::minimum_balance(T::TccAssetId::get())
+    }
+    fn total_balance(who: &T::AccountId) -> T::Balance {
+        <T::Assets as fungibles::Inspect<_>>::total_balance(T::TccAssetId::get(), who)
+    }
+    fn balance(who: &T::AccountId) -> T::Balance {
+        <T::Assets as fungibles::Inspect<_>>::balance(T::TccAssetId::get(), who)
+    }
+    fn reducible_balance(who: &T::AccountId, preservation: Preservation, force: Fortitude) -> T::Balance {
+        <T::Assets as fungibles::Inspect<_>>::reducible_balance(T::TccAssetId::get(), who, preservation, force)
+    }
+    fn can_deposit(who: &T::AccountId, amount: T::Balance, provenance: Provenance) -> DepositConsequence {
+        <T::Assets as fungibles::Inspect<_>>::can_deposit(T::TccAssetId::get(), who, amount, provenance)
+    }
+    fn can_withdraw(who: &T::AccountId, amount: T::Balance) -> WithdrawConsequence<T::Balance> {
+        <T::Assets as fungibles::Inspect<_>>::can_withdraw(T::TccAssetId::get(), who, amount)
+    }
+}
+
+impl<T: Config> fungible::Unbalanced<T::AccountId> for TccOf<T> {
+    fn handle_dust(dust: fungible::Dust<T::AccountId, Self>) {
+        <T::Assets as fungibles::Unbalanced<_>>::handle_dust(fungibles::Dust(T::TccAssetId::get(), dust.0));
+    }
+    fn write_balance(who: &T::AccountId, amount: T::Balance) -> Result<Option<T::Balance>, DispatchError> {
+        <T::Assets as fungibles::Unbalanced<_>>::write_balance(T::TccAssetId::get(), who, amount)
+    }
+    fn set_total_issuance(amount: T::Balance) {
+        <T::Assets as fungibles::Unbalanced<_>>::set_total_issuance(T::TccAssetId::get(), amount)
+    }
+    fn decrease_balance(
+        who: &T::AccountId,
+        amount: T::Balance,
+        precision: Precision,
+        preservation: Preservation,
+        force: Fortitude,
+    ) -> Result<T::Balance, DispatchError> {
+        <T::Assets as fungibles::Unbalanced<_>>::decrease_balance(
+            T::TccAssetId::get(),
+            who,
+            amount,
+            precision,
+            preservation,
+            force,
+        )
+    }
+    fn increase_balance(who: &T::AccountId, amount: T::Balance, precision: Precision) -> Result<T::Balance, DispatchError> {
+        <T::Assets as fungibles::Unbalanced<_>>::increase_balance(T::TccAssetId::get(), who, amount, precision)
+    }
+}
+
+// Mint, burn and transfer fail with `Paused` while the operation is paused.
+impl<T: Config> fungible::Mutate<T::AccountId> for TccOf<T> {
+    fn mint_into(who: &T::AccountId, amount: T::Balance) -> Result<T::Balance, DispatchError> {
+        Pallet::<T>::ensure_not_paused(TccOperation::Mint)?;
+        <T::Assets as fungibles::Mutate<_>>::mint_into(T::TccAssetId::get(), who, amount)
+    }
+    fn burn_from(
+        who: &T::AccountId,
+        amount: T::Balance,
+        preservation: Preservation,
+        precision: Precision,
+        force: Fortitude,
+    ) -> Result<T::Balance, DispatchError> {
+        Pallet::<T>::ensure_not_paused(TccOperation::Burn)?;
+        <T::Assets as fungibles::Mutate<_>>::burn_from(T::TccAssetId::get(), who, amount, preservation, precision, force)
+    }
+    fn transfer(
+        source: &T::AccountId,
+        dest: &T::AccountId,
+        amount: T::Balance,
+        preservation: Preservation,
+    ) -> Result<T::Balance, DispatchError> {
+        Pallet::<T>::ensure_not_paused(TccOperation::Transfer)?;
+        <T::Assets as fungibles::Mutate<_>>::transfer(T::TccAssetId::get(), source, dest, amount, preservation)
+    }
+}
+
+impl<T: Config> fungible::InspectHold<T::AccountId> for TccOf<T> {
+    type Reason = T::RuntimeHoldReason;
+
+    fn total_balance_on_hold(who: &T::AccountId) -> T::Balance {
+        BalancesOnHold::<T>::get(who).unwrap_or_else(Zero::zero)
+    }
+    fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> T::Balance {
+        Holds::<T>::get(who).iter().find(|h| &h.id == reason).map_or_else(Zero::zero, |h| h.amount)
+    }
+    // new holds (reservations) are paused with transfers
+    fn ensure_can_hold(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+        Pallet::<T>::ensure_not_paused(TccOperation::Transfer)?;
+        ensure!(Self::hold_available(reason, who), TokenError::CannotCreateHold);
+        ensure!(
+            amount <= <Self as fungible::Inspect<_>>::reducible_balance(who, Preservation::Protect, Fortitude::Force),
+            TokenError::FundsUnavailable
+        );
+        Ok(())
+    }
+}
+
+impl<T: Config> fungible::UnbalancedHold<T::AccountId> for TccOf<T> {
+    fn set_balance_on_hold(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+        let mut holds = Holds::<T>::get(who);
+        let previous = match holds.iter().position(|h| &h.id == reason) {
+            Some(pos) if amount.is_zero() => holds.swap_remove(pos).amount,
+            Some(pos) => sp_std::mem::replace(&mut holds[pos].amount, amount),
+            None if amount.is_zero() => Zero::zero(),
+            None => {
+                holds.try_push(IdAmount { id: *reason, amount }).map_err(|_| Error::<T>::TooManyHolds)?;
+                Zero::zero()
+            }
+        };
+
+        let total = BalancesOnHold::<T>::get(who).unwrap_or_else(Zero::zero);
+        let total = total
+            .checked_sub(&previous)
+            .ok_or(ArithmeticError::Underflow)?
+            .checked_add(&amount)
+            .ok_or(ArithmeticError::Overflow)?;
+
+        if holds.is_empty() {
+            Holds::<T>::remove(who);
+        } else {
+            Holds::<T>::insert(who, holds);
+        }
+        if total.is_zero() {
+            BalancesOnHold::<T>::remove(who);
+        } else {
+            BalancesOnHold::<T>::insert(who, total);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> fungible::MutateHold<T::AccountId> for TccOf<T> {
+    fn done_hold(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) {
+        Pallet::<T>::deposit_event(Event::Held { who: who.clone(), reason: *reason, amount });
+    }
+    fn done_release(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) {
+        Pallet::<T>::deposit_event(Event::Released { who: who.clone(), reason: *reason, amount });
+    }
+    fn done_burn_held(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) {
+        Pallet::<T>::deposit_event(Event::BurnedHeld { who: who.clone(), reason: *reason, amount });
+    }
+}
+
+impl<T: Config> fungible::InspectFreeze<T::AccountId> for TccOf<T> {
+    type Id = T::RuntimeFreezeReason;
+
+    fn balance_frozen(id: &Self::Id, who: &T::AccountId) -> T::Balance {
+        Freezes::<T>::get(who).iter().find(|f| &f.id == id).map_or_else(Zero::zero, |f| f.amount)
+    }
+    fn can_freeze(id: &Self::Id, who: &T::AccountId) -> bool {
+        let freezes = Freezes::<T>::get(who);
+        !freezes.is_full() || freezes.iter().any(|f| &f.id == id)
+    }
+}
+
+impl<T: Config> fungible::MutateFreeze<T::AccountId> for TccOf<T> {
+    fn set_freeze(id: &Self::Id, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+        if amount.is_zero() {
+            return Self::thaw(id, who);
+        }
+        let mut freezes = Freezes::<T>::get(who);
+        match freezes.iter_mut().find(|f| &f.id == id) {
+            Some(f) => f.amount = amount,
+            None => freezes.try_push(IdAmount { id: *id, amount }).map_err(|_| Error::<T>::TooManyFreezes)?,
+        }
+        Pallet::<T>::update_freezes(who, freezes);
+        Ok(())
+    }
+
+    fn extend_freeze(id: &Self::Id, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+        if amount.is_zero() {
+            return Ok(());
+        }
+        let mut freezes = Freezes::<T>::get(who);
+        match freezes.iter_mut().find(|f| &f.id == id) {
+            Some(f) => f.amount = f.amount.max(amount),
+            None => freezes.try_push(IdAmount { id: *id, amount }).map_err(|_| Error::<T>::TooManyFreezes)?,
+        }
+        Pallet::<T>::update_freezes(who, freezes);
+        Ok(())
+    }
+
+    fn thaw(id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+        let mut freezes = Freezes::<T>::get(who);
+        freezes.retain(|f| &f.id != id);
+        Pallet::<T>::update_freezes(who, freezes);
+        Ok(())
+    }
+}
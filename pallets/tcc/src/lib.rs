@@ -1,40 +1,157 @@
 //! pallet-tcc: $TCC asset wrapper using pallet-assets (full implementation)
 //!
 //! Features:
-//! - Runtime extrinsic to instantiate the $TCC asset (force-create via pallet-assets).
+//! - Runtime extrinsic to instantiate the $TCC asset (created in the configured `Assets` instance).
 //! - Controlled minting and burning (MintOrigin / BurnOrigin).
+//! - Mint safeguards: every minting role (the `MintOrigin` success value) has a per-epoch budget,
+//!   and mints above `LargeMintThreshold` are queued for `MintDelay` blocks, during which
+//!   `GuardianOrigin` can cancel them.
 //! - Transfer via user-signed extrinsic (regular asset transfer).
 //! - Helper read APIs: balance_of, total_supply, asset_exists.
+//! - `TccOf<T>`: `fungible::{Inspect, Mutate, MutateHold, MutateFreeze}` view of $TCC so rewards,
+//!   reservations and fees can use $TCC wherever a currency is expected (see `fungible.rs`).
+//! - Hard supply cap: `mint` never exceeds `MaxSupply`, adjustable by `SupplyCapOrigin` (governance).
+//! - Genesis tokenomics: genesis may create the asset and credit the allocation pots (community
+//!   rewards, treasury, ops/team, airdrop), each a sub-account of `PalletId`. Balances are exposed
+//!   through `pallet-tcc-runtime-api`.
+//! - Vesting: linear schedules with a cliff (`VestingOrigin` or genesis), claimed with
+//!   `vest`/`vest_other`; termination returns the unvested part to the treasury pot (see `vesting.rs`).
+//! - Emergency pause: `PauseOrigin` can halt transfers, minting and/or burning (also on the
+//!   underlying asset), optionally until a block; see `pause.rs` and `pause_status`.
+//! - Account freezes: `FreezeOrigin` can freeze all or part of an account's $TCC (a fungible
+//!   freeze under `FreezeReason::AccountFrozen`) with a reason hash, e.g. during a fraud review.
+//! - XCM: canonical $TCC `Location`, reserve-transfer filter (Asset Hub and whitelisted siblings)
+//!   and trusted-reserve policy for incoming assets, used by the runtime's `xcm_config` (see
+//!   `cross_chain.rs`).
+//! - Foreign assets: `ForeignAssetOrigin` registers assets of other chains (by XCM location) as
+//!   local assets, so reserve transfers of them are deposited instead of trapped (`ForeignAssetIds`).
 //! - Events for lifecycle actions.
 //!
 //! Notes:
-//! - This pallet expects the runtime to include `pallet-assets` and to satisfy the trait bounds
-//!   in `Config` (see runtime glue snippet for example wiring).
-//! - Minting/Burning use `force_*` APIs from pallet-assets guarded by origin checks in this pallet
+//! - Balances and supply live in `Config::Assets`, any `fungibles::{Create, Mutate, Inspect}`
+//!   implementation (in the runtime: a pallet-assets instance).
+//! - Minting/Burning go through `fungibles::Mutate` guarded by origin checks in this pallet
 //!   (so the runtime may choose a governance/multisig origin as the mint authority).
 //! - We use bounded/strong typing for AssetId / Balance to keep compile-time checks tight.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod mock;
 
+mod tests;
+
+pub mod fungible;
+
+pub use fungible::TccOf;
+
+pub mod vesting;
+
+pub use vesting::VestingSchedule;
+
+pub mod pause;
+
+pub use pause::{PauseStatus, PausedOperations, TccOperation};
+
+pub mod cross_chain;
 
 use frame_support::{
     pallet_prelude::*,
-    traits::{EnsureOrigin, Get},
+    traits::{
+        fungible::{InspectFreeze, MutateFreeze},
+        fungibles::{Create, Inspect, Mutate},
+        tokens::{Balance as BalanceT, Fortitude, IdAmount, Precision, Preservation},
+        EnsureOrigin, Get, VariantCount, VariantCountOf,
+    },
 };
+use frame_support::PalletId;
 use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    traits::{AccountIdConversion, AtLeast32BitUnsigned, Bounded, Saturating, Zero},
+    Percent,
+};
 use sp_std::prelude::*;
+use xcm::{latest::Location, VersionedLocation};
 
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::traits::BuildGenesisConfig;
-    use sp_runtime::traits::{AtLeast32BitUnsigned, StaticLookup};
+    use sp_runtime::traits::StaticLookup;
 
     use super::*;
 
     // Re-export types from pallet-assets for convenience in runtime wiring
     pub type AssetIdOf<T> = <T as Config>::AssetId;
     pub type BalanceOf<T> = <T as Config>::Balance;
+    pub type HoldsOf<T> =
+        BoundedVec<IdAmount<<T as Config>::RuntimeHoldReason, BalanceOf<T>>, VariantCountOf<<T as Config>::RuntimeHoldReason>>;
+    pub type FreezesOf<T> =
+        BoundedVec<IdAmount<<T as Config>::RuntimeFreezeReason, BalanceOf<T>>, VariantCountOf<<T as Config>::RuntimeFreezeReason>>;
+    pub type VestingScheduleOf<T> = VestingSchedule<BalanceOf<T>, BlockNumberFor<T>>;
+    pub type VestingSchedulesOf<T> = BoundedVec<VestingScheduleOf<T>, <T as Config>::MaxVestingSchedules>;
+    pub type PendingMintOf<T> =
+        PendingMint<<T as Config>::MintRole, <T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+    /// Genesis vesting entry: (beneficiary, total, start, cliff, duration)
+    pub type GenesisVestingOf<T> =
+        (<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>, BlockNumberFor<T>, BlockNumberFor<T>);
+
+    /// Genesis allocation pots of the $TCC supply
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+    pub enum AllocationPot {
+        CommunityRewards,
+        Treasury,
+        OpsTeam,
+        Airdrop,
+    }
+
+    impl AllocationPot {
+        pub const ALL: [AllocationPot; 4] =
+            [AllocationPot::CommunityRewards, AllocationPot::Treasury, AllocationPot::OpsTeam, AllocationPot::Airdrop];
+
+        /// Share of the supply allocated to the pot (50/25/15/10)
+        pub fn share(self) -> Percent {
+            match self {
+                AllocationPot::CommunityRewards => Percent::from_percent(50),
+                AllocationPot::Treasury => Percent::from_percent(25),
+                AllocationPot::OpsTeam => Percent::from_percent(15),
+                AllocationPot::Airdrop => Percent::from_percent(10),
+            }
+        }
+
+        /// Split `total` between all pots by `share`; rounding dust goes to community rewards.
+        pub fn split<B: AtLeast32BitUnsigned + Copy>(total: B) -> Vec<(AllocationPot, B)> {
+            let mut split: Vec<(AllocationPot, B)> = Self::ALL.iter().map(|p| (*p, p.share().mul_floor(total))).collect();
+            let allocated = split.iter().fold(B::zero(), |acc, (_, b)| acc.saturating_add(*b));
+            split[0].1 = split[0].1.saturating_add(total.saturating_sub(allocated));
+            split
+        }
+    }
+
+    /// Freeze of an account's $TCC by `FreezeOrigin`
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AccountFreeze<Balance, BlockNumber> {
+        /// Frozen amount (None => the whole balance, including future receipts)
+        pub amount: Option<Balance>,
+        /// Hash of the off-chain case / justification
+        pub reason_hash: [u8; 32],
+        pub frozen_at: BlockNumber,
+    }
+
+    pub type AccountFreezeOf<T> = AccountFreeze<BalanceOf<T>, BlockNumberFor<T>>;
+
+    /// Identifier of a queued mint
+    pub type MintId = u32;
+
+    /// A mint above `LargeMintThreshold`, executable once the delay has passed
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PendingMint<MintRole, AccountId, Balance, BlockNumber> {
+        pub role: MintRole,
+        pub to: AccountId,
+        pub amount: Balance,
+        /// Epoch whose budget the mint was charged to
+        pub epoch: BlockNumber,
+        pub executable_at: BlockNumber,
+    }
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -46,41 +163,149 @@ pub mod pallet {
         type AssetId: Parameter + Member + Copy + MaybeSerializeDeserialize + MaxEncodedLen + Default;
 
         /// The Balance type used by pallet-assets.
-        type Balance: Parameter
-            + Member
-            + AtLeast32BitUnsigned
-            + Default
-            + Copy
-            + MaybeSerializeDeserialize
-            + MaxEncodedLen;
+        type Balance: BalanceT + MaybeSerializeDeserialize;
 
         /// The configured AssetId value that will represent $TCC.
         type TccAssetId: Get<Self::AssetId>;
 
+        /// Fungibles implementation holding $TCC balances (e.g. a pallet-assets instance).
+        type Assets: Create<Self::AccountId>
+            + Mutate<Self::AccountId>
+            + Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>;
+
+        /// Reasons $TCC can be held for (`fungible::MutateHold` on `TccOf`).
+        type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Copy + VariantCount;
+
+        /// Reasons $TCC can be frozen for (`fungible::MutateFreeze` on `TccOf`).
+        type RuntimeFreezeReason: Parameter + Member + MaxEncodedLen + Copy + VariantCount + From<FreezeReason>;
+
+        /// Pallet id deriving the allocation pot accounts
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Origin allowed to change `MaxSupply` and the mint budgets (governance).
+        type SupplyCapOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to create and terminate vesting schedules (governance).
+        type VestingOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of vesting schedules per beneficiary
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+
         /// Origin allowed to instantiate the asset (root/governance).
         type InstantiateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        /// Origin allowed to mint $TCC (e.g., governance/multisig/treasury).
-        type MintOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Minting roles, each with its own per-epoch budget.
+        type MintRole: Parameter + Member + MaxEncodedLen + Copy;
+
+        /// Origin allowed to mint $TCC (e.g., governance/multisig/treasury), yielding its role.
+        type MintOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::MintRole>;
+
+        /// Length of a mint budget epoch in blocks
+        #[pallet::constant]
+        type MintEpochLength: Get<BlockNumberFor<Self>>;
+
+        /// Mints above this amount are queued instead of executed immediately
+        #[pallet::constant]
+        type LargeMintThreshold: Get<Self::Balance>;
+
+        /// Blocks a queued mint waits before it can be executed
+        #[pallet::constant]
+        type MintDelay: Get<BlockNumberFor<Self>>;
+
+        /// Origin allowed to cancel queued mints (e.g. a security council).
+        type GuardianOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to pause and unpause $TCC operations (emergency).
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to freeze and thaw accounts (governance).
+        type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// Origin allowed to burn via privileged burn (if needed).
         type BurnOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// Origin allowed to register and deregister foreign assets (governance).
+        type ForeignAssetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
 
+    /// Freeze reasons of this pallet
+    #[pallet::composite_enum]
+    pub enum FreezeReason {
+        /// Account frozen by `FreezeOrigin` (`freeze_account`)
+        AccountFrozen,
+    }
+
     // Storage: keep track if we've already instantiated the asset to prevent re-creation.
     #[pallet::storage]
     #[pallet::getter(fn asset_instantiated)]
     pub type AssetInstantiated<T: Config> = StorageValue<_, bool, ValueQuery>;
 
-    // Total supply cache optional (we can optionally update it on mint/burn).
-    // Note: pallet-assets already maintains supply; this cache is convenience and can be removed.
+    /// Hard cap on the $TCC supply enforced by `mint` (absent => uncapped)
+    #[pallet::storage]
+    #[pallet::getter(fn max_supply)]
+    pub type MaxSupply<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+    /// Holds on $TCC per account (at most one per hold reason)
+    #[pallet::storage]
+    pub type Holds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, HoldsOf<T>, ValueQuery>;
+
+    /// Sum of `Holds` per account (absent => nothing on hold)
+    #[pallet::storage]
+    pub type BalancesOnHold<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+    /// Freezes on $TCC per account (at most one per freeze reason)
+    #[pallet::storage]
+    pub type Freezes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, FreezesOf<T>, ValueQuery>;
+
+    /// Largest of `Freezes` per account (absent => nothing frozen)
+    #[pallet::storage]
+    pub type FrozenBalances<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+    /// Vesting schedules per beneficiary, funds escrowed in `vesting_account()`
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedules)]
+    pub type VestingSchedules<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, VestingSchedulesOf<T>, ValueQuery>;
+
+    /// Maximum minted per epoch by each role (absent => unlimited)
+    #[pallet::storage]
+    #[pallet::getter(fn mint_budget)]
+    pub type MintBudgets<T: Config> = StorageMap<_, Blake2_128Concat, T::MintRole, BalanceOf<T>, OptionQuery>;
+
+    /// Minted (or queued) per role in the role's latest epoch: (epoch index, amount)
+    #[pallet::storage]
+    pub type EpochMinted<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::MintRole, (BlockNumberFor<T>, BalanceOf<T>), ValueQuery>;
+
+    /// Queued large mints
     #[pallet::storage]
-    #[pallet::getter(fn cached_total_supply)]
-    pub type CachedTotalSupply<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    #[pallet::getter(fn pending_mint)]
+    pub type PendingMints<T: Config> = StorageMap<_, Twox64Concat, MintId, PendingMintOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type NextMintId<T: Config> = StorageValue<_, MintId, ValueQuery>;
+
+    /// Accounts frozen by `freeze_account`
+    #[pallet::storage]
+    #[pallet::getter(fn account_freeze)]
+    pub type AccountFreezes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AccountFreezeOf<T>, OptionQuery>;
+
+    /// Active emergency pause (absent => nothing paused)
+    #[pallet::storage]
+    pub type PauseState<T: Config> = StorageValue<_, PauseStatus<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Local asset id of each registered foreign asset, by location (relative to this chain)
+    #[pallet::storage]
+    pub type ForeignAssets<T: Config> = StorageMap<_, Blake2_128Concat, Location, AssetIdOf<T>, OptionQuery>;
+
+    /// Location of each registered foreign asset, by local asset id
+    #[pallet::storage]
+    pub type ForeignAssetLocations<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Location, OptionQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -96,6 +321,57 @@ pub mod pallet {
 
         /// Transferred $TCC (from -> to)
         Transferred { from: T::AccountId, to: T::AccountId, amount: BalanceOf<T> },
+
+        /// Per-epoch mint budget of `role` changed (None = unlimited)
+        MintBudgetSet { role: T::MintRole, budget: Option<BalanceOf<T>> },
+
+        /// Large mint queued, executable from `executable_at`
+        MintQueued { id: MintId, role: T::MintRole, to: T::AccountId, amount: BalanceOf<T>, executable_at: BlockNumberFor<T> },
+
+        /// Queued mint executed (followed by `Minted`)
+        MintExecuted { id: MintId },
+
+        /// Queued mint cancelled by the guardian; its budget is refunded
+        MintCancelled { id: MintId },
+
+        /// $TCC operations paused, until block `until` if set
+        Paused { operations: PausedOperations, until: Option<BlockNumberFor<T>> },
+
+        /// Pause lifted (by `PauseOrigin` or because `until` was reached)
+        Unpaused,
+
+        /// $TCC of `who` frozen (`amount` None = whole balance)
+        AccountFrozen { who: T::AccountId, amount: Option<BalanceOf<T>>, reason_hash: [u8; 32] },
+
+        /// Account freeze lifted
+        AccountThawed { who: T::AccountId },
+
+        /// Supply cap changed (None = uncapped)
+        MaxSupplySet { max_supply: Option<BalanceOf<T>> },
+
+        /// $TCC of an account was put on hold
+        Held { who: T::AccountId, reason: T::RuntimeHoldReason, amount: BalanceOf<T> },
+
+        /// $TCC on hold was released back to the free balance
+        Released { who: T::AccountId, reason: T::RuntimeHoldReason, amount: BalanceOf<T> },
+
+        /// $TCC on hold was burned
+        BurnedHeld { who: T::AccountId, reason: T::RuntimeHoldReason, amount: BalanceOf<T> },
+
+        /// Vesting schedule added for `who`, funded from `source`
+        VestingCreated { who: T::AccountId, source: AllocationPot, schedule: VestingScheduleOf<T> },
+
+        /// Vested $TCC paid out to `who`
+        Vested { who: T::AccountId, amount: BalanceOf<T> },
+
+        /// Vesting schedule terminated: `paid` to the beneficiary, `returned` to the treasury
+        VestingTerminated { who: T::AccountId, index: u32, paid: BalanceOf<T>, returned: BalanceOf<T> },
+
+        /// Foreign asset at `location` registered as local asset `asset_id`
+        ForeignAssetRegistered { location: Location, asset_id: AssetIdOf<T> },
+
+        /// Foreign asset deregistered; XCM no longer moves it in or out
+        ForeignAssetDeregistered { location: Location, asset_id: AssetIdOf<T> },
     }
 
     #[pallet::error]
@@ -110,8 +386,40 @@ pub mod pallet {
         AssetOperationFailed,
         /// Transfer failed.
         TransferFailed,
-        /// Overflow/underflow when updating cache.
-        SupplyOverflow,
+        /// Minting would exceed `MaxSupply`.
+        MaxSupplyExceeded,
+        /// New cap is lower than the current supply.
+        MaxSupplyBelowIssuance,
+        /// Minting role has used its budget for the current epoch.
+        MintBudgetExceeded,
+        /// No queued mint with that id.
+        NoPendingMint,
+        /// Queued mint is still within its delay.
+        MintTimelocked,
+        /// The operation is paused.
+        Paused,
+        /// Pause selects no operation or ends in the past.
+        InvalidPause,
+        /// Nothing is paused.
+        NotPaused,
+        /// Account is not frozen.
+        NotFrozen,
+        /// Account already has a hold for every hold reason.
+        TooManyHolds,
+        /// Account already has a freeze for every freeze reason.
+        TooManyFreezes,
+        /// Account has no (such) vesting schedule.
+        NotVesting,
+        /// Beneficiary already has `MaxVestingSchedules` schedules.
+        TooManyVestingSchedules,
+        /// Zero amount or duration, or cliff outside the schedule.
+        InvalidVestingSchedule,
+        /// Location is not a foreign asset (local, or not convertible to the current XCM version).
+        NotForeignAsset,
+        /// Location already registered, or the asset id is $TCC or taken.
+        ForeignAssetExists,
+        /// No foreign asset registered at that location.
+        UnknownForeignAsset,
     }
 
     // Weight trait placeholder: replace with generated benchmarking weights.
@@ -120,11 +428,41 @@ pub mod pallet {
         fn mint() -> Weight;
         fn burn() -> Weight;
         fn transfer() -> Weight;
+        fn set_max_supply() -> Weight;
+        fn set_mint_budget() -> Weight;
+        fn execute_mint() -> Weight;
+        fn cancel_mint() -> Weight;
+        fn pause() -> Weight;
+        fn unpause() -> Weight;
+        fn freeze_account() -> Weight;
+        fn thaw_account() -> Weight;
+        fn vest() -> Weight;
+        fn vest_other() -> Weight;
+        fn create_vesting() -> Weight;
+        fn terminate_vesting() -> Weight;
+        fn register_foreign_asset() -> Weight;
+        fn deregister_foreign_asset() -> Weight;
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Lift a pause whose `until` block has been reached.
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            match PauseState::<T>::get() {
+                Some(PauseStatus { until: Some(until), .. }) if now >= until => {
+                    PauseState::<T>::kill();
+                    Self::deposit_event(Event::Unpaused);
+                    T::DbWeight::get().reads_writes(1, 1)
+                }
+                _ => T::DbWeight::get().reads(1),
+            }
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Instantiate the $TCC asset using pallet-assets::force_create.
+        /// Instantiate the $TCC asset in `Config::Assets`, owned and administered by the keyless
+        /// `asset_account` so minting, freezing and destroying only go through this pallet.
         /// Restricted to `InstantiateOrigin` (e.g., Root or governance).
         ///
         /// Note: min_balance can be > 0 to require a minimum balance for accounts.
@@ -132,9 +470,8 @@ pub mod pallet {
         #[pallet::weight(T::WeightInfo::instantiate_asset())]
         pub fn instantiate_asset(
             origin: OriginFor<T>,
-            owner: <T::Lookup as StaticLookup>::Source,
-            _min_balance: BalanceOf<T>,
-            _is_sufficient: bool,
+            min_balance: BalanceOf<T>,
+            is_sufficient: bool,
         ) -> DispatchResult {
             // Ensure caller has permission to create the asset
             T::InstantiateOrigin::ensure_origin(origin)?;
@@ -143,14 +480,11 @@ pub mod pallet {
             ensure!(!AssetInstantiated::<T>::get(), Error::<T>::AlreadyInstantiated);
 
             let asset_id = T::TccAssetId::get();
-            let owner = T::Lookup::lookup(owner)?;
-
-            // For now, we just mark as instantiated
-            // TODO: Wire this properly with pallet-assets in runtime when needed
+            let owner = Self::asset_account();
 
-            // mark instantiated and optionally update cached total supply to zero
+            T::Assets::create(asset_id, owner.clone(), is_sufficient, min_balance)
+                .map_err(|_| Error::<T>::AssetOperationFailed)?;
             AssetInstantiated::<T>::put(true);
-            CachedTotalSupply::<T>::put(BalanceOf::<T>::from(0u32));
 
             Self::deposit_event(Event::AssetInstantiated { asset_id, owner });
             Ok(())
@@ -158,7 +492,8 @@ pub mod pallet {
 
         /// Mint $TCC to account. Restricted to MintOrigin.
         ///
-        /// Uses pallet-assets::force_mint guarded by MintOrigin.
+        /// The amount is charged to the caller role's epoch budget. Amounts above
+        /// `LargeMintThreshold` are queued for `MintDelay` blocks (see `execute_mint`).
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::mint())]
         pub fn mint(
@@ -166,33 +501,29 @@ pub mod pallet {
             to: <T::Lookup as StaticLookup>::Source,
             amount: BalanceOf<T>,
         ) -> DispatchResult {
-            T::MintOrigin::ensure_origin(origin)?;
+            let role = T::MintOrigin::ensure_origin(origin)?;
             ensure!(AssetInstantiated::<T>::get(), Error::<T>::NotInstantiated);
-
-            let _asset_id = T::TccAssetId::get();
+            Self::ensure_not_paused(TccOperation::Mint)?;
+            Self::ensure_within_cap(amount)?;
             let to = T::Lookup::lookup(to)?;
+            let epoch = Self::charge_mint_budget(role, amount)?;
 
-            // call force_mint (requires Root origin)
-            // TODO: Wire this properly with pallet-assets in runtime
-            // let root = frame_system::RawOrigin::Root.into();
-            // pallet_assets::Pallet::<Runtime>::force_mint(root, asset_id, to.clone(), amount)
-            //     .map_err(|_| Error::<T>::AssetOperationFailed)?;
+            if amount <= T::LargeMintThreshold::get() {
+                return Self::do_mint(to, amount);
+            }
 
-            // update cached total supply (best-effort)
-            CachedTotalSupply::<T>::try_mutate(|supply| -> Result<(), DispatchError> {
-                *supply = supply
-                    .checked_add(&amount)
-                    .ok_or(Error::<T>::SupplyOverflow)?;
-                Ok(())
-            })?;
+            let id = NextMintId::<T>::get();
+            NextMintId::<T>::put(id.wrapping_add(1));
+            let executable_at = frame_system::Pallet::<T>::block_number().saturating_add(T::MintDelay::get());
+            PendingMints::<T>::insert(id, PendingMint { role, to: to.clone(), amount, epoch, executable_at });
 
-            Self::deposit_event(Event::Minted { to, amount });
+            Self::deposit_event(Event::MintQueued { id, role, to, amount, executable_at });
             Ok(())
         }
 
         /// Burn $TCC from an account. Restricted to BurnOrigin (privileged).
         ///
-        /// Uses pallet-assets::force_burn to remove tokens from `from`.
+        /// Force-burns exactly `amount` from `from` (failing if the balance is lower).
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::burn())]
         pub fn burn(
@@ -202,22 +533,13 @@ pub mod pallet {
         ) -> DispatchResult {
             T::BurnOrigin::ensure_origin(origin)?;
             ensure!(AssetInstantiated::<T>::get(), Error::<T>::NotInstantiated);
+            Self::ensure_not_paused(TccOperation::Burn)?;
 
-            let _asset_id = T::TccAssetId::get();
+            let asset_id = T::TccAssetId::get();
             let from = T::Lookup::lookup(from)?;
 
-            // TODO: Wire this properly with pallet-assets in runtime
-            // let root = frame_system::RawOrigin::Root.into();
-            // pallet_assets::Pallet::<Runtime>::force_burn(root, asset_id, from.clone(), amount)
-            //     .map_err(|_| Error::<T>::AssetOperationFailed)?;
-
-            // update cached total supply (best-effort)
-            CachedTotalSupply::<T>::try_mutate(|supply| -> Result<(), DispatchError> {
-                *supply = supply
-                    .checked_sub(&amount)
-                    .ok_or(Error::<T>::SupplyOverflow)?;
-                Ok(())
-            })?;
+            T::Assets::burn_from(asset_id, &from, amount, Preservation::Expendable, Precision::Exact, Fortitude::Force)
+                .map_err(|_| Error::<T>::AssetOperationFailed)?;
 
             Self::deposit_event(Event::Burned { from, amount });
             Ok(())
@@ -234,23 +556,257 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(AssetInstantiated::<T>::get(), Error::<T>::NotInstantiated);
+            Self::ensure_not_paused(TccOperation::Transfer)?;
 
-            let _asset_id = T::TccAssetId::get();
+            let asset_id = T::TccAssetId::get();
             let to = T::Lookup::lookup(to)?;
 
-            // Call pallet-assets transfer; it needs a Signed origin (the caller)
-            // TODO: Wire this properly with pallet-assets in runtime
-            // pallet_assets::Pallet::<Runtime>::transfer(
-            //     frame_system::RawOrigin::Signed(who.clone()).into(),
-            //     asset_id,
-            //     to.clone(),
-            //     amount,
-            // )
-            // .map_err(|_| Error::<T>::TransferFailed)?;
+            T::Assets::transfer(asset_id, &who, &to, amount, Preservation::Expendable)
+                .map_err(|_| Error::<T>::TransferFailed)?;
 
             Self::deposit_event(Event::Transferred { from: who, to, amount });
             Ok(())
         }
+
+        /// Set or lift (None) the hard cap on the $TCC supply. Restricted to SupplyCapOrigin.
+        /// The cap can't be set below the current supply.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_max_supply())]
+        pub fn set_max_supply(origin: OriginFor<T>, max_supply: Option<BalanceOf<T>>) -> DispatchResult {
+            T::SupplyCapOrigin::ensure_origin(origin)?;
+            if let Some(cap) = max_supply {
+                ensure!(cap >= Self::total_supply(), Error::<T>::MaxSupplyBelowIssuance);
+            }
+
+            MaxSupply::<T>::set(max_supply);
+            Self::deposit_event(Event::MaxSupplySet { max_supply });
+            Ok(())
+        }
+
+        /// Set or lift (None) the per-epoch mint budget of `role`. Restricted to SupplyCapOrigin.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_mint_budget())]
+        pub fn set_mint_budget(origin: OriginFor<T>, role: T::MintRole, budget: Option<BalanceOf<T>>) -> DispatchResult {
+            T::SupplyCapOrigin::ensure_origin(origin)?;
+            MintBudgets::<T>::set(role, budget);
+            Self::deposit_event(Event::MintBudgetSet { role, budget });
+            Ok(())
+        }
+
+        /// Execute a queued mint whose delay has passed. Callable by anyone.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::execute_mint())]
+        pub fn execute_mint(origin: OriginFor<T>, id: MintId) -> DispatchResult {
+            ensure_signed(origin)?;
+            let pending = PendingMints::<T>::get(id).ok_or(Error::<T>::NoPendingMint)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= pending.executable_at, Error::<T>::MintTimelocked);
+            Self::ensure_not_paused(TccOperation::Mint)?;
+            Self::ensure_within_cap(pending.amount)?;
+
+            PendingMints::<T>::remove(id);
+            Self::deposit_event(Event::MintExecuted { id });
+            Self::do_mint(pending.to, pending.amount)
+        }
+
+        /// Cancel a queued mint. Restricted to GuardianOrigin. The amount is given back to the
+        /// role's budget if its epoch is still running.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::cancel_mint())]
+        pub fn cancel_mint(origin: OriginFor<T>, id: MintId) -> DispatchResult {
+            T::GuardianOrigin::ensure_origin(origin)?;
+            let pending = PendingMints::<T>::take(id).ok_or(Error::<T>::NoPendingMint)?;
+
+            EpochMinted::<T>::mutate(pending.role, |(epoch, minted)| {
+                if *epoch == pending.epoch {
+                    *minted = minted.saturating_sub(pending.amount);
+                }
+            });
+            Self::deposit_event(Event::MintCancelled { id });
+            Ok(())
+        }
+
+        /// Pause `operations` on $TCC, replacing any active pause. With `until`, the pause is
+        /// lifted automatically at that block. Restricted to PauseOrigin.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(
+            origin: OriginFor<T>,
+            operations: PausedOperations,
+            until: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            ensure!(!operations.is_empty(), Error::<T>::InvalidPause);
+            if let Some(until) = until {
+                ensure!(until > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidPause);
+            }
+
+            PauseState::<T>::put(PauseStatus { paused: operations, until });
+            Self::deposit_event(Event::Paused { operations, until });
+            Ok(())
+        }
+
+        /// Lift the active pause. Restricted to PauseOrigin.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::unpause())]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+            ensure!(PauseState::<T>::exists(), Error::<T>::NotPaused);
+
+            PauseState::<T>::kill();
+            Self::deposit_event(Event::Unpaused);
+            Ok(())
+        }
+
+        /// Freeze `amount` of the $TCC of `who` (None = the whole balance), replacing any earlier
+        /// account freeze. Frozen funds stay in place and can't be moved until thawed.
+        /// `reason_hash` points to the off-chain case. Restricted to FreezeOrigin.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::freeze_account())]
+        pub fn freeze_account(
+            origin: OriginFor<T>,
+            who: <T::Lookup as StaticLookup>::Source,
+            amount: Option<BalanceOf<T>>,
+            reason_hash: [u8; 32],
+        ) -> DispatchResult {
+            T::FreezeOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(who)?;
+
+            let frozen = amount.unwrap_or_else(BalanceOf::<T>::max_value);
+            <TccOf<T> as MutateFreeze<_>>::set_freeze(&FreezeReason::AccountFrozen.into(), &who, frozen)?;
+            let frozen_at = frame_system::Pallet::<T>::block_number();
+            AccountFreezes::<T>::insert(&who, AccountFreeze { amount, reason_hash, frozen_at });
+
+            Self::deposit_event(Event::AccountFrozen { who, amount, reason_hash });
+            Ok(())
+        }
+
+        /// Lift the account freeze of `who`. Restricted to FreezeOrigin.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::thaw_account())]
+        pub fn thaw_account(origin: OriginFor<T>, who: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
+            T::FreezeOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(who)?;
+            ensure!(AccountFreezes::<T>::contains_key(&who), Error::<T>::NotFrozen);
+
+            <TccOf<T> as MutateFreeze<_>>::thaw(&FreezeReason::AccountFrozen.into(), &who)?;
+            AccountFreezes::<T>::remove(&who);
+
+            Self::deposit_event(Event::AccountThawed { who });
+            Ok(())
+        }
+
+        /// Register the foreign asset at `location` (relative to this chain) as the new local
+        /// asset `asset_id`, so XCM deposits of it are minted there instead of trapped. The asset
+        /// is created sufficient and administered by `asset_account`. Restricted to
+        /// ForeignAssetOrigin.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::register_foreign_asset())]
+        pub fn register_foreign_asset(
+            origin: OriginFor<T>,
+            location: Box<VersionedLocation>,
+            asset_id: AssetIdOf<T>,
+            min_balance: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::ForeignAssetOrigin::ensure_origin(origin)?;
+            let location = Self::foreign_location(*location)?;
+            ensure!(!ForeignAssets::<T>::contains_key(&location), Error::<T>::ForeignAssetExists);
+            ensure!(
+                asset_id != T::TccAssetId::get() && !T::Assets::asset_exists(asset_id),
+                Error::<T>::ForeignAssetExists
+            );
+
+            T::Assets::create(asset_id, Self::asset_account(), true, min_balance)
+                .map_err(|_| Error::<T>::AssetOperationFailed)?;
+            ForeignAssets::<T>::insert(&location, asset_id);
+            ForeignAssetLocations::<T>::insert(asset_id, &location);
+
+            Self::deposit_event(Event::ForeignAssetRegistered { location, asset_id });
+            Ok(())
+        }
+
+        /// Deregister the foreign asset at `location`. The local asset and its balances stay, but
+        /// XCM no longer deposits or withdraws it. Restricted to ForeignAssetOrigin.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::deregister_foreign_asset())]
+        pub fn deregister_foreign_asset(origin: OriginFor<T>, location: Box<VersionedLocation>) -> DispatchResult {
+            T::ForeignAssetOrigin::ensure_origin(origin)?;
+            let location = Self::foreign_location(*location)?;
+            let asset_id = ForeignAssets::<T>::take(&location).ok_or(Error::<T>::UnknownForeignAsset)?;
+            ForeignAssetLocations::<T>::remove(asset_id);
+
+            Self::deposit_event(Event::ForeignAssetDeregistered { location, asset_id });
+            Ok(())
+        }
+
+        /// Claim the caller's vested $TCC.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::vest())]
+        pub fn vest(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_vest(&who)
+        }
+
+        /// Pay out the vested $TCC of `target` (to `target`). Callable by anyone.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::vest_other())]
+        pub fn vest_other(origin: OriginFor<T>, target: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
+            ensure_signed(origin)?;
+            let target = T::Lookup::lookup(target)?;
+            Self::do_vest(&target)
+        }
+
+        /// Vest `total` to `beneficiary` linearly over `duration` blocks from `start`, with nothing
+        /// claimable before `cliff`. The amount is moved from the `source` pot into escrow.
+        /// Restricted to VestingOrigin.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::create_vesting())]
+        pub fn create_vesting(
+            origin: OriginFor<T>,
+            beneficiary: <T::Lookup as StaticLookup>::Source,
+            source: AllocationPot,
+            total: BalanceOf<T>,
+            start: BlockNumberFor<T>,
+            cliff: BlockNumberFor<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::VestingOrigin::ensure_origin(origin)?;
+            ensure!(AssetInstantiated::<T>::get(), Error::<T>::NotInstantiated);
+            let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+            let schedule = VestingSchedule::new(total, start, cliff, duration);
+            Self::add_vesting_schedule(&beneficiary, source, schedule.clone())?;
+
+            Self::deposit_event(Event::VestingCreated { who: beneficiary, source, schedule });
+            Ok(())
+        }
+
+        /// Terminate schedule `index` of `beneficiary`: what has vested so far is paid out, the
+        /// unvested remainder goes to the treasury pot. Restricted to VestingOrigin.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::terminate_vesting())]
+        pub fn terminate_vesting(
+            origin: OriginFor<T>,
+            beneficiary: <T::Lookup as StaticLookup>::Source,
+            index: u32,
+        ) -> DispatchResult {
+            T::VestingOrigin::ensure_origin(origin)?;
+            let who = T::Lookup::lookup(beneficiary)?;
+
+            let mut schedules = VestingSchedules::<T>::get(&who);
+            ensure!((index as usize) < schedules.len(), Error::<T>::NotVesting);
+            let schedule = schedules.remove(index as usize);
+
+            let paid = schedule.claimable_at(frame_system::Pallet::<T>::block_number());
+            let returned = schedule.remaining().saturating_sub(paid);
+            let escrow = Self::vesting_account();
+            Self::transfer_tcc(&escrow, &who, paid)?;
+            Self::transfer_tcc(&escrow, &Self::pot_account(AllocationPot::Treasury), returned)?;
+
+            Self::store_vesting_schedules(&who, schedules);
+            Self::deposit_event(Event::VestingTerminated { who, index, paid, returned });
+            Ok(())
+        }
     }
 
     // Public helper functions usable by other pallets/runtimes
@@ -260,52 +816,234 @@ pub mod pallet {
             AssetInstantiated::<T>::get()
         }
 
-        /// Query balance of account for $TCC using pallet-assets storage.
-        pub fn balance_of(_who: &T::AccountId) -> T::Balance {
-            // TODO: Wire this properly with pallet-assets in runtime
-            // let asset_id = T::TccAssetId::get();
-            // pallet_assets::Pallet::<Runtime>::balance(asset_id, who)
-            T::Balance::default()
+        /// Query balance of account for $TCC.
+        pub fn balance_of(who: &T::AccountId) -> T::Balance {
+            T::Assets::balance(T::TccAssetId::get(), who)
         }
 
-        /// Query total supply of $TCC via pallet-assets::Asset details if possible.
+        /// Query total supply of $TCC.
         pub fn total_supply() -> T::Balance {
-            // Use cached if present, otherwise try to read pallet-assets' Asset details
-            let cached = CachedTotalSupply::<T>::get();
-            if cached != T::Balance::from(0u32) {
-                return cached;
+            T::Assets::total_issuance(T::TccAssetId::get())
+        }
+
+        /// Keyless owner, issuer, admin and freezer of the $TCC asset
+        pub fn asset_account() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"asset")
+        }
+
+        /// Account holding the allocation `pot`
+        pub fn pot_account(pot: AllocationPot) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(pot)
+        }
+
+        /// Current $TCC balance of every allocation pot
+        pub fn allocation_balances() -> Vec<(AllocationPot, T::AccountId, T::Balance)> {
+            AllocationPot::ALL
+                .iter()
+                .map(|pot| {
+                    let account = Self::pot_account(*pot);
+                    let balance = Self::balance_of(&account);
+                    (*pot, account, balance)
+                })
+                .collect()
+        }
+
+        /// Account escrowing the $TCC of all vesting schedules
+        pub fn vesting_account() -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(b"vest")
+        }
+
+        /// Vested but unclaimed $TCC of `who` at the current block
+        pub fn vested_balance(who: &T::AccountId) -> T::Balance {
+            let now = frame_system::Pallet::<T>::block_number();
+            VestingSchedules::<T>::get(who)
+                .iter()
+                .fold(Zero::zero(), |acc: T::Balance, s| acc.saturating_add(s.claimable_at(now)))
+        }
+
+        /// Validate `schedule`, escrow its total from the `source` pot and store it for `who`.
+        pub fn add_vesting_schedule(who: &T::AccountId, source: AllocationPot, schedule: VestingScheduleOf<T>) -> DispatchResult {
+            ensure!(schedule.is_valid(), Error::<T>::InvalidVestingSchedule);
+            let mut schedules = VestingSchedules::<T>::get(who);
+            schedules.try_push(schedule.clone()).map_err(|_| Error::<T>::TooManyVestingSchedules)?;
+
+            Self::transfer_tcc(&Self::pot_account(source), &Self::vesting_account(), schedule.total)?;
+            VestingSchedules::<T>::insert(who, schedules);
+            Ok(())
+        }
+
+        fn do_vest(who: &T::AccountId) -> DispatchResult {
+            let mut schedules = VestingSchedules::<T>::get(who);
+            ensure!(!schedules.is_empty(), Error::<T>::NotVesting);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut amount: T::Balance = Zero::zero();
+            for schedule in schedules.iter_mut() {
+                let claimable = schedule.claimable_at(now);
+                schedule.claimed = schedule.claimed.saturating_add(claimable);
+                amount = amount.saturating_add(claimable);
+            }
+            schedules.retain(|s| !s.remaining().is_zero());
+
+            Self::transfer_tcc(&Self::vesting_account(), who, amount)?;
+            Self::store_vesting_schedules(who, schedules);
+            if !amount.is_zero() {
+                Self::deposit_event(Event::Vested { who: who.clone(), amount });
+            }
+            Ok(())
+        }
+
+        /// `location` in the current XCM version, if it is outside this chain
+        fn foreign_location(location: VersionedLocation) -> Result<Location, DispatchError> {
+            let location = Location::try_from(location).map_err(|_| Error::<T>::NotForeignAsset)?;
+            ensure!(location.parent_count() > 0, Error::<T>::NotForeignAsset);
+            Ok(location)
+        }
+
+        fn store_vesting_schedules(who: &T::AccountId, schedules: VestingSchedulesOf<T>) {
+            if schedules.is_empty() {
+                VestingSchedules::<T>::remove(who);
+            } else {
+                VestingSchedules::<T>::insert(who, schedules);
             }
+        }
+
+        fn transfer_tcc(from: &T::AccountId, to: &T::AccountId, amount: T::Balance) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            Self::ensure_not_paused(TccOperation::Transfer)?;
+            T::Assets::transfer(T::TccAssetId::get(), from, to, amount, Preservation::Expendable)
+                .map(|_| ())
+                .map_err(|_| Error::<T>::TransferFailed.into())
+        }
 
-            // Fallback: read from pallet-assets storage - Asset metadata tracks supply as `supply`.
-            // Accessing internal storage types is somewhat brittle across versions; use pallet API if available.
-            // pallet_assets::Pallet::<T>::total_issuance(...) does not exist in all versions, so try to read Accounts map sum is expensive.
-            // We'll return cached value (zero) if not set; upgrade this to a robust read if needed.
-            cached
+        /// $TCC of `who` frozen by `freeze_account` (zero if not frozen)
+        pub fn account_frozen_balance(who: &T::AccountId) -> T::Balance {
+            <TccOf<T> as InspectFreeze<_>>::balance_frozen(&FreezeReason::AccountFrozen.into(), who)
+        }
+
+        /// Active pause, if any (one whose `until` block is reached counts as lifted)
+        pub fn pause_status() -> Option<PauseStatus<BlockNumberFor<T>>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            PauseState::<T>::get().filter(|status| status.until.is_none_or(|until| now < until))
+        }
+
+        /// True if `op` is currently paused
+        pub fn is_paused(op: TccOperation) -> bool {
+            Self::pause_status().is_some_and(|status| status.paused.contains(op))
+        }
+
+        /// Fail with `Paused` if `op` is currently paused.
+        pub fn ensure_not_paused(op: TccOperation) -> DispatchResult {
+            ensure!(!Self::is_paused(op), Error::<T>::Paused);
+            Ok(())
+        }
+
+        /// Current mint budget epoch index
+        pub fn current_mint_epoch() -> BlockNumberFor<T> {
+            let now = frame_system::Pallet::<T>::block_number();
+            now.checked_div(&T::MintEpochLength::get()).unwrap_or_default()
+        }
+
+        /// Charge `amount` to the current epoch of `role`, failing above its budget.
+        fn charge_mint_budget(role: T::MintRole, amount: T::Balance) -> Result<BlockNumberFor<T>, DispatchError> {
+            let current = Self::current_mint_epoch();
+            let (epoch, minted) = EpochMinted::<T>::get(role);
+            let used = if epoch == current { minted } else { Zero::zero() };
+            let used = used.checked_add(&amount).ok_or(Error::<T>::MintBudgetExceeded)?;
+            if let Some(budget) = MintBudgets::<T>::get(role) {
+                ensure!(used <= budget, Error::<T>::MintBudgetExceeded);
+            }
+
+            EpochMinted::<T>::insert(role, (current, used));
+            Ok(current)
+        }
+
+        fn do_mint(to: T::AccountId, amount: T::Balance) -> DispatchResult {
+            T::Assets::mint_into(T::TccAssetId::get(), &to, amount).map_err(|_| Error::<T>::AssetOperationFailed)?;
+            Self::deposit_event(Event::Minted { to, amount });
+            Ok(())
+        }
+
+        fn ensure_within_cap(amount: T::Balance) -> DispatchResult {
+            if let Some(cap) = MaxSupply::<T>::get() {
+                let supply = Self::total_supply().checked_add(&amount).ok_or(Error::<T>::MaxSupplyExceeded)?;
+                ensure!(supply <= cap, Error::<T>::MaxSupplyExceeded);
+            }
+            Ok(())
+        }
+
+        /// Store `freezes` of `who`, keeping `FrozenBalances` at the largest freeze.
+        pub(crate) fn update_freezes(who: &T::AccountId, freezes: FreezesOf<T>) {
+            match freezes.iter().map(|f| f.amount).max() {
+                Some(frozen) => {
+                    FrozenBalances::<T>::insert(who, frozen);
+                    Freezes::<T>::insert(who, freezes);
+                }
+                None => {
+                    FrozenBalances::<T>::remove(who);
+                    Freezes::<T>::remove(who);
+                }
+            }
         }
     }
 
-    // Optional genesis config to mark asset as instantiated or pre-seed cache
+    // Optional genesis config creating the asset and crediting the allocation pots
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
+        /// Mark $TCC as instantiated; the asset itself must be created by the `Assets` genesis.
         pub instantiate_asset: bool,
-        pub cached_total: BalanceOf<T>,
-        // Note: creating asset at genesis via pallet-assets requires wiring pallet-assets genesis config.
+        /// Create $TCC at genesis instead, owned by the keyless `asset_account`. The asset is
+        /// created sufficient so the pot accounts need no native balance.
+        pub create_asset: bool,
+        /// Minimum balance of the asset created through `create_asset`
+        pub min_balance: BalanceOf<T>,
+        pub max_supply: Option<BalanceOf<T>>,
+        /// Amounts minted to the allocation pots (e.g. `AllocationPot::split(total)`)
+        pub allocations: Vec<(AllocationPot, BalanceOf<T>)>,
+        /// Vesting schedules funded from the ops/team pot
+        pub vesting: Vec<GenesisVestingOf<T>>,
         pub _phantom: sp_std::marker::PhantomData<T>,
     }
 
     #[cfg(feature = "std")]
     impl<T: Config> Default for GenesisConfig<T> {
         fn default() -> Self {
-            Self { instantiate_asset: false, cached_total: BalanceOf::<T>::from(0u32), _phantom: Default::default() }
+            Self {
+                instantiate_asset: false,
+                create_asset: false,
+                min_balance: 1u32.into(),
+                max_supply: None,
+                allocations: Vec::new(),
+                vesting: Vec::new(),
+                _phantom: Default::default(),
+            }
         }
     }
 
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            if self.instantiate_asset {
+            let asset_id = T::TccAssetId::get();
+            if self.create_asset {
+                T::Assets::create(asset_id, Pallet::<T>::asset_account(), true, self.min_balance)
+                    .expect("TCC asset creatable at genesis");
+                AssetInstantiated::<T>::put(true);
+            } else if self.instantiate_asset {
+                assert!(T::Assets::asset_exists(asset_id), "TCC asset must exist in Assets genesis");
                 AssetInstantiated::<T>::put(true);
-                CachedTotalSupply::<T>::put(self.cached_total);
+            }
+
+            MaxSupply::<T>::set(self.max_supply);
+            for (pot, amount) in &self.allocations {
+                assert!(AssetInstantiated::<T>::get(), "allocations require the TCC asset");
+                Pallet::<T>::ensure_within_cap(*amount).expect("genesis allocations exceed MaxSupply");
+                T::Assets::mint_into(asset_id, &Pallet::<T>::pot_account(*pot), *amount).expect("allocation mintable");
+            }
+            for (who, total, start, cliff, duration) in &self.vesting {
+                let schedule = VestingSchedule::new(*total, *start, *cliff, *duration);
+                Pallet::<T>::add_vesting_schedule(who, AllocationPot::OpsTeam, schedule).expect("genesis vesting schedule fundable");
             }
         }
     }
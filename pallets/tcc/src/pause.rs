@@ -0,0 +1,50 @@
+//! Emergency pause of $TCC operations.
+//!
+//! `PauseOrigin` can halt transfers, minting and/or burning, optionally until a given block.
+//! While transfers are paused every $TCC balance is reported frozen to pallet-assets, so direct
+//! asset transfers (and burns) fail as well; pallet-tcc calls and `TccOf` fail with `Paused`.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// A $TCC operation that can be paused
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum TccOperation {
+    Transfer,
+    Mint,
+    Burn,
+}
+
+/// Operations halted by a pause
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct PausedOperations {
+    pub transfers: bool,
+    pub minting: bool,
+    pub burning: bool,
+}
+
+impl PausedOperations {
+    pub const ALL: PausedOperations = PausedOperations { transfers: true, minting: true, burning: true };
+
+    pub fn is_empty(&self) -> bool {
+        !(self.transfers || self.minting || self.burning)
+    }
+
+    pub fn contains(&self, op: TccOperation) -> bool {
+        match op {
+            TccOperation::Transfer => self.transfers,
+            TccOperation::Mint => self.minting,
+            TccOperation::Burn => self.burning,
+        }
+    }
+}
+
+/// Active pause; lifted automatically at block `until` if set
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct PauseStatus<BlockNumber> {
+    pub paused: PausedOperations,
+    pub until: Option<BlockNumber>,
+}
@@ -0,0 +1,757 @@
+//! Unit tests for pallet-tcc using the per-pallet mock runtime in `mock.rs`.
+
+#![cfg(test)]
+
+use crate::{mock::*, Error};
+use frame_support::{assert_noop, assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+
+const ADMIN: AccountId = 1;
+const ALICE: AccountId = 2;
+const BOB: AccountId = 3;
+
+fn instantiate() {
+    assert_ok!(Tcc::instantiate_asset(RawOrigin::Root.into(), 1, true));
+}
+
+#[test]
+fn instantiate_creates_the_asset_once() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Tcc::instantiate_asset(RawOrigin::Signed(ADMIN).into(), 1, true), sp_runtime::DispatchError::BadOrigin);
+        assert!(!Tcc::asset_exists());
+
+        instantiate();
+        assert!(Tcc::asset_exists());
+        assert!(Assets::asset_exists(TCC));
+        assert_eq!(Assets::minimum_balance(TCC), 1);
+        assert_noop!(Tcc::instantiate_asset(RawOrigin::Root.into(), 1, true), Error::<Test>::AlreadyInstantiated);
+    });
+}
+
+#[test]
+fn the_asset_is_administered_by_the_keyless_pallet_account() {
+    use crate::AllocationPot;
+    use frame_support::traits::fungibles::roles::Inspect as _;
+
+    new_test_ext().execute_with(|| {
+        instantiate();
+        let account = Tcc::asset_account();
+        assert_eq!(Assets::owner(TCC), Some(account));
+        assert_eq!(Assets::issuer(TCC), Some(account));
+        assert_eq!(Assets::admin(TCC), Some(account));
+        assert_eq!(Assets::freezer(TCC), Some(account));
+        assert!(AllocationPot::ALL.iter().all(|pot| Tcc::pot_account(*pot) != account));
+    });
+
+    new_test_ext_with(crate::GenesisConfig { create_asset: true, ..Default::default() }).execute_with(|| {
+        assert_eq!(Assets::admin(TCC), Some(Tcc::asset_account()));
+    });
+}
+
+#[test]
+fn mint_transfer_and_burn_move_real_balances() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Tcc::mint(RawOrigin::Root.into(), ALICE, 100), Error::<Test>::NotInstantiated);
+        instantiate();
+
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 100));
+        assert_eq!(Tcc::balance_of(&ALICE), 100);
+        assert_eq!(Assets::balance(TCC, ALICE), 100);
+        assert_eq!(Tcc::total_supply(), 100);
+
+        assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 30));
+        assert_eq!(Tcc::balance_of(&ALICE), 70);
+        assert_eq!(Tcc::balance_of(&BOB), 30);
+        assert_noop!(Tcc::transfer(RawOrigin::Signed(BOB).into(), ALICE, 31), Error::<Test>::TransferFailed);
+
+        assert_ok!(Tcc::burn(RawOrigin::Root.into(), ALICE, 20));
+        assert_eq!(Tcc::balance_of(&ALICE), 50);
+        assert_eq!(Tcc::total_supply(), 80);
+        assert_noop!(Tcc::burn(RawOrigin::Root.into(), BOB, 31), Error::<Test>::AssetOperationFailed);
+        assert_noop!(Tcc::mint(RawOrigin::Signed(ALICE).into(), ALICE, 1), sp_runtime::DispatchError::BadOrigin);
+    });
+}
+
+mod tcc_fungible {
+    use super::*;
+    use crate::TccOf;
+    use frame_support::traits::{
+        fungible::{Inspect as _, InspectFreeze, InspectHold, Mutate as _, MutateFreeze, MutateHold},
+        tokens::{Fortitude, Precision, Preservation},
+    };
+
+    fn funded() {
+        instantiate();
+        assert_ok!(TccOf::<Test>::mint_into(&ALICE, 100));
+    }
+
+    #[test]
+    fn adapter_reads_and_moves_the_tcc_asset() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_eq!(TccOf::<Test>::balance(&ALICE), 100);
+            assert_eq!(TccOf::<Test>::total_issuance(), 100);
+            assert_eq!(TccOf::<Test>::minimum_balance(), 1);
+
+            assert_ok!(TccOf::<Test>::transfer(&ALICE, &BOB, 40, Preservation::Preserve));
+            assert_eq!(Tcc::balance_of(&BOB), 40);
+            assert_ok!(TccOf::<Test>::burn_from(&BOB, 10, Preservation::Expendable, Precision::Exact, Fortitude::Polite));
+            assert_eq!(Tcc::total_supply(), 90);
+        });
+    }
+
+    #[test]
+    fn holds_take_funds_out_of_the_free_balance() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_ok!(TccOf::<Test>::hold(&TestReason::Escrow, &ALICE, 30));
+            assert_eq!(TccOf::<Test>::balance(&ALICE), 70);
+            assert_eq!(TccOf::<Test>::total_balance(&ALICE), 100);
+            assert_eq!(TccOf::<Test>::balance_on_hold(&TestReason::Escrow, &ALICE), 30);
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 71), Error::<Test>::TransferFailed);
+
+            assert_ok!(TccOf::<Test>::transfer_on_hold(
+                &TestReason::Escrow,
+                &ALICE,
+                &BOB,
+                10,
+                Precision::Exact,
+                frame_support::traits::tokens::Restriction::Free,
+                Fortitude::Polite,
+            ));
+            assert_eq!(Tcc::balance_of(&BOB), 10);
+            assert_ok!(TccOf::<Test>::release(&TestReason::Escrow, &ALICE, 20, Precision::Exact));
+            assert_eq!(TccOf::<Test>::balance(&ALICE), 90);
+            assert_eq!(TccOf::<Test>::total_balance_on_hold(&ALICE), 0);
+            assert!(crate::Holds::<Test>::get(ALICE).is_empty());
+        });
+    }
+
+    #[test]
+    fn freezes_keep_funds_in_place() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_ok!(TccOf::<Test>::set_freeze(&TestReason::Staking, &ALICE, 60));
+            assert_ok!(TccOf::<Test>::extend_freeze(&TestReason::Escrow, &ALICE, 20));
+            assert_eq!(TccOf::<Test>::balance_frozen(&TestReason::Staking, &ALICE), 60);
+            assert_eq!(TccOf::<Test>::reducible_balance(&ALICE, Preservation::Expendable, Fortitude::Polite), 40);
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 41), Error::<Test>::TransferFailed);
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 40));
+
+            assert_ok!(TccOf::<Test>::thaw(&TestReason::Staking, &ALICE));
+            assert_eq!(TccOf::<Test>::reducible_balance(&ALICE, Preservation::Expendable, Fortitude::Polite), 40);
+            assert_ok!(TccOf::<Test>::thaw(&TestReason::Escrow, &ALICE));
+            assert!(crate::FrozenBalances::<Test>::get(ALICE).is_none());
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 60));
+        });
+    }
+}
+
+#[test]
+fn mint_respects_the_governance_supply_cap() {
+    new_test_ext().execute_with(|| {
+        instantiate();
+        assert_noop!(Tcc::set_max_supply(RawOrigin::Signed(ADMIN).into(), Some(100)), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(Tcc::set_max_supply(RawOrigin::Root.into(), Some(100)));
+        assert_eq!(Tcc::max_supply(), Some(100));
+
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 60));
+        assert_noop!(Tcc::mint(RawOrigin::Root.into(), ALICE, 41), Error::<Test>::MaxSupplyExceeded);
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), BOB, 40));
+        assert_noop!(Tcc::set_max_supply(RawOrigin::Root.into(), Some(99)), Error::<Test>::MaxSupplyBelowIssuance);
+
+        // burning frees room under the cap; lifting the cap removes it
+        assert_ok!(Tcc::burn(RawOrigin::Root.into(), BOB, 10));
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), BOB, 10));
+        assert_ok!(Tcc::set_max_supply(RawOrigin::Root.into(), None));
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), BOB, 1_000));
+    });
+}
+
+#[test]
+fn genesis_creates_the_asset_and_funds_allocation_pots() {
+    use crate::AllocationPot;
+
+    let total: Balance = 100_000_000;
+    let split = AllocationPot::split(total);
+    assert_eq!(split.iter().map(|(_, b)| b).sum::<Balance>(), total);
+    assert_eq!(AllocationPot::split(101u64)[0], (AllocationPot::CommunityRewards, 51));
+
+    let genesis = crate::GenesisConfig {
+        create_asset: true,
+        max_supply: Some(total),
+        allocations: split,
+        ..Default::default()
+    };
+    new_test_ext_with(genesis).execute_with(|| {
+        assert!(Tcc::asset_exists());
+        assert_eq!(Tcc::total_supply(), total);
+        assert_eq!(Tcc::max_supply(), Some(total));
+
+        let balances = Tcc::allocation_balances();
+        let expected = [
+            (AllocationPot::CommunityRewards, 50_000_000),
+            (AllocationPot::Treasury, 25_000_000),
+            (AllocationPot::OpsTeam, 15_000_000),
+            (AllocationPot::Airdrop, 10_000_000),
+        ];
+        for ((pot, account, balance), (expected_pot, expected_balance)) in balances.into_iter().zip(expected) {
+            assert_eq!((pot, balance), (expected_pot, expected_balance));
+            assert_eq!(account, Tcc::pot_account(pot));
+        }
+        assert_ne!(Tcc::pot_account(AllocationPot::Treasury), Tcc::pot_account(AllocationPot::Airdrop));
+        assert_noop!(Tcc::mint(RawOrigin::Root.into(), ALICE, 1), Error::<Test>::MaxSupplyExceeded);
+    });
+}
+
+mod vesting {
+    use super::*;
+    use crate::{AllocationPot, Event, VestingSchedule};
+
+    const TREASURY_FUNDS: Balance = 1_000;
+    const OPS_FUNDS: Balance = 10_000;
+
+    fn ext() -> sp_io::TestExternalities {
+        new_test_ext_with(crate::GenesisConfig {
+            create_asset: true,
+            allocations: vec![(AllocationPot::Treasury, TREASURY_FUNDS), (AllocationPot::OpsTeam, OPS_FUNDS)],
+            ..Default::default()
+        })
+    }
+
+    /// 1_000 TCC vesting over blocks 10..110 with a cliff at 30
+    fn create() {
+        assert_ok!(Tcc::create_vesting(RawOrigin::Root.into(), ALICE, AllocationPot::OpsTeam, 1_000, 10, 30, 100));
+    }
+
+    #[test]
+    fn schedule_vests_linearly_after_the_cliff() {
+        let schedule = VestingSchedule::<Balance, u64>::new(1_000, 10, 30, 100);
+        assert_eq!(schedule.vested_at(29), 0);
+        assert_eq!(schedule.vested_at(30), 200);
+        assert_eq!(schedule.vested_at(60), 500);
+        assert_eq!(schedule.vested_at(110), 1_000);
+        assert!(!VestingSchedule::<Balance, u64>::new(1_000, 10, 9, 100).is_valid());
+        assert!(!VestingSchedule::<Balance, u64>::new(1_000, 10, 111, 100).is_valid());
+        assert!(!VestingSchedule::<Balance, u64>::new(1_000, 10, 10, 0).is_valid());
+    }
+
+    #[test]
+    fn create_vesting_escrows_from_the_source_pot() {
+        ext().execute_with(|| {
+            assert_noop!(
+                Tcc::create_vesting(RawOrigin::Signed(ADMIN).into(), ALICE, AllocationPot::OpsTeam, 1_000, 10, 30, 100),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Tcc::create_vesting(RawOrigin::Root.into(), ALICE, AllocationPot::OpsTeam, 0, 10, 30, 100),
+                Error::<Test>::InvalidVestingSchedule
+            );
+            assert_noop!(
+                Tcc::create_vesting(RawOrigin::Root.into(), ALICE, AllocationPot::Airdrop, 1_000, 10, 30, 100),
+                Error::<Test>::TransferFailed
+            );
+
+            create();
+            assert_eq!(Tcc::balance_of(&Tcc::pot_account(AllocationPot::OpsTeam)), OPS_FUNDS - 1_000);
+            assert_eq!(Tcc::balance_of(&Tcc::vesting_account()), 1_000);
+            assert_eq!(Tcc::vesting_schedules(ALICE).len(), 1);
+            System::assert_last_event(
+                Event::<Test>::VestingCreated {
+                    who: ALICE,
+                    source: AllocationPot::OpsTeam,
+                    schedule: VestingSchedule::new(1_000, 10, 30, 100),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn vest_and_vest_other_pay_out_what_has_unlocked() {
+        ext().execute_with(|| {
+            assert_noop!(Tcc::vest(RawOrigin::Signed(ALICE).into()), Error::<Test>::NotVesting);
+            create();
+
+            System::set_block_number(29);
+            assert_ok!(Tcc::vest(RawOrigin::Signed(ALICE).into()));
+            assert_eq!(Tcc::balance_of(&ALICE), 0);
+
+            System::set_block_number(60);
+            assert_eq!(Tcc::vested_balance(&ALICE), 500);
+            assert_ok!(Tcc::vest(RawOrigin::Signed(ALICE).into()));
+            assert_eq!(Tcc::balance_of(&ALICE), 500);
+            System::assert_last_event(Event::<Test>::Vested { who: ALICE, amount: 500 }.into());
+
+            System::set_block_number(200);
+            assert_ok!(Tcc::vest_other(RawOrigin::Signed(BOB).into(), ALICE));
+            assert_eq!(Tcc::balance_of(&ALICE), 1_000);
+            assert_eq!(Tcc::balance_of(&Tcc::vesting_account()), 0);
+            assert!(!crate::VestingSchedules::<Test>::contains_key(ALICE));
+        });
+    }
+
+    #[test]
+    fn termination_returns_unvested_tokens_to_the_treasury() {
+        ext().execute_with(|| {
+            create();
+            System::set_block_number(40);
+            assert_ok!(Tcc::vest(RawOrigin::Signed(ALICE).into()));
+
+            System::set_block_number(60);
+            assert_noop!(Tcc::terminate_vesting(RawOrigin::Signed(ADMIN).into(), ALICE, 0), sp_runtime::DispatchError::BadOrigin);
+            assert_noop!(Tcc::terminate_vesting(RawOrigin::Root.into(), ALICE, 1), Error::<Test>::NotVesting);
+            assert_ok!(Tcc::terminate_vesting(RawOrigin::Root.into(), ALICE, 0));
+
+            assert_eq!(Tcc::balance_of(&ALICE), 500);
+            assert_eq!(Tcc::balance_of(&Tcc::pot_account(AllocationPot::Treasury)), TREASURY_FUNDS + 500);
+            assert_eq!(Tcc::balance_of(&Tcc::vesting_account()), 0);
+            assert!(Tcc::vesting_schedules(ALICE).is_empty());
+            System::assert_last_event(Event::<Test>::VestingTerminated { who: ALICE, index: 0, paid: 200, returned: 500 }.into());
+        });
+    }
+
+    #[test]
+    fn genesis_schedules_are_funded_from_the_ops_pot() {
+        new_test_ext_with(crate::GenesisConfig {
+            create_asset: true,
+            allocations: vec![(AllocationPot::OpsTeam, OPS_FUNDS)],
+            vesting: vec![(ALICE, 4_000, 0, 10, 40), (BOB, 6_000, 0, 0, 60)],
+            ..Default::default()
+        })
+        .execute_with(|| {
+            assert_eq!(Tcc::balance_of(&Tcc::pot_account(AllocationPot::OpsTeam)), 0);
+            assert_eq!(Tcc::balance_of(&Tcc::vesting_account()), OPS_FUNDS);
+
+            System::set_block_number(20);
+            assert_ok!(Tcc::vest(RawOrigin::Signed(ALICE).into()));
+            assert_ok!(Tcc::vest(RawOrigin::Signed(BOB).into()));
+            assert_eq!(Tcc::balance_of(&ALICE), 2_000);
+            assert_eq!(Tcc::balance_of(&BOB), 2_000);
+        });
+    }
+}
+
+mod mint_safeguards {
+    use super::*;
+    use crate::Event;
+
+    #[test]
+    fn each_role_has_its_own_epoch_budget() {
+        new_test_ext().execute_with(|| {
+            instantiate();
+            assert_noop!(
+                Tcc::set_mint_budget(RawOrigin::Signed(TREASURER).into(), TestMintRole::Treasury, Some(500)),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Tcc::set_mint_budget(RawOrigin::Root.into(), TestMintRole::Treasury, Some(500)));
+            System::assert_last_event(Event::<Test>::MintBudgetSet { role: TestMintRole::Treasury, budget: Some(500) }.into());
+
+            assert_ok!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 300));
+            assert_ok!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 200));
+            assert_noop!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 1), Error::<Test>::MintBudgetExceeded);
+            // governance has no budget set
+            assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 5_000));
+
+            System::set_block_number(100);
+            assert_ok!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 500));
+            assert_eq!(Tcc::balance_of(&ALICE), 6_000);
+        });
+    }
+
+    #[test]
+    fn large_mints_are_queued_until_the_delay_passes() {
+        new_test_ext().execute_with(|| {
+            instantiate();
+            assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 10_001));
+            System::assert_last_event(
+                Event::<Test>::MintQueued { id: 0, role: TestMintRole::Governance, to: ALICE, amount: 10_001, executable_at: 11 }
+                    .into(),
+            );
+            assert_eq!(Tcc::balance_of(&ALICE), 0);
+
+            System::set_block_number(10);
+            assert_noop!(Tcc::execute_mint(RawOrigin::Signed(BOB).into(), 0), Error::<Test>::MintTimelocked);
+            System::set_block_number(11);
+            assert_ok!(Tcc::execute_mint(RawOrigin::Signed(BOB).into(), 0));
+            System::assert_has_event(Event::<Test>::MintExecuted { id: 0 }.into());
+            System::assert_last_event(Event::<Test>::Minted { to: ALICE, amount: 10_001 }.into());
+            assert_eq!(Tcc::balance_of(&ALICE), 10_001);
+            assert_noop!(Tcc::execute_mint(RawOrigin::Signed(BOB).into(), 0), Error::<Test>::NoPendingMint);
+        });
+    }
+
+    #[test]
+    fn guardian_cancels_queued_mints_and_refunds_the_budget() {
+        new_test_ext().execute_with(|| {
+            instantiate();
+            assert_ok!(Tcc::set_mint_budget(RawOrigin::Root.into(), TestMintRole::Treasury, Some(15_000)));
+            assert_ok!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 15_000));
+            assert_noop!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 1), Error::<Test>::MintBudgetExceeded);
+
+            assert_noop!(Tcc::cancel_mint(RawOrigin::Signed(TREASURER).into(), 0), sp_runtime::DispatchError::BadOrigin);
+            assert_ok!(Tcc::cancel_mint(RawOrigin::Root.into(), 0));
+            System::assert_last_event(Event::<Test>::MintCancelled { id: 0 }.into());
+            assert!(Tcc::pending_mint(0).is_none());
+
+            System::set_block_number(20);
+            assert_noop!(Tcc::execute_mint(RawOrigin::Signed(BOB).into(), 0), Error::<Test>::NoPendingMint);
+            assert_eq!(Tcc::total_supply(), 0);
+            assert_ok!(Tcc::mint(RawOrigin::Signed(TREASURER).into(), ALICE, 1));
+        });
+    }
+}
+
+mod pause {
+    use super::*;
+    use crate::{Event, PauseStatus, PausedOperations, TccOf};
+    use frame_support::traits::{
+        fungible::{Mutate as _, MutateHold},
+        tokens::Preservation,
+        Hooks,
+    };
+
+    const TRANSFERS: PausedOperations = PausedOperations { transfers: true, minting: false, burning: false };
+
+    fn funded() {
+        instantiate();
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 100));
+    }
+
+    #[test]
+    fn pause_all_halts_transfers_minting_and_burning() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_noop!(
+                Tcc::pause(RawOrigin::Signed(ADMIN).into(), PausedOperations::ALL, None),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_noop!(Tcc::pause(RawOrigin::Root.into(), PausedOperations::default(), None), Error::<Test>::InvalidPause);
+            assert_noop!(Tcc::unpause(RawOrigin::Root.into()), Error::<Test>::NotPaused);
+
+            assert_ok!(Tcc::pause(RawOrigin::Root.into(), PausedOperations::ALL, None));
+            System::assert_last_event(Event::<Test>::Paused { operations: PausedOperations::ALL, until: None }.into());
+            assert_eq!(Tcc::pause_status(), Some(PauseStatus { paused: PausedOperations::ALL, until: None }));
+
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 10), Error::<Test>::Paused);
+            assert_noop!(Tcc::mint(RawOrigin::Root.into(), ALICE, 10), Error::<Test>::Paused);
+            assert_noop!(Tcc::burn(RawOrigin::Root.into(), ALICE, 10), Error::<Test>::Paused);
+            // the underlying asset is frozen as well
+            assert!(Assets::transfer(RawOrigin::Signed(ALICE).into(), TCC, BOB, 10).is_err());
+
+            assert_ok!(Tcc::unpause(RawOrigin::Root.into()));
+            System::assert_last_event(Event::<Test>::Unpaused.into());
+            assert_eq!(Tcc::pause_status(), None);
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 10));
+            assert_ok!(Assets::transfer(RawOrigin::Signed(ALICE).into(), TCC, BOB, 10));
+        });
+    }
+
+    #[test]
+    fn partial_pause_only_halts_the_selected_operations() {
+        new_test_ext().execute_with(|| {
+            funded();
+            let minting = PausedOperations { minting: true, ..Default::default() };
+            assert_ok!(Tcc::pause(RawOrigin::Root.into(), minting, None));
+
+            assert_noop!(Tcc::mint(RawOrigin::Root.into(), ALICE, 10), Error::<Test>::Paused);
+            assert_noop!(TccOf::<Test>::mint_into(&ALICE, 10), Error::<Test>::Paused);
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 10));
+            assert_ok!(Tcc::burn(RawOrigin::Root.into(), BOB, 10));
+        });
+    }
+
+    #[test]
+    fn tcc_payouts_and_reservations_fail_while_transfers_are_paused() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_ok!(Tcc::pause(RawOrigin::Root.into(), TRANSFERS, None));
+
+            assert_noop!(TccOf::<Test>::transfer(&ALICE, &BOB, 10, Preservation::Expendable), Error::<Test>::Paused);
+            assert_noop!(TccOf::<Test>::hold(&TestReason::Escrow, &ALICE, 10), Error::<Test>::Paused);
+            assert_ok!(Tcc::mint(RawOrigin::Root.into(), BOB, 10));
+        });
+    }
+
+    #[test]
+    fn pause_is_lifted_at_the_auto_unpause_block() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_noop!(Tcc::pause(RawOrigin::Root.into(), TRANSFERS, Some(1)), Error::<Test>::InvalidPause);
+            assert_ok!(Tcc::pause(RawOrigin::Root.into(), TRANSFERS, Some(5)));
+
+            System::set_block_number(4);
+            Tcc::on_initialize(4);
+            assert!(Tcc::pause_status().is_some());
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 10), Error::<Test>::Paused);
+
+            System::set_block_number(5);
+            Tcc::on_initialize(5);
+            System::assert_last_event(Event::<Test>::Unpaused.into());
+            assert!(!crate::PauseState::<Test>::exists());
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 10));
+        });
+    }
+}
+
+mod account_freeze {
+    use super::*;
+    use crate::{AccountFreeze, Event};
+
+    const CASE: [u8; 32] = [7; 32];
+
+    fn funded() {
+        instantiate();
+        assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 100));
+    }
+
+    #[test]
+    fn partial_freeze_keeps_the_amount_in_place() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_noop!(
+                Tcc::freeze_account(RawOrigin::Signed(ADMIN).into(), ALICE, Some(60), CASE),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Tcc::freeze_account(RawOrigin::Root.into(), ALICE, Some(60), CASE));
+            System::assert_last_event(Event::<Test>::AccountFrozen { who: ALICE, amount: Some(60), reason_hash: CASE }.into());
+            assert_eq!(Tcc::account_freeze(ALICE), Some(AccountFreeze { amount: Some(60), reason_hash: CASE, frozen_at: 1 }));
+            assert_eq!(Tcc::account_frozen_balance(&ALICE), 60);
+
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 41), Error::<Test>::TransferFailed);
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 40));
+            assert_eq!(Tcc::balance_of(&ALICE), 60);
+        });
+    }
+
+    #[test]
+    fn full_freeze_blocks_everything_until_thawed() {
+        new_test_ext().execute_with(|| {
+            funded();
+            assert_noop!(Tcc::thaw_account(RawOrigin::Root.into(), ALICE), Error::<Test>::NotFrozen);
+            assert_ok!(Tcc::freeze_account(RawOrigin::Root.into(), ALICE, None, CASE));
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 1), Error::<Test>::TransferFailed);
+            // newly received funds are frozen too
+            assert_ok!(Tcc::mint(RawOrigin::Root.into(), ALICE, 50));
+            assert_noop!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 1), Error::<Test>::TransferFailed);
+            assert_eq!(Tcc::balance_of(&ALICE), 150);
+
+            assert_ok!(Tcc::thaw_account(RawOrigin::Root.into(), ALICE));
+            System::assert_last_event(Event::<Test>::AccountThawed { who: ALICE }.into());
+            assert_eq!(Tcc::account_freeze(ALICE), None);
+            assert_eq!(Tcc::account_frozen_balance(&ALICE), 0);
+            assert_ok!(Tcc::transfer(RawOrigin::Signed(ALICE).into(), BOB, 150));
+        });
+    }
+}
+
+mod foreign_assets {
+    use super::*;
+    use crate::{cross_chain::ForeignAssetIds, Event, ForeignAssetLocations};
+    use frame_support::traits::{
+        fungibles::{roles::Inspect as _, Mutate as _},
+        Contains,
+    };
+    use sp_runtime::traits::MaybeEquivalence;
+    use xcm::{
+        latest::{Junction::*, Location},
+        VersionedLocation,
+    };
+
+    const USDT: u32 = 1984;
+
+    fn usdt_location() -> Location {
+        Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+    }
+
+    fn versioned(location: Location) -> Box<VersionedLocation> {
+        Box::new(location.into())
+    }
+
+    #[test]
+    fn governance_registers_foreign_assets_as_sufficient_local_assets() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                Tcc::register_foreign_asset(RawOrigin::Signed(ADMIN).into(), versioned(usdt_location()), USDT, 1),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location()), USDT, 1));
+            System::assert_last_event(Event::<Test>::ForeignAssetRegistered { location: usdt_location(), asset_id: USDT }.into());
+
+            assert!(Assets::asset_exists(USDT));
+            assert_eq!(Assets::admin(USDT), Some(Tcc::asset_account()));
+            // sufficient: a first deposit needs no native balance
+            assert_ok!(Assets::mint_into(USDT, &ALICE, 5));
+            assert_eq!(Assets::balance(USDT, ALICE), 5);
+
+            assert!(ForeignAssetIds::<Test>::contains(&usdt_location()));
+            assert_eq!(ForeignAssetIds::<Test>::convert(&usdt_location()), Some(USDT));
+            assert_eq!(ForeignAssetIds::<Test>::convert_back(&USDT), Some(usdt_location()));
+            assert_eq!(ForeignAssetIds::<Test>::convert_back(&TCC), None);
+        });
+    }
+
+    #[test]
+    fn only_new_foreign_locations_and_free_asset_ids_are_registered() {
+        new_test_ext().execute_with(|| {
+            // local assets, $TCC included, are not foreign
+            let tcc = crate::cross_chain::tcc_local_location(50, TCC.into());
+            assert_noop!(
+                Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(tcc), 8, 1),
+                Error::<Test>::NotForeignAsset
+            );
+            // $TCC's id is reserved even before the asset is instantiated
+            assert_noop!(
+                Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location()), TCC, 1),
+                Error::<Test>::ForeignAssetExists
+            );
+
+            assert_ok!(Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location()), USDT, 1));
+            assert_noop!(
+                Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location()), USDT + 1, 1),
+                Error::<Test>::ForeignAssetExists
+            );
+            let other = Location::new(1, [Parachain(2_000), GeneralIndex(1)]);
+            assert_noop!(
+                Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(other), USDT, 1),
+                Error::<Test>::ForeignAssetExists
+            );
+        });
+    }
+
+    #[test]
+    fn deregistering_stops_matching_but_keeps_the_asset() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                Tcc::deregister_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location())),
+                Error::<Test>::UnknownForeignAsset
+            );
+            assert_ok!(Tcc::register_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location()), USDT, 1));
+            assert_noop!(
+                Tcc::deregister_foreign_asset(RawOrigin::Signed(ADMIN).into(), versioned(usdt_location())),
+                sp_runtime::DispatchError::BadOrigin
+            );
+
+            assert_ok!(Tcc::deregister_foreign_asset(RawOrigin::Root.into(), versioned(usdt_location())));
+            System::assert_last_event(Event::<Test>::ForeignAssetDeregistered { location: usdt_location(), asset_id: USDT }.into());
+            assert!(!ForeignAssetIds::<Test>::contains(&usdt_location()));
+            assert!(!ForeignAssetLocations::<Test>::contains_key(USDT));
+            assert!(Assets::asset_exists(USDT));
+        });
+    }
+}
+
+mod cross_chain {
+    use crate::cross_chain::*;
+    use frame_support::{
+        parameter_types,
+        traits::{Contains, ContainsPair, Equals},
+    };
+    use xcm::latest::prelude::*;
+
+    const SIBLING: u32 = 2_000;
+
+    parameter_types! {
+        pub TccLocation: Location = tcc_local_location(50, 7);
+        pub const WhitelistedSibling: u32 = SIBLING;
+    }
+
+    type Destinations = AssetHubAndSiblings<Equals<WhitelistedSibling>>;
+
+    fn para(id: u32) -> Location {
+        Location::new(1, [Parachain(id)])
+    }
+
+    fn tcc(amount: u128) -> Asset {
+        (TccLocation::get(), amount).into()
+    }
+
+    #[test]
+    fn tcc_location_is_the_asset_hub_style_pallet_asset_location() {
+        assert_eq!(TccLocation::get(), Location::new(0, [PalletInstance(50), GeneralIndex(7)]));
+        let from_sibling = tcc_sibling_location(3_000, 50, 7);
+        assert_eq!(from_sibling, Location::new(1, [Parachain(3_000), PalletInstance(50), GeneralIndex(7)]));
+        assert_eq!(from_sibling.chain_location(), para(3_000));
+    }
+
+    #[test]
+    fn only_tcc_reserve_transfers_to_asset_hub_and_whitelisted_siblings() {
+        type Filter = TccReserveTransfers<TccLocation, Destinations>;
+        assert!(Filter::contains(&(para(ASSET_HUB_PARA_ID), vec![tcc(10)])));
+        assert!(Filter::contains(&(para(SIBLING), vec![tcc(10)])));
+
+        assert!(!Filter::contains(&(para(SIBLING + 1), vec![tcc(10)])));
+        assert!(!Filter::contains(&(Location::parent(), vec![tcc(10)])));
+        assert!(!Filter::contains(&(para(ASSET_HUB_PARA_ID), vec![])));
+        let other: Asset = (Location::new(0, [PalletInstance(50), GeneralIndex(8)]), 10u128).into();
+        assert!(!Filter::contains(&(para(ASSET_HUB_PARA_ID), vec![tcc(10), other])));
+        let relay_token: Asset = (Location::parent(), 10u128).into();
+        assert!(!Filter::contains(&(para(ASSET_HUB_PARA_ID), vec![relay_token])));
+    }
+
+    #[test]
+    fn reserves_are_trusted_only_for_their_own_assets() {
+        type Reserves = TrustedReserves<Destinations>;
+        let sibling_asset: Asset = (Location::new(1, [Parachain(SIBLING), GeneralIndex(1)]), 5u128).into();
+        assert!(Reserves::contains(&sibling_asset, &para(SIBLING)));
+        // a sibling cannot claim to be the reserve of Asset Hub assets (or of $TCC)
+        let asset_hub_asset: Asset = (Location::new(1, [Parachain(ASSET_HUB_PARA_ID), GeneralIndex(1)]), 5u128).into();
+        assert!(!Reserves::contains(&asset_hub_asset, &para(SIBLING)));
+        assert!(!Reserves::contains(&tcc(5), &para(SIBLING)));
+        // unknown chains are not trusted even for their own assets
+        let unknown: Asset = (Location::new(1, [Parachain(SIBLING + 1), GeneralIndex(1)]), 5u128).into();
+        assert!(!Reserves::contains(&unknown, &para(SIBLING + 1)));
+        // only the chain itself is a reserve, not an account or pallet on it
+        let sibling_pallet = Location::new(1, [Parachain(SIBLING), PalletInstance(50)]);
+        assert!(!Reserves::contains(&sibling_asset, &sibling_pallet));
+    }
+
+    #[test]
+    fn destinations_are_asset_hub_and_whitelisted_sibling_chains() {
+        assert!(Destinations::contains(&para(ASSET_HUB_PARA_ID)));
+        assert!(Destinations::contains(&para(SIBLING)));
+
+        assert!(!Destinations::contains(&para(SIBLING + 1)));
+        assert!(!Destinations::contains(&Location::parent()));
+        assert!(!Destinations::contains(&Location::here()));
+        // accounts and pallets on those chains, and the same para ids seen from elsewhere, are not
+        assert!(!Destinations::contains(&Location::new(1, [Parachain(ASSET_HUB_PARA_ID), PalletInstance(50)])));
+        assert!(!Destinations::contains(&Location::new(1, [Parachain(SIBLING), AccountId32 { network: None, id: [1; 32] }])));
+        assert!(!Destinations::contains(&Location::new(0, [Parachain(ASSET_HUB_PARA_ID)])));
+        assert!(!Destinations::contains(&Location::new(2, [Parachain(ASSET_HUB_PARA_ID)])));
+    }
+
+    /// Mirrors the runtime's `xcm_config`: a constant sibling whitelist, and the relay chain trusted
+    /// as reserve of its own token besides the $TCC destinations.
+    #[test]
+    fn runtime_style_config_routes_tcc_and_trusts_reserves() {
+        const WHITELISTED_SIBLINGS: &[u32] = &[SIBLING];
+
+        struct WhitelistedSiblings;
+        impl Contains<u32> for WhitelistedSiblings {
+            fn contains(para_id: &u32) -> bool {
+                WHITELISTED_SIBLINGS.contains(para_id)
+            }
+        }
+
+        parameter_types! {
+            pub RelayLocation: Location = Location::parent();
+        }
+        type TccDestinations = AssetHubAndSiblings<WhitelistedSiblings>;
+        type ReserveTransfers = TccReserveTransfers<TccLocation, TccDestinations>;
+        type IsReserve = TrustedReserves<(Equals<RelayLocation>, TccDestinations)>;
+
+        let relay_token: Asset = (Location::parent(), 5u128).into();
+        assert!(ReserveTransfers::contains(&(para(SIBLING), vec![tcc(1)])));
+        assert!(ReserveTransfers::contains(&(para(ASSET_HUB_PARA_ID), vec![tcc(1)])));
+        assert!(!ReserveTransfers::contains(&(Location::parent(), vec![tcc(1)])));
+        assert!(!ReserveTransfers::contains(&(para(ASSET_HUB_PARA_ID), vec![relay_token.clone()])));
+
+        assert!(IsReserve::contains(&relay_token, &Location::parent()));
+        assert!(!IsReserve::contains(&relay_token, &para(ASSET_HUB_PARA_ID)));
+        let usdt: Asset = (Location::new(1, [Parachain(ASSET_HUB_PARA_ID), PalletInstance(50), GeneralIndex(1984)]), 5u128).into();
+        assert!(IsReserve::contains(&usdt, &para(ASSET_HUB_PARA_ID)));
+        assert!(!IsReserve::contains(&usdt, &Location::parent()));
+        assert!(!IsReserve::contains(&usdt, &para(SIBLING)));
+    }
+}
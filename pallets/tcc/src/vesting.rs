@@ -0,0 +1,72 @@
+//! Linear vesting schedules with a cliff for $TCC.
+//!
+//! Vesting $TCC is escrowed in `Pallet::vesting_account()` when a schedule is created and paid
+//! out to the beneficiary by `vest`/`vest_other` as it unlocks.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::AtLeast32BitUnsigned,
+    Rounding, RuntimeDebug, SaturatedConversion,
+};
+
+/// `total` unlocks linearly over `duration` blocks from `start`, nothing being claimable before
+/// `cliff`. `claimed` is what the beneficiary already received.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct VestingSchedule<Balance, BlockNumber> {
+    pub total: Balance,
+    pub claimed: Balance,
+    pub start: BlockNumber,
+    pub cliff: BlockNumber,
+    pub duration: BlockNumber,
+}
+
+impl<Balance, BlockNumber> VestingSchedule<Balance, BlockNumber>
+where
+    Balance: AtLeast32BitUnsigned + Copy,
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    pub fn new(total: Balance, start: BlockNumber, cliff: BlockNumber, duration: BlockNumber) -> Self {
+        Self { total, claimed: Balance::zero(), start, cliff, duration }
+    }
+
+    /// Block at which everything is vested
+    pub fn end(&self) -> BlockNumber {
+        self.start.saturating_add(self.duration)
+    }
+
+    /// Non-empty schedule whose cliff lies within `start..=end`
+    pub fn is_valid(&self) -> bool {
+        !self.total.is_zero() && !self.duration.is_zero() && self.cliff >= self.start && self.cliff <= self.end()
+    }
+
+    /// Amount vested at block `now`, claimed or not
+    pub fn vested_at(&self, now: BlockNumber) -> Balance {
+        if now < self.cliff || now < self.start {
+            return Balance::zero();
+        }
+        if now >= self.end() {
+            return self.total;
+        }
+        let elapsed = now.saturating_sub(self.start).saturated_into::<u128>();
+        multiply_by_rational_with_rounding(
+            self.total.saturated_into::<u128>(),
+            elapsed,
+            self.duration.saturated_into::<u128>(),
+            Rounding::Down,
+        )
+        .map(|v| v.saturated_into::<Balance>())
+        .unwrap_or(Balance::zero())
+    }
+
+    /// Vested but not yet claimed at block `now`
+    pub fn claimable_at(&self, now: BlockNumber) -> Balance {
+        self.vested_at(now).saturating_sub(self.claimed)
+    }
+
+    /// Still escrowed (claimed or not, vested or not)
+    pub fn remaining(&self) -> Balance {
+        self.total.saturating_sub(self.claimed)
+    }
+}
@@ -1,6 +1,13 @@
+//! XCM configuration of the runtime.
+//!
+//! Note: `configs` is not yet compiled into the runtime (no `mod configs`), so nothing here is
+//! type-checked. The $TCC policy types it wires up (`pallet_tcc::cross_chain`) are tested in
+//! pallet-tcc, and reserve transfers with them end to end in `integration-tests/xcm`.
+
 use crate::{
-    AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+    AccountId, AllPalletsWithSystem, Assets, Balance, Balances, ParachainInfo, ParachainSystem,
+    PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, TccAssetId, WeightToFee,
+    XcmpQueue,
 };
 
 use polkadot_sdk::{
@@ -9,10 +16,11 @@ use polkadot_sdk::{
 
 use frame_support::{
     parameter_types,
-    traits::{ConstU32, Contains, Everything, Nothing},
+    traits::{ConstU32, Contains, Equals, Everything, Nothing, PalletInfoAccess},
     weights::Weight,
 };
 use frame_system::EnsureRoot;
+use pallet_tcc::cross_chain::{AssetHubAndSiblings, ForeignAssetIds, TccReserveTransfers, TrustedReserves};
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
@@ -23,13 +31,14 @@ use polkadot_sdk::{
 use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
-    DenyReserveTransferToRelayChain, EnsureXcmOrigin, FixedWeightBounds,
-    FrameTransactionalProcessor, FungibleAdapter, IsConcrete, NativeAsset, ParentIsPreset,
-    RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-    TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic,
+    AsPrefixedGeneralIndex, ConvertedConcreteId, DenyReserveTransferToRelayChain, EnsureXcmOrigin, FixedWeightBounds,
+    FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter, IsConcrete,
+    MatchedConvertedConcreteId, NoChecking, ParentIsPreset, RelayChainAsNative,
+    SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
+    SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId,
+    UsingComponents, WithComputedOrigin, WithUniqueTopic,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{traits::JustTry, XcmExecutor};
 
 parameter_types! {
     pub const RelayLocation: Location = Location::parent();
@@ -38,8 +47,31 @@ parameter_types! {
     // For the real deployment, it is recommended to set `RelayNetwork` according to the relay chain
     // and prepend `UniversalLocation` with `GlobalConsensus(RelayNetwork::get())`.
     pub UniversalLocation: InteriorLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+    /// The pallet-assets instance holding $TCC
+    pub AssetsPalletLocation: Location = PalletInstance(<Assets as PalletInfoAccess>::index() as u8).into();
+    /// Canonical location of $TCC; `(1, [Parachain(<our id>), PalletInstance(..), GeneralIndex(..)])`
+    /// from Asset Hub and siblings (see `pallet_tcc::cross_chain`)
+    pub TccLocation: Location = pallet_tcc::cross_chain::tcc_local_location(
+        <Assets as PalletInfoAccess>::index() as u8,
+        TccAssetId::get().into(),
+    );
+    pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+}
+
+/// Sibling parachains $TCC may be reserve-transferred to, besides Asset Hub. Also the only
+/// siblings trusted as reserves of their own assets. Extended by runtime upgrade.
+pub const WHITELISTED_SIBLINGS: &[u32] = &[];
+
+pub struct WhitelistedSiblings;
+impl Contains<u32> for WhitelistedSiblings {
+    fn contains(para_id: &u32) -> bool {
+        WHITELISTED_SIBLINGS.contains(para_id)
+    }
 }
 
+/// Destinations of $TCC reserve transfers
+pub type TccDestinations = AssetHubAndSiblings<WhitelistedSiblings>;
+
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -66,6 +98,54 @@ pub type LocalAssetTransactor = FungibleAdapter<
     (),
 >;
 
+/// Means for transacting $TCC. Only the $TCC asset of `Assets` matches, so other local assets
+/// cannot be moved over XCM. Withdrawals respect $TCC freezes and the transfer pause (pallet-tcc is
+/// the `Freezer` of `Assets`).
+pub type TccAssetTransactor = FungiblesAdapter<
+    // Use this fungibles implementation:
+    Assets,
+    // Only $TCC, converted to its asset id:
+    MatchedConvertedConcreteId<
+        u32,
+        Balance,
+        Equals<TccLocation>,
+        AsPrefixedGeneralIndex<AssetsPalletLocation, u32, JustTry>,
+        JustTry,
+    >,
+    // Convert an XCM Location into a local account id:
+    LocationToAccountId,
+    // Our chain's account ID type (we can't get away without mentioning it explicitly):
+    AccountId,
+    // $TCC is reserve-transferred, never teleported:
+    NoChecking,
+    CheckingAccount,
+>;
+
+/// Means for transacting foreign assets registered by governance (`Tcc::register_foreign_asset`),
+/// each held as a sufficient asset of `Assets`.
+pub type ForeignAssetTransactor = FungiblesAdapter<
+    // Use this fungibles implementation:
+    Assets,
+    // Only registered foreign assets, converted to their local asset id:
+    ConvertedConcreteId<u32, Balance, ForeignAssetIds<Runtime>, JustTry>,
+    // Convert an XCM Location into a local account id:
+    LocationToAccountId,
+    // Our chain's account ID type (we can't get away without mentioning it explicitly):
+    AccountId,
+    // Foreign assets are reserve-based, never teleported:
+    NoChecking,
+    CheckingAccount,
+>;
+
+/// Relay token, $TCC and registered foreign assets. Foreign assets are not registered
+/// automatically: anything else sent here matches no transactor and ends up in the asset trap
+/// until governance registers it.
+pub type AssetTransactors = (LocalAssetTransactor, TccAssetTransactor, ForeignAssetTransactor);
+
+/// Reserves trusted for incoming assets: the relay chain, Asset Hub and whitelisted siblings, each
+/// for its own assets only.
+pub type TrustedReserveOrigins = (Equals<RelayLocation>, TccDestinations);
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
@@ -135,9 +215,9 @@ impl xcm_executor::Config for XcmConfig {
     type XcmSender = XcmRouter;
     type XcmEventEmitter = PolkadotXcm;
     // How to withdraw and deposit an asset.
-    type AssetTransactor = LocalAssetTransactor;
+    type AssetTransactor = AssetTransactors;
     type OriginConverter = XcmOriginToTransactDispatchOrigin;
-    type IsReserve = NativeAsset;
+    type IsReserve = TrustedReserves<TrustedReserveOrigins>;
     type IsTeleporter = (); // Teleporting is disabled.
     type UniversalLocation = UniversalLocation;
     type Barrier = Barrier;
@@ -187,8 +267,10 @@ impl pallet_xcm::Config for Runtime {
     // ^ Disable dispatchable execute on the XCM pallet.
     // Needs to be `Everything` for local testing.
     type XcmExecutor = XcmExecutor<XcmConfig>;
-    type XcmTeleportFilter = Everything;
-    type XcmReserveTransferFilter = Nothing;
+    // $TCC is reserve-based and nothing else is teleportable (`IsTeleporter = ()`)
+    type XcmTeleportFilter = Nothing;
+    // $TCC only, to Asset Hub and whitelisted siblings
+    type XcmReserveTransferFilter = TccReserveTransfers<TccLocation, TccDestinations>;
     type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
     type UniversalLocation = UniversalLocation;
     type RuntimeOrigin = RuntimeOrigin;
@@ -1,10 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod tests;
+
 use frame_support::{
     construct_runtime,
     parameter_types,
-    traits::{ConstU128, ConstU32, ConstU64, Everything},
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Contains, ContainsPair, NeverEnsureOrigin},
     weights::constants::RocksDbWeight,
+    PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -31,6 +34,20 @@ parameter_types! {
     pub const ExistentialDeposit: Balance = 1;
     pub const MinimumPeriod: u64 = 3;
     pub const ParachainId: u32 = 2000; 
+    /// pallet-assets id of $TCC
+    pub const TccAssetId: u32 = 1;
+    /// Derives the $TCC allocation pot accounts
+    pub const TccPalletId: PalletId = PalletId(*b"tcc/pots");
+    /// $TCC mint budgets reset weekly (6s blocks)
+    pub const TccMintEpochLength: u32 = 7 * 14_400;
+    /// Mints above 1M TCC (12 decimals) are timelocked
+    pub const TccLargeMintThreshold: Balance = 1_000_000 * 1_000_000_000_000;
+    /// Queued mints wait two days
+    pub const TccMintDelay: u32 = 2 * 14_400;
+    /// Fees in $TCC: 0.001 TCC per transaction plus 0.000001 TCC per byte, weight 1:1
+    pub const TccBaseFee: Balance = 1_000_000_000;
+    pub const TccByteFee: Balance = 1_000_000;
+    pub const TccAirdropPalletId: PalletId = PalletId(*b"tcc/drop");
 }
 
 pub const PARACHAIN_ID: u32 = ParachainId::get();
@@ -43,6 +60,10 @@ construct_runtime!(
         Balances: pallet_balances,
         Sudo: pallet_sudo,
         MemberRegistry: pallet_member_registry,
+        Assets: pallet_assets,
+        Tcc: pallet_tcc,
+        TccTxPayment: pallet_tcc_tx_payment,
+        TccAirdrop: pallet_tcc_airdrop,
     }
 );
 
@@ -69,7 +90,7 @@ impl frame_system::Config for Runtime {
     type PostTransactions = ();
 
     // Common
-    type BaseCallFilter = Everything;
+    type BaseCallFilter = TccAssetCallFilter;
     type BlockWeights = ();
     type BlockLength = ();
     type DbWeight = RocksDbWeight;
@@ -118,7 +139,7 @@ impl pallet_balances::Config for Runtime {
     type WeightInfo = ();
 
     // Recent associated types
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
     type ReserveIdentifier = ();
     type FreezeIdentifier = ();
@@ -141,8 +162,203 @@ impl pallet_member_registry::Config for Runtime {
     type WeightInfo = DummyWeight;
 }
 
+/// Rejects `Assets` calls on $TCC so supply, pause and freezes only change through pallet-tcc;
+/// Root still reaches the force calls through sudo, which bypasses the filter
+pub struct TccAssetCallFilter;
+
+impl Contains<RuntimeCall> for TccAssetCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        use pallet_assets::Call as A;
+        match call {
+            RuntimeCall::Assets(
+                A::create { id, .. }
+                | A::force_create { id, .. }
+                | A::start_destroy { id }
+                | A::destroy_accounts { id }
+                | A::destroy_approvals { id }
+                | A::finish_destroy { id }
+                | A::mint { id, .. }
+                | A::burn { id, .. }
+                | A::transfer { id, .. }
+                | A::transfer_keep_alive { id, .. }
+                | A::force_transfer { id, .. }
+                | A::freeze { id, .. }
+                | A::thaw { id, .. }
+                | A::freeze_asset { id }
+                | A::thaw_asset { id }
+                | A::transfer_ownership { id, .. }
+                | A::set_team { id, .. }
+                | A::set_metadata { id, .. }
+                | A::clear_metadata { id }
+                | A::force_set_metadata { id, .. }
+                | A::force_clear_metadata { id }
+                | A::force_asset_status { id, .. }
+                | A::approve_transfer { id, .. }
+                | A::cancel_approval { id, .. }
+                | A::force_cancel_approval { id, .. }
+                | A::transfer_approved { id, .. }
+                | A::touch { id }
+                | A::refund { id, .. }
+                | A::set_min_balance { id, .. }
+                | A::touch_other { id, .. }
+                | A::refund_other { id, .. }
+                | A::block { id, .. }
+                | A::transfer_all { id, .. },
+            ) => *id != TccAssetId::get(),
+            _ => true,
+        }
+    }
+}
+
+// Assets (holds $TCC balances)
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    // Assets are only created by Root through `force_create`
+    type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<100>;
+    type AssetAccountDeposit = ConstU128<1>;
+    type MetadataDepositBase = ConstU128<10>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type ApprovalDeposit = ConstU128<1>;
+    type StringLimit = ConstU32<50>;
+    // $TCC holds and freezes are kept by pallet-tcc
+    type Freezer = Tcc;
+    type Holder = Tcc;
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+}
+
+// Implement dummy weights for TCC pallet
+impl pallet_tcc::WeightInfo for DummyWeight {
+    fn instantiate_asset() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn mint() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn burn() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn transfer() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn set_max_supply() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn set_mint_budget() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn execute_mint() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn cancel_mint() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn pause() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn unpause() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn freeze_account() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn thaw_account() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn vest() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn vest_other() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn create_vesting() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn terminate_vesting() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn register_foreign_asset() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn deregister_foreign_asset() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+}
+
+// TCC
+impl pallet_tcc::Config for Runtime {
+    type AssetId = u32;
+    type Balance = Balance;
+    type TccAssetId = TccAssetId;
+    type Assets = Assets;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type PalletId = TccPalletId;
+    type SupplyCapOrigin = frame_system::EnsureRoot<AccountId>;
+    type VestingOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxVestingSchedules = ConstU32<8>;
+    type InstantiateOrigin = frame_system::EnsureRoot<AccountId>;
+    type MintRole = ();
+    type MintOrigin = frame_system::EnsureRoot<AccountId>;
+    type MintEpochLength = TccMintEpochLength;
+    type LargeMintThreshold = TccLargeMintThreshold;
+    type MintDelay = TccMintDelay;
+    type GuardianOrigin = frame_system::EnsureRoot<AccountId>;
+    type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+    type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+    type BurnOrigin = frame_system::EnsureRoot<AccountId>;
+    type ForeignAssetOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = DummyWeight;
+}
+
+/// $TCC fees go to the treasury allocation pot
+pub struct TccTreasury;
+
+impl frame_support::traits::Get<AccountId> for TccTreasury {
+    fn get() -> AccountId {
+        Tcc::pot_account(pallet_tcc::AllocationPot::Treasury)
+    }
+}
+
+/// Sudo calls signed by the sudo key pay no fee so an emergency pause can always be lifted
+pub struct FeeExemptCalls;
+
+impl ContainsPair<AccountId, RuntimeCall> for FeeExemptCalls {
+    fn contains(who: &AccountId, call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Sudo(_)) && pallet_sudo::Key::<Runtime>::get().as_ref() == Some(who)
+    }
+}
+
+impl pallet_tcc_tx_payment::WeightInfo for DummyWeight {
+    fn set_conversion_rate() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn charge_tcc_fee() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+}
+
+// Fees in $TCC
+impl pallet_tcc_tx_payment::Config for Runtime {
+    type Currency = pallet_tcc::TccOf<Runtime>;
+    type NativeCurrency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FeeDestination = TccTreasury;
+    type BaseFee = TccBaseFee;
+    type ByteFee = TccByteFee;
+    type WeightToFee = frame_support::weights::IdentityFee<Balance>;
+    type FeeExempt = FeeExemptCalls;
+    type RateOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = DummyWeight;
+}
+
+/// Airdrop rounds are funded from the airdrop allocation pot
+pub struct TccAirdropPot;
+
+impl frame_support::traits::Get<AccountId> for TccAirdropPot {
+    fn get() -> AccountId {
+        Tcc::pot_account(pallet_tcc::AllocationPot::Airdrop)
+    }
+}
+
+impl pallet_tcc_airdrop::WeightInfo for DummyWeight {
+    fn create_round() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn claim() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+    fn close_round() -> frame_support::weights::Weight { frame_support::weights::Weight::from_parts(0, 0) }
+}
+
+// Merkle airdrops of $TCC; unclaimed funds return to the treasury pot
+impl pallet_tcc_airdrop::Config for Runtime {
+    type Currency = pallet_tcc::TccOf<Runtime>;
+    type PalletId = TccAirdropPalletId;
+    type FundingSource = TccAirdropPot;
+    type UnclaimedDestination = TccTreasury;
+    type AirdropOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxProofLength = ConstU32<32>;
+    type WeightInfo = DummyWeight;
+}
+
 // Extrinsic types (after Runtime exists)
-pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+/// Transaction extensions; fee payment comes last so it sees the actual weight
+pub type TxExtension = (
+    frame_system::CheckNonZeroSender<Runtime>,
+    frame_system::CheckSpecVersion<Runtime>,
+    frame_system::CheckTxVersion<Runtime>,
+    frame_system::CheckGenesis<Runtime>,
+    frame_system::CheckEra<Runtime>,
+    frame_system::CheckNonce<Runtime>,
+    frame_system::CheckWeight<Runtime>,
+    pallet_tcc_tx_payment::ChargeTccFee<Runtime>,
+);
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, RuntimeCall, Signature, TxExtension>;
 pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 
 #[cfg(feature = "std")]
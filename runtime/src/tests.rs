@@ -0,0 +1,34 @@
+//! Runtime configuration tests.
+
+#![cfg(test)]
+
+use crate::*;
+use frame_support::traits::Contains;
+
+fn assets_call(id: u32) -> RuntimeCall {
+    RuntimeCall::Assets(pallet_assets::Call::mint { id, beneficiary: AccountId::new([1; 32]), amount: 1 })
+}
+
+#[test]
+fn direct_assets_calls_on_tcc_are_filtered() {
+    let tcc = TccAssetId::get();
+    assert!(!TccAssetCallFilter::contains(&assets_call(tcc)));
+    assert!(!TccAssetCallFilter::contains(&RuntimeCall::Assets(pallet_assets::Call::freeze_asset { id: tcc })));
+    assert!(TccAssetCallFilter::contains(&assets_call(tcc + 1)));
+    assert!(TccAssetCallFilter::contains(&RuntimeCall::Tcc(pallet_tcc::Call::mint { to: AccountId::new([1; 32]), amount: 1 })));
+}
+
+#[test]
+fn only_the_sudo_key_is_fee_exempt() {
+    use frame_support::traits::ContainsPair;
+
+    let key = AccountId::new([1; 32]);
+    let other = AccountId::new([2; 32]);
+    let sudo_call = RuntimeCall::Sudo(pallet_sudo::Call::remove_key {});
+    sp_io::TestExternalities::default().execute_with(|| {
+        pallet_sudo::Key::<Runtime>::put(&key);
+        assert!(FeeExemptCalls::contains(&key, &sudo_call));
+        assert!(!FeeExemptCalls::contains(&other, &sudo_call));
+        assert!(!FeeExemptCalls::contains(&key, &assets_call(TccAssetId::get() + 1)));
+    });
+}
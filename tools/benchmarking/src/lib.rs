@@ -0,0 +1,135 @@
+//! Measure pallet weights from a pallet's benchmarks without building the node.
+//!
+//! [`measure`] runs every benchmark of a pallet the way the `benchmark pallet` subcommand does
+//! (each component stepped over its range with the others at their maximum, repeated, then fitted
+//! with the least squares of the inter-quartile range), against a [`BenchmarkingState`] built from
+//! the pallet's mock genesis. The result is the base value and per-component slope of the
+//! extrinsic time, database reads/writes and proof size.
+//!
+//! Notes:
+//! - Execution is native, on whatever machine runs it; run in release mode on reference hardware
+//!   when the numbers are meant for `weights.rs`.
+//! - Proof sizes are measured (the recorded trie nodes), not derived from `MaxEncodedLen`.
+
+mod state;
+
+pub use state::BenchmarkingState;
+
+use codec::Codec;
+use frame_benchmarking::{Analysis, Benchmarking, BenchmarkResult, BenchmarkSelector};
+use hash_db::Hasher;
+use sp_core::storage::TrackedStorageKey;
+use sp_state_machine::{Ext, OverlayedChanges, TestExternalities};
+use std::fmt;
+
+/// Fitted `base + Σ slope_i · component_i`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fit {
+    pub base: u128,
+    pub slopes: Vec<u128>,
+}
+
+impl Fit {
+    fn new(results: &Vec<BenchmarkResult>, selector: BenchmarkSelector) -> Result<Self, String> {
+        Analysis::min_squares_iqr(results, selector)
+            .map(|analysis| Fit { base: analysis.base, slopes: analysis.slopes })
+            .ok_or_else(|| "not enough results to fit".to_string())
+    }
+}
+
+/// Measured weight of one benchmark
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchmarkWeights {
+    pub name: String,
+    /// Component names, in the order of every [`Fit::slopes`]
+    pub components: Vec<String>,
+    /// Picoseconds
+    pub ref_time: Fit,
+    pub reads: Fit,
+    pub writes: Fit,
+    /// Bytes
+    pub proof_size: Fit,
+}
+
+impl fmt::Display for BenchmarkWeights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = |f: &mut fmt::Formatter<'_>, what: &str, fit: &Fit| {
+            write!(f, "\n  {what}: {}", fit.base)?;
+            for (component, slope) in self.components.iter().zip(&fit.slopes) {
+                write!(f, " + {slope} * {component}")?;
+            }
+            Ok(())
+        };
+        write!(f, "{}:", self.name)?;
+        line(f, "ref_time (ps)", &self.ref_time)?;
+        line(f, "reads", &self.reads)?;
+        line(f, "writes", &self.writes)?;
+        line(f, "proof_size (bytes)", &self.proof_size)
+    }
+}
+
+/// Run every benchmark of `B` on top of `genesis` and fit its weights.
+///
+/// Each benchmark is first run once with verification on; then every component is stepped over
+/// `steps` values of its range (the others at their maximum) and each point is run `repeat` times.
+pub fn measure<B, H>(
+    mut genesis: TestExternalities<H>,
+    whitelist: &[TrackedStorageKey],
+    steps: u32,
+    repeat: u32,
+) -> Result<Vec<BenchmarkWeights>, String>
+where
+    B: Benchmarking,
+    H: Hasher + 'static,
+    H::Out: Ord + Codec + 'static,
+{
+    let state = BenchmarkingState::new(genesis.as_backend());
+    let mut overlay = OverlayedChanges::<H>::default();
+    overlay.enter_runtime().map_err(|_| "overlay already in runtime".to_string())?;
+    let mut ext = Ext::new(&mut overlay, &state, None);
+
+    sp_externalities::set_and_run_with_externalities(&mut ext, || {
+        B::benchmarks(false)
+            .into_iter()
+            .map(|benchmark| {
+                let name = String::from_utf8_lossy(&benchmark.name).into_owned();
+                let run = |components: &[_], verify| {
+                    B::run_benchmark(&benchmark.name, components, whitelist, verify, 1)
+                        .map_err(|e| format!("{name}: {e:?}"))
+                };
+
+                let highest: Vec<_> = benchmark.components.iter().map(|(param, _, high)| (*param, *high)).collect();
+                run(&highest, true)?;
+
+                let mut results = Vec::new();
+                for (i, (_, low, high)) in benchmark.components.iter().enumerate() {
+                    let points = steps.clamp(1, high - low + 1);
+                    for step in 0..points {
+                        let mut components = highest.clone();
+                        components[i].1 = match points {
+                            1 => *high,
+                            _ => low + (high - low) * step / (points - 1),
+                        };
+                        for _ in 0..repeat {
+                            results.extend(run(&components, false)?);
+                        }
+                    }
+                }
+                if benchmark.components.is_empty() {
+                    for _ in 0..repeat {
+                        results.extend(run(&[], false)?);
+                    }
+                }
+
+                Ok(BenchmarkWeights {
+                    components: benchmark.components.iter().map(|(param, _, _)| format!("{param:?}")).collect(),
+                    ref_time: Fit::new(&results, BenchmarkSelector::ExtrinsicTime)?,
+                    reads: Fit::new(&results, BenchmarkSelector::Reads)?,
+                    writes: Fit::new(&results, BenchmarkSelector::Writes)?,
+                    proof_size: Fit::new(&results, BenchmarkSelector::ProofSize)?,
+                    name,
+                })
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,358 @@
+//! State backend tracking the reads, writes and proof size of a benchmark.
+//!
+//! An in-memory counterpart of the node's benchmarking database: the trie lives in a
+//! `PrefixedMemoryDB`, `commit` folds each block's changes into it and reopens the trie with a
+//! fresh proof recorder, and `wipe` restores the genesis nodes. Keys are tracked the same way the
+//! node does, so a key counts as one read/write however often it is touched, and whitelisted keys
+//! don't count at all.
+
+use codec::Codec;
+use hash_db::{Hasher, Prefix};
+use sp_core::storage::{ChildInfo, TrackedStorageKey};
+use sp_state_machine::{
+    backend::Backend, BackendTransaction, ChildStorageCollection, DBValue, IterArgs, StateMachineStats,
+    StorageCollection, StorageIterator, StorageKey, StorageValue, TrieBackend, TrieBackendBuilder,
+    TrieBackendStorage, UsageInfo,
+};
+use sp_trie::{recorder::Recorder, MerkleValue, PrefixedMemoryDB};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc,
+    sync::{Arc, RwLock},
+};
+
+/// Trie nodes shared between the state and the trie backend reading them
+struct SharedDb<H: Hasher>(Arc<RwLock<PrefixedMemoryDB<H>>>);
+
+impl<H: Hasher> sp_state_machine::Storage<H> for SharedDb<H> {
+    fn get(&self, key: &H::Out, prefix: Prefix) -> Result<Option<DBValue>, String> {
+        let db = self.0.read().map_err(|_| "benchmark db lock poisoned".to_string())?;
+        TrieBackendStorage::<H>::get(&*db, key, prefix)
+    }
+}
+
+type Trie<H> = TrieBackend<Arc<dyn sp_state_machine::Storage<H>>, H>;
+
+/// Accesses per key since the last reset; child trie keys are grouped by the child's storage key.
+#[derive(Default)]
+struct KeyTracker {
+    main_keys: BTreeMap<Vec<u8>, TrackedStorageKey>,
+    child_keys: BTreeMap<Vec<u8>, BTreeMap<Vec<u8>, TrackedStorageKey>>,
+}
+
+impl KeyTracker {
+    fn keys(&mut self, child: Option<&[u8]>) -> &mut BTreeMap<Vec<u8>, TrackedStorageKey> {
+        match child {
+            Some(child) => self.child_keys.entry(child.to_vec()).or_default(),
+            None => &mut self.main_keys,
+        }
+    }
+
+    fn add_read(&mut self, child: Option<&[u8]>, key: &[u8]) {
+        self.keys(child)
+            .entry(key.to_vec())
+            .or_insert_with(|| TrackedStorageKey::new(key.to_vec()))
+            .add_read();
+    }
+
+    fn add_write(&mut self, child: Option<&[u8]>, key: &[u8]) {
+        self.keys(child)
+            .entry(key.to_vec())
+            .or_insert_with(|| TrackedStorageKey::new(key.to_vec()))
+            .add_write();
+    }
+
+    fn reset(&mut self, whitelist: &[TrackedStorageKey]) {
+        self.main_keys.clear();
+        self.child_keys.clear();
+        for key in whitelist {
+            let mut whitelisted = TrackedStorageKey::new(key.key.clone());
+            whitelisted.whitelist();
+            self.main_keys.insert(key.key.clone(), whitelisted);
+        }
+    }
+
+    fn all(&self) -> impl Iterator<Item = &TrackedStorageKey> {
+        self.main_keys.values().chain(self.child_keys.values().flat_map(|keys| keys.values()))
+    }
+}
+
+/// In-memory state implementing the benchmarking hooks of [`Backend`]
+pub struct BenchmarkingState<H: Hasher> {
+    db: Arc<RwLock<PrefixedMemoryDB<H>>>,
+    genesis: PrefixedMemoryDB<H>,
+    genesis_root: H::Out,
+    root: Cell<H::Out>,
+    trie: RefCell<Trie<H>>,
+    recorder: Recorder<H>,
+    recorder_root: Cell<H::Out>,
+    tracker: Rc<RefCell<KeyTracker>>,
+    whitelist: RefCell<Vec<TrackedStorageKey>>,
+}
+
+impl<H> BenchmarkingState<H>
+where
+    H: Hasher + 'static,
+    H::Out: Ord + Codec + 'static,
+{
+    /// Benchmark on top of `genesis`; every `wipe` returns to it.
+    pub fn new(genesis: TrieBackend<PrefixedMemoryDB<H>, H>) -> Self {
+        let genesis_root = *genesis.root();
+        let genesis = genesis.into_storage();
+        let db = Arc::new(RwLock::new(genesis.clone()));
+        let recorder = Recorder::default();
+        let trie = RefCell::new(Self::open(&db, genesis_root, &recorder));
+        Self {
+            db,
+            genesis,
+            genesis_root,
+            root: Cell::new(genesis_root),
+            trie,
+            recorder,
+            recorder_root: Cell::new(genesis_root),
+            tracker: Default::default(),
+            whitelist: Default::default(),
+        }
+    }
+
+    fn open(db: &Arc<RwLock<PrefixedMemoryDB<H>>>, root: H::Out, recorder: &Recorder<H>) -> Trie<H> {
+        let storage: Arc<dyn sp_state_machine::Storage<H>> = Arc::new(SharedDb(db.clone()));
+        TrieBackendBuilder::new(storage, root).with_recorder(recorder.clone()).build()
+    }
+
+    /// Start a new proof at the current root, as a new block would.
+    fn reopen(&self) {
+        self.recorder.reset();
+        self.recorder_root.set(self.root.get());
+        *self.trie.borrow_mut() = Self::open(&self.db, self.root.get(), &self.recorder);
+    }
+
+    fn add_read(&self, child: Option<&[u8]>, key: &[u8]) {
+        self.tracker.borrow_mut().add_read(child, key);
+    }
+}
+
+impl<H: Hasher> core::fmt::Debug for BenchmarkingState<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BenchmarkingState")
+    }
+}
+
+/// Iterator counting every key it yields as read
+pub struct RawIter<H>
+where
+    H: Hasher + 'static,
+    H::Out: Ord + Codec + 'static,
+{
+    inner: <Trie<H> as Backend<H>>::RawIter,
+    child: Option<Vec<u8>>,
+    tracker: Rc<RefCell<KeyTracker>>,
+}
+
+impl<H> StorageIterator<H> for RawIter<H>
+where
+    H: Hasher + 'static,
+    H::Out: Ord + Codec + 'static,
+{
+    type Backend = BenchmarkingState<H>;
+    type Error = String;
+
+    fn next_key(&mut self, backend: &Self::Backend) -> Option<Result<StorageKey, Self::Error>> {
+        let next = self.inner.next_key(&backend.trie.borrow());
+        if let Some(Ok(key)) = &next {
+            self.tracker.borrow_mut().add_read(self.child.as_deref(), key);
+        }
+        next
+    }
+
+    fn next_pair(&mut self, backend: &Self::Backend) -> Option<Result<(StorageKey, StorageValue), Self::Error>> {
+        let next = self.inner.next_pair(&backend.trie.borrow());
+        if let Some(Ok((key, _))) = &next {
+            self.tracker.borrow_mut().add_read(self.child.as_deref(), key);
+        }
+        next
+    }
+
+    fn was_complete(&self) -> bool {
+        self.inner.was_complete()
+    }
+}
+
+impl<H> Backend<H> for BenchmarkingState<H>
+where
+    H: Hasher + 'static,
+    H::Out: Ord + Codec + 'static,
+{
+    type Error = String;
+    type TrieBackendStorage = Arc<dyn sp_state_machine::Storage<H>>;
+    type RawIter = RawIter<H>;
+
+    fn storage(&self, key: &[u8]) -> Result<Option<StorageValue>, Self::Error> {
+        self.add_read(None, key);
+        self.trie.borrow().storage(key)
+    }
+
+    fn storage_hash(&self, key: &[u8]) -> Result<Option<H::Out>, Self::Error> {
+        self.add_read(None, key);
+        self.trie.borrow().storage_hash(key)
+    }
+
+    fn closest_merkle_value(&self, key: &[u8]) -> Result<Option<MerkleValue<H::Out>>, Self::Error> {
+        self.add_read(None, key);
+        self.trie.borrow().closest_merkle_value(key)
+    }
+
+    fn child_closest_merkle_value(
+        &self,
+        child_info: &ChildInfo,
+        key: &[u8],
+    ) -> Result<Option<MerkleValue<H::Out>>, Self::Error> {
+        self.add_read(Some(child_info.storage_key()), key);
+        self.trie.borrow().child_closest_merkle_value(child_info, key)
+    }
+
+    fn child_storage(&self, child_info: &ChildInfo, key: &[u8]) -> Result<Option<StorageValue>, Self::Error> {
+        self.add_read(Some(child_info.storage_key()), key);
+        self.trie.borrow().child_storage(child_info, key)
+    }
+
+    fn child_storage_hash(&self, child_info: &ChildInfo, key: &[u8]) -> Result<Option<H::Out>, Self::Error> {
+        self.add_read(Some(child_info.storage_key()), key);
+        self.trie.borrow().child_storage_hash(child_info, key)
+    }
+
+    fn exists_storage(&self, key: &[u8]) -> Result<bool, Self::Error> {
+        self.add_read(None, key);
+        self.trie.borrow().exists_storage(key)
+    }
+
+    fn exists_child_storage(&self, child_info: &ChildInfo, key: &[u8]) -> Result<bool, Self::Error> {
+        self.add_read(Some(child_info.storage_key()), key);
+        self.trie.borrow().exists_child_storage(child_info, key)
+    }
+
+    fn next_storage_key(&self, key: &[u8]) -> Result<Option<StorageKey>, Self::Error> {
+        self.add_read(None, key);
+        self.trie.borrow().next_storage_key(key)
+    }
+
+    fn next_child_storage_key(&self, child_info: &ChildInfo, key: &[u8]) -> Result<Option<StorageKey>, Self::Error> {
+        self.add_read(Some(child_info.storage_key()), key);
+        self.trie.borrow().next_child_storage_key(child_info, key)
+    }
+
+    fn storage_root<'a>(
+        &self,
+        delta: impl Iterator<Item = (&'a [u8], Option<&'a [u8]>)>,
+        state_version: sp_core::storage::StateVersion,
+    ) -> (H::Out, BackendTransaction<H>) {
+        self.trie.borrow().storage_root(delta, state_version)
+    }
+
+    fn child_storage_root<'a>(
+        &self,
+        child_info: &ChildInfo,
+        delta: impl Iterator<Item = (&'a [u8], Option<&'a [u8]>)>,
+        state_version: sp_core::storage::StateVersion,
+    ) -> (H::Out, bool, BackendTransaction<H>) {
+        self.trie.borrow().child_storage_root(child_info, delta, state_version)
+    }
+
+    fn raw_iter(&self, args: IterArgs) -> Result<Self::RawIter, Self::Error> {
+        let child = args.child_info.as_ref().map(|info| info.storage_key().to_vec());
+        let inner = self.trie.borrow().raw_iter(args)?;
+        Ok(RawIter { inner, child, tracker: self.tracker.clone() })
+    }
+
+    fn register_overlay_stats(&self, stats: &StateMachineStats) {
+        self.trie.borrow().register_overlay_stats(stats)
+    }
+
+    fn usage_info(&self) -> UsageInfo {
+        self.trie.borrow().usage_info()
+    }
+
+    fn wipe(&self) -> Result<(), Self::Error> {
+        *self.db.write().map_err(|_| "benchmark db lock poisoned".to_string())? = self.genesis.clone();
+        self.root.set(self.genesis_root);
+        self.reopen();
+        self.reset_read_write_count();
+        Ok(())
+    }
+
+    fn commit(
+        &self,
+        storage_root: H::Out,
+        transaction: BackendTransaction<H>,
+        main_storage_changes: StorageCollection,
+        child_storage_changes: ChildStorageCollection,
+    ) -> Result<(), Self::Error> {
+        self.db.write().map_err(|_| "benchmark db lock poisoned".to_string())?.consolidate(transaction);
+        self.root.set(storage_root);
+
+        let mut tracker = self.tracker.borrow_mut();
+        for (key, _) in &main_storage_changes {
+            tracker.add_write(None, key);
+        }
+        for (child, changes) in &child_storage_changes {
+            for (key, _) in changes {
+                tracker.add_write(Some(child), key);
+            }
+        }
+        drop(tracker);
+
+        self.reopen();
+        Ok(())
+    }
+
+    /// `(reads, repeat_reads, writes, repeat_writes)` of the non-whitelisted keys
+    fn read_write_count(&self) -> (u32, u32, u32, u32) {
+        let (mut reads, mut repeat_reads, mut writes, mut repeat_writes) = (0, 0, 0, 0);
+        for key in self.tracker.borrow().all().filter(|key| !key.whitelisted) {
+            if key.reads > 0 {
+                reads += 1;
+                repeat_reads += key.reads - 1;
+            }
+            if key.writes > 0 {
+                writes += 1;
+                repeat_writes += key.writes - 1;
+            }
+        }
+        (reads, repeat_reads, writes, repeat_writes)
+    }
+
+    fn reset_read_write_count(&self) {
+        self.tracker.borrow_mut().reset(&self.whitelist.borrow());
+    }
+
+    fn get_whitelist(&self) -> Vec<TrackedStorageKey> {
+        self.whitelist.borrow().clone()
+    }
+
+    fn set_whitelist(&self, new: Vec<TrackedStorageKey>) {
+        *self.whitelist.borrow_mut() = new;
+    }
+
+    /// Compact size of the proof recorded since the last commit
+    fn proof_size(&self) -> Option<u32> {
+        let estimate = self.recorder.estimate_encoded_size() as u32;
+        let root = self.recorder_root.get();
+        if root == Default::default() || estimate == 1 {
+            return Some(estimate);
+        }
+        Some(self.recorder.to_storage_proof().encoded_compact_size::<H>(root).map_or(0, |size| size as u32))
+    }
+
+    /// Keys touched since the last reset, grouped by their 32-byte (pallet + item) prefix
+    fn get_read_and_written_keys(&self) -> Vec<(Vec<u8>, u32, u32, bool)> {
+        let mut prefixes = BTreeMap::<Vec<u8>, (u32, u32)>::new();
+        for key in self.tracker.borrow().all().filter(|key| !key.whitelisted) {
+            let prefix = key.key[..key.key.len().min(32)].to_vec();
+            let entry = prefixes.entry(prefix).or_default();
+            entry.0 += key.reads.min(1);
+            entry.1 += key.writes.min(1);
+        }
+        prefixes.into_iter().map(|(prefix, (reads, writes))| (prefix, reads, writes, false)).collect()
+    }
+}